@@ -16,6 +16,8 @@
 //! Define the commands supported by the CLI related to Accounts.
 
 use super::*;
+use futures::TryStreamExt as _;
+use std::path::{Path, PathBuf};
 
 /// Account related commands
 #[derive(StructOpt, Clone)]
@@ -24,6 +26,10 @@ pub enum Command {
     Show(Show),
     /// Transfer funds from the author to a recipient account.
     Transfer(Transfer),
+    /// Transfer funds from the author to multiple recipients in one transaction.
+    MultiTransfer(MultiTransfer),
+    /// Print the changes of the account balance as they happen. Runs until interrupted.
+    Watch(Watch),
 }
 
 #[async_trait::async_trait]
@@ -32,6 +38,8 @@ impl CommandT for Command {
         match self {
             Command::Show(cmd) => cmd.run().await,
             Command::Transfer(cmd) => cmd.run().await,
+            Command::MultiTransfer(cmd) => cmd.run().await,
+            Command::Watch(cmd) => cmd.run().await,
         }
     }
 }
@@ -47,13 +55,33 @@ pub struct Show {
 
     #[structopt(flatten)]
     network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    output_options: OutputOptions,
+
+    #[structopt(flatten)]
+    state_options: StateOptions,
+}
+
+/// JSON output of [Show].
+#[derive(serde::Serialize)]
+struct AccountInfo {
+    ss58_address: String,
+    balance: Balance,
 }
 
 #[async_trait::async_trait]
 impl CommandT for Show {
     async fn run(self) -> Result<(), CommandError> {
-        let client = self.network_options.client().await?;
+        let client = self.state_options.client(&self.network_options).await?;
         let balance = client.free_balance(&self.account_id).await?;
+        if self.output_options.output == OutputFormat::Json {
+            print_json(&AccountInfo {
+                ss58_address: self.account_id.to_ss58check(),
+                balance,
+            });
+            return Ok(());
+        }
         println!("ss58 address: {}", self.account_id.to_ss58check());
         println!("balance: {} μRAD", balance);
         Ok(())
@@ -103,3 +131,140 @@ impl CommandT for Transfer {
         Ok(())
     }
 }
+
+#[derive(StructOpt, Clone)]
+pub struct MultiTransfer {
+    /// CSV file with one transfer per line.
+    /// Each line holds the recipient and the amount separated by a comma. The recipient is an
+    /// SS58 address or the name of a local key pair.
+    #[structopt(value_name = "csv_file", parse(from_os_str))]
+    transfers_file: PathBuf,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for MultiTransfer {
+    async fn run(self) -> Result<(), CommandError> {
+        let transfers = read_transfers_file(&self.transfers_file)?;
+        let recipient_count = transfers.len();
+        let total: Balance = transfers.iter().map(|(_, amount)| amount).sum();
+
+        let client = self.network_options.client().await?;
+        let transfer_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::MultiTransfer { transfers },
+                self.tx_options.fee,
+            )
+            .await?;
+        announce_tx("Transferring funds...");
+
+        let transfered = transfer_fut.await?;
+        transfered.result?;
+        println!(
+            "✓ Transferred {} μRAD to {} recipients in block {}",
+            total, recipient_count, transfered.block,
+        );
+        Ok(())
+    }
+}
+
+/// Read the transfers for [MultiTransfer] from a CSV file. Empty lines are ignored.
+fn read_transfers_file(path: &Path) -> Result<Vec<(AccountId, Balance)>, CommandError> {
+    let content =
+        std::fs::read_to_string(path).map_err(|error| CommandError::TransfersFileUnreadable {
+            path: path.to_path_buf(),
+            error,
+        })?;
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            parse_transfer(line).map_err(|reason| CommandError::InvalidTransfer {
+                line: index + 1,
+                reason,
+            })
+        })
+        .collect()
+}
+
+/// Parse a `<recipient>,<amount>` line of a transfers file.
+fn parse_transfer(line: &str) -> Result<(AccountId, Balance), String> {
+    let mut fields = line.split(',').map(str::trim);
+    match (fields.next(), fields.next(), fields.next()) {
+        (Some(recipient), Some(amount), None) => {
+            let recipient = parse_account_id(recipient)?;
+            let amount = amount
+                .parse()
+                .map_err(|error| format!("invalid amount {:?}: {}", amount, error))?;
+            Ok((recipient, amount))
+        }
+        _ => Err("expected a recipient and an amount separated by a comma".to_string()),
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Watch {
+    /// The account's SS58 address or the name of a local key pair.
+    #[structopt(
+        value_name = "address_or_name",
+        parse(try_from_str = parse_account_id),
+    )]
+    account_id: AccountId,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Watch {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let mut balance_changes = client.subscribe_balance_changes(self.account_id).await?;
+        println!("Watching {}", self.account_id.to_ss58check());
+
+        while let Some(change) = balance_changes.try_next().await? {
+            let delta = if change.current >= change.previous {
+                format!("+{}", change.current - change.previous)
+            } else {
+                format!("-{}", change.previous - change.current)
+            };
+            println!(
+                "block {}: {} μRAD (balance: {} μRAD)",
+                change.block_number, delta, change.current
+            );
+            for (sender, recipient, amount) in change.transfers {
+                if recipient == self.account_id {
+                    println!("  received {} μRAD from {}", amount, sender.to_ss58check());
+                } else {
+                    println!("  sent {} μRAD to {}", amount, recipient.to_ss58check());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_transfer() {
+        let recipient = ed25519::Pair::from_string("//Bob", None).unwrap().public();
+        let line = format!("{}, 1000", recipient.to_ss58check());
+        assert_eq!(parse_transfer(&line), Ok((recipient, 1000)));
+
+        assert!(parse_transfer(&recipient.to_ss58check()).is_err());
+        let line = format!("{},-1", recipient.to_ss58check());
+        assert!(parse_transfer(&line).is_err());
+        let line = format!("{},1,2", recipient.to_ss58check());
+        assert!(parse_transfer(&line).is_err());
+    }
+}
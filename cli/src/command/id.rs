@@ -0,0 +1,77 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Define the commands supported by the CLI related to org and user IDs.
+
+use super::*;
+
+/// ID related commands
+#[derive(StructOpt, Clone)]
+pub enum Command {
+    /// Check whether an org or a user can be registered with the given id.
+    Check(Check),
+}
+
+#[async_trait::async_trait]
+impl CommandT for Command {
+    async fn run(self) -> Result<(), CommandError> {
+        match self {
+            Command::Check(cmd) => cmd.run().await,
+        }
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Check {
+    /// The id to check.
+    id: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    output_options: OutputOptions,
+
+    #[structopt(flatten)]
+    state_options: StateOptions,
+}
+
+/// JSON output of [Check].
+#[derive(serde::Serialize)]
+struct IdInfo {
+    id: Id,
+    status: IdStatus,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Check {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.state_options.client(&self.network_options).await?;
+        let status = client.get_id_status(&self.id).await?;
+
+        match self.output_options.output {
+            OutputFormat::Json => print_json(&IdInfo {
+                id: self.id,
+                status,
+            }),
+            OutputFormat::Text => match status {
+                IdStatus::Available => println!("✓ Id {} is available", self.id),
+                IdStatus::Taken => println!("✗ Id {} is taken by an org or a user", self.id),
+                IdStatus::Retired => println!("✗ Id {} is retired and cannot be claimed", self.id),
+            },
+        }
+        Ok(())
+    }
+}
@@ -27,6 +27,11 @@ pub enum Command {
     Generate(Generate),
     /// List all the local key pairs.
     List(List),
+    /// Derive a child key pair from the local key pair `parent`
+    /// following a derivation path like `//hard//path` and store
+    /// it as `name`. Fail if there is already a key pair with the
+    /// given `name`.
+    Derive(Derive),
 }
 
 #[async_trait::async_trait]
@@ -35,6 +40,7 @@ impl CommandT for Command {
         match self {
             Command::Generate(cmd) => cmd.run().await,
             Command::List(cmd) => cmd.run().await,
+            Command::Derive(cmd) => cmd.run().await,
         }
     }
 }
@@ -43,19 +49,61 @@ impl CommandT for Command {
 pub struct Generate {
     /// The name that uniquely identifies the key pair locally.
     name: String,
+
+    /// A note to describe the key pair.
+    #[structopt(long)]
+    label: Option<String>,
 }
 
 #[async_trait::async_trait]
 impl CommandT for Generate {
     async fn run(self) -> Result<(), CommandError> {
         let (key_pair, seed) = ed25519::Pair::generate();
-        key_pair_storage::add(self.name, key_pair_storage::KeyPairData { seed })?;
+        let data = key_pair_storage::KeyPairData {
+            label: self.label,
+            ..key_pair_storage::KeyPairData::new(seed)
+        };
+        key_pair_storage::add(self.name, data)?;
         println!("✓ Key pair generated successfully");
         println!("ⓘ SS58 address: {}", key_pair.public().to_ss58check());
         Ok(())
     }
 }
 
+#[derive(StructOpt, Clone)]
+pub struct Derive {
+    /// The name of the local key pair to derive from.
+    parent: String,
+
+    /// The name that uniquely identifies the derived key pair locally.
+    name: String,
+
+    /// The derivation path, e.g. `//hard//path`. Only hard
+    /// junctions are supported.
+    #[structopt(long)]
+    path: String,
+
+    /// A note to describe the key pair.
+    #[structopt(long)]
+    label: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Derive {
+    async fn run(self) -> Result<(), CommandError> {
+        let parent = key_pair_storage::get(&self.parent)?;
+        let data = key_pair_storage::KeyPairData {
+            label: self.label,
+            ..parent.derive(&self.path)?
+        };
+        let address = data.ss58_address();
+        key_pair_storage::add(self.name, data)?;
+        println!("✓ Key pair derived successfully");
+        println!("ⓘ SS58 address: {}", address);
+        Ok(())
+    }
+}
+
 #[derive(StructOpt, Clone)]
 pub struct List {}
 
@@ -64,12 +112,42 @@ impl CommandT for List {
     async fn run(self) -> Result<(), CommandError> {
         let key_pairs = key_pair_storage::list()?;
         println!("Key pairs ({})\n", key_pairs.len());
-        for (name, data) in key_pairs {
-            println!("  '{}'", name);
-            println!(
-                "  ss58 address: {}\n",
-                ed25519::Pair::from_seed(&data.seed).public().to_ss58check()
-            );
+
+        let mut rows = key_pairs
+            .into_iter()
+            .map(|(name, data)| {
+                [
+                    name,
+                    data.ss58_address(),
+                    data.label.clone().unwrap_or_default(),
+                    data.created_at
+                        .map(|created_at| created_at.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                        .unwrap_or_default(),
+                ]
+            })
+            .collect::<Vec<_>>();
+        rows.sort();
+
+        let header = [
+            String::from("NAME"),
+            String::from("SS58 ADDRESS"),
+            String::from("LABEL"),
+            String::from("CREATED AT"),
+        ];
+        let mut widths = [0usize; 4];
+        for row in std::iter::once(&header).chain(rows.iter()) {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        for row in std::iter::once(&header).chain(rows.iter()) {
+            let line = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            println!("  {}", line.trim_end());
         }
         Ok(())
     }
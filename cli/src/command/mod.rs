@@ -15,7 +15,10 @@
 
 //! Define the commands supported by the CLI.
 
-use crate::{lookup_key_pair, CommandError, CommandT, NetworkOptions, TxOptions};
+use crate::{
+    lookup_key_pair, CommandError, CommandT, NetworkOptions, OutputFormat, OutputOptions,
+    StateOptions, TxOptions,
+};
 use itertools::Itertools;
 use radicle_registry_client::*;
 
@@ -23,6 +26,8 @@ use sp_core::crypto::Ss58Codec;
 use structopt::StructOpt;
 
 pub mod account;
+pub mod admin;
+pub mod id;
 pub mod key_pair;
 pub mod org;
 pub mod other;
@@ -49,7 +54,46 @@ fn parse_account_id(data: &str) -> Result<AccountId, String> {
         })
 }
 
+/// Tell the user if the fee for `message` will be charged to an org account instead of the
+/// author's account.
+async fn announce_fee_payer<Message_: Message + Clone>(
+    client: &Client,
+    author: &ed25519::Pair,
+    message: &Message_,
+) -> Result<(), CommandError> {
+    let author_account_id = author.public();
+    let payer = client.fee_payer_for(&author_account_id, message).await?;
+    if payer != author_account_id {
+        println!(
+            "ⓘ Fee will be charged to org account {}",
+            payer.to_ss58check()
+        );
+    }
+    Ok(())
+}
+
+/// Print `value` as pretty JSON to stdout.
+fn print_json<T: serde::Serialize>(value: &T) {
+    let json = serde_json::to_string_pretty(value).expect("Serializing command output never fails");
+    println!("{}", json);
+}
+
 fn announce_tx(msg: &str) {
     println!("{}", msg);
     println!("⏳ Transactions might take a while to be processed. Please wait...");
 }
+
+/// Ask the user to confirm an action on stdin. Fails with [CommandError::Aborted] unless the user
+/// answers with "y" or "yes".
+fn confirm(msg: &str) -> Result<(), CommandError> {
+    use std::io::Write as _;
+
+    print!("{} Continue? [y/N] ", msg);
+    let _ = std::io::stdout().flush();
+    let mut answer = String::new();
+    let _ = std::io::stdin().read_line(&mut answer);
+    match answer.trim().to_lowercase().as_str() {
+        "y" | "yes" => Ok(()),
+        _ => Err(CommandError::Aborted),
+    }
+}
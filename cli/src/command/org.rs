@@ -33,6 +33,8 @@ pub enum Command {
     Unregister(Unregister),
     /// Register a new member under an org.
     RegisterMember(RegisterMember),
+    /// Leave an org. The user associated with the author is removed from the org members.
+    Leave(Leave),
 }
 
 #[async_trait::async_trait]
@@ -45,6 +47,7 @@ impl CommandT for Command {
             Command::Unregister(cmd) => cmd.run().await,
             Command::Transfer(cmd) => cmd.run().await,
             Command::RegisterMember(cmd) => cmd.run().await,
+            Command::Leave(cmd) => cmd.run().await,
         }
     }
 }
@@ -53,16 +56,27 @@ impl CommandT for Command {
 pub struct List {
     #[structopt(flatten)]
     network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    output_options: OutputOptions,
+
+    #[structopt(flatten)]
+    state_options: StateOptions,
 }
 
 #[async_trait::async_trait]
 impl CommandT for List {
     async fn run(self) -> Result<(), CommandError> {
-        let client = self.network_options.client().await?;
-        let org_ids = client.list_orgs().await?;
-        println!("ORGS ({})", org_ids.len());
-        for org_id in org_ids {
-            println!("{}", org_id)
+        let client = self.state_options.client(&self.network_options).await?;
+        let org_ids = client.registry().orgs().list().await?;
+        match self.output_options.output {
+            OutputFormat::Json => print_json(&org_ids),
+            OutputFormat::Text => {
+                println!("ORGS ({})", org_ids.len());
+                for org_id in org_ids {
+                    println!("{}", org_id)
+                }
+            }
         }
         Ok(())
     }
@@ -75,25 +89,58 @@ pub struct Show {
 
     #[structopt(flatten)]
     network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    output_options: OutputOptions,
+
+    #[structopt(flatten)]
+    state_options: StateOptions,
+}
+
+/// JSON output of [Show].
+#[derive(serde::Serialize)]
+struct OrgInfo {
+    id: Id,
+    /// SS58 address of the org account.
+    account_id: String,
+    balance: Balance,
+    member_ids: Vec<Id>,
+    projects: Vec<ProjectName>,
+    registered_at: BlockNumber,
 }
 
 #[async_trait::async_trait]
 impl CommandT for Show {
     async fn run(self) -> Result<(), CommandError> {
-        let client = self.network_options.client().await?;
+        let client = self.state_options.client(&self.network_options).await?;
         let org = client
-            .get_org(self.org_id.clone())
+            .registry()
+            .orgs()
+            .get(self.org_id.clone())
             .await?
             .ok_or(CommandError::OrgNotFound {
                 org_id: self.org_id.clone(),
             })?;
         let balance = client.free_balance(&org.account_id()).await?;
 
+        if self.output_options.output == OutputFormat::Json {
+            print_json(&OrgInfo {
+                id: self.org_id,
+                account_id: org.account_id().to_ss58check(),
+                balance,
+                member_ids: org.members().clone(),
+                projects: org.projects().clone(),
+                registered_at: org.registered_at(),
+            });
+            return Ok(());
+        }
+
         println!("id: {}", self.org_id);
         println!("account id: {}", org.account_id());
         println!("balance: {} μRAD", balance);
         println!("member ids: [{}]", org.members().iter().format(", "));
         println!("projects: [{}]", org.projects().iter().format(", "));
+        println!("registered at block: {}", org.registered_at());
         Ok(())
     }
 }
@@ -120,6 +167,7 @@ impl CommandT for Register {
                 &self.tx_options.author,
                 message::RegisterOrg {
                     org_id: self.org_id.clone(),
+                    reservation_salt: None,
                 },
                 self.tx_options.fee,
             )
@@ -137,6 +185,10 @@ pub struct Unregister {
     /// Id of the org to unregister.
     org_id: Id,
 
+    /// Do not ask for confirmation.
+    #[structopt(long, short)]
+    yes: bool,
+
     #[structopt(flatten)]
     network_options: NetworkOptions,
 
@@ -147,6 +199,13 @@ pub struct Unregister {
 #[async_trait::async_trait]
 impl CommandT for Unregister {
     async fn run(self) -> Result<(), CommandError> {
+        if !self.yes {
+            confirm(&format!(
+                "This will permanently retire the id {}.",
+                self.org_id
+            ))?;
+        }
+
         let client = self.network_options.client().await?;
 
         let register_org_fut = client
@@ -191,14 +250,16 @@ pub struct Transfer {
 impl CommandT for Transfer {
     async fn run(self) -> Result<(), CommandError> {
         let client = self.network_options.client().await?;
+        let transfer_message = message::TransferFromOrg {
+            org_id: self.org_id.clone(),
+            recipient: self.recipient,
+            amount: self.amount,
+        };
+        announce_fee_payer(&client, &self.tx_options.author, &transfer_message).await?;
         let transfer_fut = client
             .sign_and_submit_message(
                 &self.tx_options.author,
-                message::TransferFromOrg {
-                    org_id: self.org_id.clone(),
-                    recipient: self.recipient,
-                    amount: self.amount,
-                },
+                transfer_message,
                 self.tx_options.fee,
             )
             .await?;
@@ -234,23 +295,113 @@ impl CommandT for RegisterMember {
     async fn run(self) -> Result<(), CommandError> {
         let client = self.network_options.client().await?;
 
+        let register_member_message = message::RegisterMember {
+            org_id: self.org_id.clone(),
+            user_id: self.user_id.clone(),
+        };
+        announce_fee_payer(&client, &self.tx_options.author, &register_member_message).await?;
         let register_member_fut = client
             .sign_and_submit_message(
                 &self.tx_options.author,
-                message::RegisterMember {
-                    org_id: self.org_id.clone(),
-                    user_id: self.user_id.clone(),
-                },
+                register_member_message,
                 self.tx_options.fee,
             )
             .await?;
         announce_tx("Registering member...");
 
-        register_member_fut.await?.result?;
+        let registered = register_member_fut.await?;
+        registered
+            .result
+            .map_err(|error| register_member_error(&self.org_id, &self.user_id, error))?;
         println!(
-            "✓ User {} is now a member of the Org {}.",
-            self.user_id, self.org_id
+            "✓ User {} is now a member of the Org {} in block {}.",
+            self.user_id, self.org_id, registered.block
         );
         Ok(())
     }
 }
+
+/// Turn the errors that [message::RegisterMember] commonly fails with into errors that name the
+/// org and the user.
+fn register_member_error(org_id: &Id, user_id: &Id, error: TransactionError) -> CommandError {
+    match error {
+        TransactionError::RegistryError(RegistryError::InsufficientSenderPermissions) => {
+            CommandError::NotOrgMember {
+                org_id: org_id.clone(),
+            }
+        }
+        TransactionError::RegistryError(RegistryError::InexistentUser) => {
+            CommandError::UserNotFound {
+                user_id: user_id.clone(),
+            }
+        }
+        error => CommandError::FailedTransaction(error),
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Leave {
+    /// Id of the org to leave.
+    org_id: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Leave {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let leave_org_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::LeaveOrg {
+                    org_id: self.org_id.clone(),
+                },
+                self.tx_options.fee,
+            )
+            .await?;
+        announce_tx("Leaving org...");
+
+        leave_org_fut.await?.result?;
+        println!("✓ You are no longer a member of the Org {}.", self.org_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_register_member_error() {
+        let org_id = Id::try_from("monadic").unwrap();
+        let user_id = Id::try_from("cloudhead").unwrap();
+
+        let error = register_member_error(
+            &org_id,
+            &user_id,
+            RegistryError::InsufficientSenderPermissions.into(),
+        );
+        assert_eq!(
+            error.to_string(),
+            "the author is not a member of org monadic"
+        );
+
+        let error = register_member_error(&org_id, &user_id, RegistryError::InexistentUser.into());
+        assert_eq!(error.to_string(), "cannot find user cloudhead");
+
+        let error = register_member_error(&org_id, &user_id, RegistryError::AlreadyAMember.into());
+        assert!(matches!(
+            error,
+            CommandError::FailedTransaction(TransactionError::RegistryError(
+                RegistryError::AlreadyAMember
+            ))
+        ));
+    }
+}
@@ -16,6 +16,8 @@
 //! Define the commands supported by the CLI related to Projects.
 
 use super::*;
+use std::convert::TryFrom as _;
+use std::path::{Path, PathBuf};
 use structopt::clap::arg_enum;
 
 /// Project related commands
@@ -25,6 +27,8 @@ pub enum Command {
     List(List),
     /// Register a project with the given name under the given org.
     Register(Register),
+    /// Unregister a project.
+    Unregister(Unregister),
 }
 
 #[async_trait::async_trait]
@@ -33,6 +37,7 @@ impl CommandT for Command {
         match self {
             Command::List(cmd) => cmd.run().await,
             Command::Register(cmd) => cmd.run().await,
+            Command::Unregister(cmd) => cmd.run().await,
         }
     }
 }
@@ -41,16 +46,27 @@ impl CommandT for Command {
 pub struct List {
     #[structopt(flatten)]
     network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    output_options: OutputOptions,
+
+    #[structopt(flatten)]
+    state_options: StateOptions,
 }
 
 #[async_trait::async_trait]
 impl CommandT for List {
     async fn run(self) -> Result<(), CommandError> {
-        let client = self.network_options.client().await?;
-        let project_ids = client.list_projects().await?;
-        println!("PROJECTS ({})", project_ids.len());
-        for (name, org) in project_ids {
-            println!("{}.{:?}", name, org)
+        let client = self.state_options.client(&self.network_options).await?;
+        let project_ids = client.registry().projects().list().await?;
+        match self.output_options.output {
+            OutputFormat::Json => print_json(&project_ids),
+            OutputFormat::Text => {
+                println!("PROJECTS ({})", project_ids.len());
+                for (name, org) in project_ids {
+                    println!("{}.{:?}", name, org)
+                }
+            }
         }
         Ok(())
     }
@@ -74,6 +90,20 @@ pub struct Register {
     /// Project state hash. A hex-encoded 32 byte string. Defaults to all zeros.
     project_hash: Option<H256>,
 
+    /// Hex-encoded project metadata of up to 128 bytes, e.g. a multihash. May be prefixed with
+    /// `0x`. Defaults to random metadata.
+    #[structopt(
+        long,
+        value_name = "hex",
+        parse(try_from_str = parse_metadata_hex),
+        conflicts_with = "metadata-file"
+    )]
+    metadata: Option<Bytes128>,
+
+    /// File with up to 128 bytes of project metadata. Defaults to random metadata.
+    #[structopt(long, value_name = "path")]
+    metadata_file: Option<PathBuf>,
+
     #[structopt(flatten)]
     network_options: NetworkOptions,
 
@@ -85,18 +115,22 @@ pub struct Register {
 impl CommandT for Register {
     async fn run(self) -> Result<(), CommandError> {
         let client = self.network_options.client().await?;
-        let project_domain = match self.domain_type {
-            DomainType::Org => ProjectDomain::Org(self.domain_id),
-            DomainType::User => ProjectDomain::User(self.domain_id),
+        let project_domain = self.domain_type.project_domain(self.domain_id);
+        let metadata = match (&self.metadata, &self.metadata_file) {
+            (Some(metadata), _) => metadata.clone(),
+            (None, Some(path)) => read_metadata_file(path)?,
+            (None, None) => Bytes128::random(),
         };
+        let register_project_message = message::RegisterProject {
+            project_name: self.project_name.clone(),
+            project_domain: project_domain.clone(),
+            metadata,
+        };
+        announce_fee_payer(&client, &self.tx_options.author, &register_project_message).await?;
         let register_project_fut = client
             .sign_and_submit_message(
                 &self.tx_options.author,
-                message::RegisterProject {
-                    project_name: self.project_name.clone(),
-                    project_domain: project_domain.clone(),
-                    metadata: Bytes128::random(),
-                },
+                register_project_message,
                 self.tx_options.fee,
             )
             .await?;
@@ -112,6 +146,98 @@ impl CommandT for Register {
     }
 }
 
+#[derive(StructOpt, Clone)]
+pub struct Unregister {
+    /// Name of the project to unregister.
+    project_name: ProjectName,
+
+    /// The type of domain of the project.
+    #[structopt(
+        possible_values = &DomainType::variants(),
+        case_insensitive = true,
+    )]
+    domain_type: DomainType,
+
+    /// The id of the domain of the project.
+    domain_id: Id,
+
+    /// Do not ask for confirmation.
+    #[structopt(long, short)]
+    yes: bool,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Unregister {
+    async fn run(self) -> Result<(), CommandError> {
+        let project_domain = self.domain_type.project_domain(self.domain_id);
+        if !self.yes {
+            confirm(&format!(
+                "This will unregister the project {}.{:?}.",
+                self.project_name, project_domain
+            ))?;
+        }
+
+        let client = self.network_options.client().await?;
+        let unregister_project_message = message::UnregisterProject {
+            project_name: self.project_name.clone(),
+            project_domain: project_domain.clone(),
+        };
+        announce_fee_payer(
+            &client,
+            &self.tx_options.author,
+            &unregister_project_message,
+        )
+        .await?;
+        let unregister_project_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                unregister_project_message,
+                self.tx_options.fee,
+            )
+            .await?;
+        announce_tx("Unregistering project...");
+
+        unregister_project_fut.await?.result?;
+        println!(
+            "✓ Project {}.{:?} is now unregistered.",
+            self.project_name, project_domain
+        );
+        Ok(())
+    }
+}
+
+/// Parse hex-encoded project metadata with an optional `0x` prefix. Fails if the metadata is
+/// larger than 128 bytes.
+fn parse_metadata_hex(data: &str) -> Result<Bytes128, String> {
+    let data = data.strip_prefix("0x").unwrap_or(data);
+    let bytes = hex::decode(data).map_err(|error| format!("invalid hex: {}", error))?;
+    Bytes128::try_from_slice(&bytes).map_err(|_| {
+        format!(
+            "metadata has {} bytes but is limited to 128 bytes",
+            bytes.len()
+        )
+    })
+}
+
+/// Read project metadata from the file at `path`. Fails if the file is larger than 128 bytes.
+fn read_metadata_file(path: &Path) -> Result<Bytes128, CommandError> {
+    let content = std::fs::read(path).map_err(|error| CommandError::MetadataFileUnreadable {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    let size = content.len();
+    Bytes128::try_from(content).map_err(|_| CommandError::MetadataFileTooLarge {
+        path: path.to_path_buf(),
+        size,
+    })
+}
+
 arg_enum! {
     #[derive(Clone, Eq, PartialEq, Debug)]
     enum DomainType {
@@ -120,6 +246,15 @@ arg_enum! {
     }
 }
 
+impl DomainType {
+    fn project_domain(&self, domain_id: Id) -> ProjectDomain {
+        match self {
+            DomainType::Org => ProjectDomain::Org(domain_id),
+            DomainType::User => ProjectDomain::User(domain_id),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -140,4 +275,43 @@ mod test {
             assert_eq!(res, Ok(DomainType::User));
         }
     }
+
+    #[test]
+    fn test_parse_metadata_hex() {
+        assert_eq!(
+            Vec::from(parse_metadata_hex("0x00abff").unwrap()),
+            vec![0x00, 0xab, 0xff]
+        );
+        assert_eq!(
+            Vec::from(parse_metadata_hex("abff").unwrap()),
+            vec![0xab, 0xff]
+        );
+        assert_eq!(Vec::from(parse_metadata_hex("").unwrap()), Vec::<u8>::new());
+        assert!(parse_metadata_hex("0xabf").is_err());
+        assert!(parse_metadata_hex(&"ab".repeat(129)).is_err());
+    }
+
+    #[test]
+    fn test_read_metadata_file() {
+        let dir = std::env::temp_dir().join(format!("radicle-registry-cli-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("metadata");
+        std::fs::write(&path, [7u8; 128]).unwrap();
+        let metadata = read_metadata_file(&path).unwrap();
+        assert_eq!(Vec::from(metadata), vec![7u8; 128]);
+
+        std::fs::write(&path, [7u8; 129]).unwrap();
+        assert!(matches!(
+            read_metadata_file(&path),
+            Err(CommandError::MetadataFileTooLarge { size: 129, .. })
+        ));
+
+        assert!(matches!(
+            read_metadata_file(&dir.join("missing")),
+            Err(CommandError::MetadataFileUnreadable { .. })
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
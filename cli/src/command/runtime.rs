@@ -20,7 +20,8 @@ use super::*;
 /// Runtime related commands
 #[derive(StructOpt, Clone)]
 pub enum Command {
-    /// Submit a transaction to update the on-chain runtime.
+    /// Submit a transaction to update the on-chain runtime. The author must be the chain's sudo
+    /// key.
     Update(Update),
 
     /// Show the version of the on-chain runtime.
@@ -53,23 +54,35 @@ pub struct Update {
 impl CommandT for Update {
     async fn run(self) -> Result<(), CommandError> {
         let client = self.network_options.client().await?;
+        ensure_sudo_key(&client, &self.tx_options.author).await?;
         let new_runtime_code =
             std::fs::read(self.path).expect("Invalid path or couldn't read the wasm file");
 
-        let update_runtime_fut = client
-            .sign_and_submit_message(
+        announce_tx("Submitting the new on-chain runtime...");
+        let version = client
+            .set_runtime_code(
                 &self.tx_options.author,
-                message::UpdateRuntime {
-                    code: new_runtime_code,
-                },
+                new_runtime_code,
                 self.tx_options.fee,
             )
             .await?;
-        announce_tx("Submitting the new on-chain runtime...");
+        println!(
+            "✓ The new on-chain runtime is now published at spec_version {}.",
+            version.spec_version
+        );
+        Ok(())
+    }
+}
 
-        update_runtime_fut.await?.result?;
-        println!("✓ The new on-chain runtime is now published.");
+/// Ensure that `author` is the sudo key of the chain.
+///
+/// Fails with [CommandError::NotSudoKey] otherwise.
+async fn ensure_sudo_key(client: &Client, author: &ed25519::Pair) -> Result<(), CommandError> {
+    let sudo_key = client.sudo_key().await?;
+    if author.public() == sudo_key {
         Ok(())
+    } else {
+        Err(CommandError::NotSudoKey { sudo_key })
     }
 }
 
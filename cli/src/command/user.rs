@@ -64,6 +64,7 @@ impl CommandT for Register {
                 &self.tx_options.author,
                 message::RegisterUser {
                     user_id: self.user_id.clone(),
+                    reservation_salt: None,
                 },
                 self.tx_options.fee,
             )
@@ -81,6 +82,10 @@ pub struct Unregister {
     /// Id of the org to unregister.
     user_id: Id,
 
+    /// Do not ask for confirmation.
+    #[structopt(long, short)]
+    yes: bool,
+
     #[structopt(flatten)]
     network_options: NetworkOptions,
 
@@ -91,6 +96,13 @@ pub struct Unregister {
 #[async_trait::async_trait]
 impl CommandT for Unregister {
     async fn run(self) -> Result<(), CommandError> {
+        if !self.yes {
+            confirm(&format!(
+                "This will permanently retire the id {}.",
+                self.user_id
+            ))?;
+        }
+
         let client = self.network_options.client().await?;
         let unregister_user = client
             .sign_and_submit_message(
@@ -116,25 +128,55 @@ pub struct Show {
 
     #[structopt(flatten)]
     network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    output_options: OutputOptions,
+
+    #[structopt(flatten)]
+    state_options: StateOptions,
+}
+
+/// JSON output of [Show].
+#[derive(serde::Serialize)]
+struct UserInfo {
+    id: Id,
+    /// SS58 address of the user account.
+    account_id: String,
+    balance: Balance,
+    projects: Vec<ProjectName>,
+    registered_at: BlockNumber,
 }
 
 #[async_trait::async_trait]
 impl CommandT for Show {
     async fn run(self) -> Result<(), CommandError> {
-        let client = self.network_options.client().await?;
-        let user =
-            client
-                .get_user(self.user_id.clone())
-                .await?
-                .ok_or(CommandError::UserNotFound {
-                    user_id: self.user_id.clone(),
-                })?;
+        let client = self.state_options.client(&self.network_options).await?;
+        let user = client
+            .registry()
+            .users()
+            .get(self.user_id.clone())
+            .await?
+            .ok_or(CommandError::UserNotFound {
+                user_id: self.user_id.clone(),
+            })?;
         let balance = client.free_balance(&user.account_id()).await?;
 
+        if self.output_options.output == OutputFormat::Json {
+            print_json(&UserInfo {
+                id: self.user_id,
+                account_id: user.account_id().to_ss58check(),
+                balance,
+                projects: user.projects().clone(),
+                registered_at: user.registered_at(),
+            });
+            return Ok(());
+        }
+
         println!("id: {}", self.user_id);
         println!("account id: {}", user.account_id());
         println!("balance: {} μRAD", balance);
         println!("projects: [{}]", user.projects().iter().format(", "));
+        println!("registered at block: {}", user.registered_at());
         Ok(())
     }
 }
@@ -143,16 +185,27 @@ impl CommandT for Show {
 pub struct List {
     #[structopt(flatten)]
     network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    output_options: OutputOptions,
+
+    #[structopt(flatten)]
+    state_options: StateOptions,
 }
 
 #[async_trait::async_trait]
 impl CommandT for List {
     async fn run(self) -> Result<(), CommandError> {
-        let client = self.network_options.client().await?;
-        let user_ids = client.list_users().await?;
-        println!("USERS ({})", user_ids.len());
-        for user_id in user_ids {
-            println!("{}", user_id)
+        let client = self.state_options.client(&self.network_options).await?;
+        let user_ids = client.registry().users().list().await?;
+        match self.output_options.output {
+            OutputFormat::Json => print_json(&user_ids),
+            OutputFormat::Text => {
+                println!("USERS ({})", user_ids.len());
+                for user_id in user_ids {
+                    println!("{}", user_id)
+                }
+            }
         }
         Ok(())
     }
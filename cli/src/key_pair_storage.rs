@@ -16,7 +16,10 @@
 //! Manages key pairs stored in the filesystem,
 //! providing ways to store and retrieve them.
 
+use chrono::{DateTime, Utc};
 use directories::BaseDirs;
+use sp_core::crypto::{Pair as _, Ss58Codec as _};
+use sp_core::ed25519;
 use sp_core::serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::File;
@@ -55,9 +58,63 @@ enum VersionedFile {
 /// The data that is stored in the filesystem relative
 /// to a key pair. The name of the key pair is used as
 /// the key to this value, therefore not included here.
+///
+/// All fields but `seed` are optional so that files written
+/// before they were added are still valid.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct KeyPairData {
     pub seed: Seed,
+
+    /// A free-form note to describe the key pair.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// The SS58 address of the key pair, cached so that it
+    /// can be read without deriving the key pair.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+
+    /// When the key pair was added to the storage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl KeyPairData {
+    /// Create the data for a key pair with the given seed
+    /// and no metadata.
+    pub fn new(seed: Seed) -> Self {
+        KeyPairData {
+            seed,
+            label: None,
+            address: None,
+            created_at: None,
+        }
+    }
+
+    /// The SS58 address of the key pair. Uses the cached
+    /// `address` if present.
+    pub fn ss58_address(&self) -> String {
+        self.address
+            .clone()
+            .unwrap_or_else(|| ed25519::Pair::from_seed(&self.seed).public().to_ss58check())
+    }
+
+    /// Derive a child key pair from this key pair's seed
+    /// following the derivation `path`, e.g. `//hard//path`.
+    ///
+    /// Uses the standard substrate derivation that is also
+    /// used by polkadot.js. ed25519 keys only support hard
+    /// junctions so soft junctions (`/soft`) are rejected.
+    pub fn derive(&self, path: &str) -> Result<KeyPairData, Error> {
+        let invalid_path = || Error::InvalidDerivationPath(path.to_string());
+        if !path.starts_with("//") || path.contains("///") {
+            return Err(invalid_path());
+        }
+        let secret_uri = format!("0x{}{}", hex::encode(self.seed), path);
+        let (_, seed) =
+            ed25519::Pair::from_string_with_seed(&secret_uri, None).map_err(|_| invalid_path())?;
+        seed.map(KeyPairData::new).ok_or_else(invalid_path)
+    }
 }
 
 /// The seed from which a key pair
@@ -89,6 +146,10 @@ pub enum Error {
     /// Could not find a key pair with the given name
     #[error("Could not find a key pair with the given name")]
     NotFound(),
+
+    /// The derivation path is malformed or contains soft junctions
+    #[error("Invalid derivation path '{0}'. Only hard junctions like '//hard' are supported")]
+    InvalidDerivationPath(String),
 }
 
 fn io_error_message(action: &str) -> String {
@@ -135,14 +196,19 @@ pub fn list() -> Result<HashMap<String, KeyPairData>, Error> {
 
 /// Add a key pair to the storage.
 ///
+/// Sets the `address` and `created_at` fields of `data`
+/// if they are not given.
+///
 /// Fails if a key pair with the given `name` already exists.
 /// It can also fail from IO and Serde Json errors.
-pub fn add(name: String, data: KeyPairData) -> Result<(), Error> {
+pub fn add(name: String, mut data: KeyPairData) -> Result<(), Error> {
     let mut key_pairs = list()?;
     if key_pairs.contains_key(&name) {
         return Err(Error::AlreadyExists());
     }
 
+    data.address = Some(data.ss58_address());
+    data.created_at.get_or_insert_with(Utc::now);
     key_pairs.insert(name, data);
     update(key_pairs)
 }
@@ -218,3 +284,69 @@ fn init_file(path: &Path) -> Result<(), Error> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Test that key pair data without metadata, as written by
+    /// earlier versions, is parsed.
+    #[test]
+    fn parse_data_without_metadata() {
+        let seed = [1u8; 32];
+        let seed_json = serde_json::to_string(&seed).unwrap();
+
+        let unversioned = format!(r#"{{"alice": {{"seed": {}}}}}"#, seed_json);
+        let file: KeyStorageFile = serde_json::from_str(&unversioned).unwrap();
+        let mut key_pairs = HashMap::new();
+        key_pairs.insert(String::from("alice"), KeyPairData::new(seed));
+        assert_eq!(file, KeyStorageFile::Unversioned(key_pairs.clone()));
+
+        let versioned = format!(
+            r#"{{"version": "1", "key_pairs": {{"alice": {{"seed": {}}}}}}}"#,
+            seed_json
+        );
+        let file: KeyStorageFile = serde_json::from_str(&versioned).unwrap();
+        assert_eq!(
+            file,
+            KeyStorageFile::Versioned(VersionedFile::V1 { key_pairs })
+        );
+    }
+
+    /// Test that key pair metadata survives a serialization
+    /// roundtrip.
+    #[test]
+    fn metadata_roundtrip() {
+        let seed = [2u8; 32];
+        let data = KeyPairData {
+            seed,
+            label: Some(String::from("cold storage")),
+            address: Some(KeyPairData::new(seed).ss58_address()),
+            created_at: Some(Utc::now()),
+        };
+        let json = serde_json::to_string(&data).unwrap();
+        let parsed: KeyPairData = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, data);
+    }
+
+    /// Test that deriving along a path is the same as deriving
+    /// along each junction in turn and that soft junctions are
+    /// rejected.
+    #[test]
+    fn derive() {
+        let parent = KeyPairData::new([3u8; 32]);
+
+        let child = parent.derive("//foo//bar").unwrap();
+        let child_stepwise = parent.derive("//foo").unwrap().derive("//bar").unwrap();
+        assert_eq!(child, child_stepwise);
+        assert_ne!(child.seed, parent.seed);
+
+        for path in &["", "foo", "/soft", "//hard/soft", "///password"] {
+            assert!(
+                matches!(parent.derive(path), Err(Error::InvalidDerivationPath(_))),
+                "Derivation path '{}' was not rejected",
+                path
+            );
+        }
+    }
+}
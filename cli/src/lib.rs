@@ -19,13 +19,13 @@
 
 use lazy_static::lazy_static;
 use radicle_registry_client::*;
-use structopt::StructOpt;
+use structopt::{clap::arg_enum, StructOpt};
 use thiserror::Error as ThisError;
 
 pub mod key_pair_storage;
 
 mod command;
-use command::{account, key_pair, org, other, project, runtime, user};
+use command::{account, admin, id, key_pair, org, other, project, runtime, user};
 
 /// The type that captures the command line.
 #[derive(StructOpt, Clone)]
@@ -55,6 +55,10 @@ pub struct NetworkOptions {
         parse(try_from_str = Self::parse_node_host),
     )]
     pub node_host: url::Host,
+
+    /// Fail if the node is not running the chain with this name
+    #[structopt(long, env = "RAD_CHAIN", value_name = "name")]
+    pub chain: Option<String>,
 }
 
 impl NetworkOptions {
@@ -70,7 +74,31 @@ impl NetworkOptions {
     }
 
     pub async fn client(&self) -> Result<Client, Error> {
-        Client::create_with_executor(self.node_host.clone()).await
+        let client = Client::create_with_executor(self.node_host.clone()).await?;
+        if let Some(chain) = &self.chain {
+            client.ensure_chain_name(chain).await?;
+        }
+        Ok(client)
+    }
+}
+
+/// Command-line options for commands that read the registry state
+#[derive(StructOpt, Clone, Debug)]
+pub struct StateOptions {
+    /// Read the state at the block with this hash instead of the best block.
+    /// The node must run with `--archive` to read the state of old blocks.
+    #[structopt(long, value_name = "block_hash")]
+    pub at: Option<BlockHash>,
+}
+
+impl StateOptions {
+    /// Return a client that reads the state at the block given by [StateOptions::at].
+    pub async fn client(&self, network_options: &NetworkOptions) -> Result<Client, Error> {
+        let client = network_options.client().await?;
+        Ok(match self.at {
+            Some(block_hash) => client.at(block_hash),
+            None => client,
+        })
     }
 }
 
@@ -92,6 +120,29 @@ pub struct TxOptions {
     pub fee: Balance,
 }
 
+/// Output-related command-line options
+#[derive(StructOpt, Clone, Debug)]
+pub struct OutputOptions {
+    /// Format of the command output. JSON output is meant to be consumed by scripts.
+    #[structopt(
+        long,
+        default_value = "text",
+        env = "RAD_OUTPUT",
+        value_name = "format",
+        possible_values = &OutputFormat::variants(),
+        case_insensitive = true,
+    )]
+    pub output: OutputFormat,
+}
+
+arg_enum! {
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum OutputFormat {
+        Text,
+        Json,
+    }
+}
+
 lazy_static! {
     static ref FEE_DEFAULT: String = MINIMUM_TX_FEE.to_string();
 }
@@ -107,6 +158,8 @@ fn lookup_key_pair(name: &str) -> Result<ed25519::Pair, String> {
 #[derive(StructOpt, Clone)]
 pub enum Command {
     Account(account::Command),
+    Admin(admin::Command),
+    Id(id::Command),
     KeyPair(key_pair::Command),
     Org(org::Command),
     Project(project::Command),
@@ -122,6 +175,8 @@ impl CommandT for Command {
     async fn run(self) -> Result<(), CommandError> {
         match self.clone() {
             Command::Account(cmd) => cmd.run().await,
+            Command::Admin(cmd) => cmd.run().await,
+            Command::Id(cmd) => cmd.run().await,
             Command::KeyPair(cmd) => cmd.run().await,
             Command::Org(cmd) => cmd.run().await,
             Command::Project(cmd) => cmd.run().await,
@@ -155,6 +210,9 @@ pub enum CommandError {
     #[error("cannot find user {user_id}")]
     UserNotFound { user_id: Id },
 
+    #[error("the author is not a member of org {org_id}")]
+    NotOrgMember { org_id: Id },
+
     #[error("cannot find project {project_name}.{project_domain:?}")]
     ProjectNotFound {
         project_name: ProjectName,
@@ -163,4 +221,33 @@ pub enum CommandError {
 
     #[error(transparent)]
     KeyPairStorageError(#[from] key_pair_storage::Error),
+
+    #[error("aborted by user")]
+    Aborted,
+
+    #[error("the author is not the sudo key {sudo_key}")]
+    NotSudoKey { sudo_key: AccountId },
+
+    #[error("cannot read metadata file {path:?}")]
+    MetadataFileUnreadable {
+        path: std::path::PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("metadata file {path:?} has {size} bytes but metadata is limited to 128 bytes")]
+    MetadataFileTooLarge {
+        path: std::path::PathBuf,
+        size: usize,
+    },
+
+    #[error("cannot read transfers file {path:?}")]
+    TransfersFileUnreadable {
+        path: std::path::PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("invalid transfer on line {line}: {reason}")]
+    InvalidTransfer { line: usize, reason: String },
 }
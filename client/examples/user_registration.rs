@@ -35,6 +35,7 @@ async fn main() {
             &alice,
             message::RegisterUser {
                 user_id: user_id.clone(),
+                reservation_salt: None,
             },
             100,
         )
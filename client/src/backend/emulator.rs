@@ -15,17 +15,24 @@
 
 //! Provides [Emulator] backend to run the registry ledger in memory.
 
+use futures::channel::mpsc;
 use futures::future::BoxFuture;
+use futures::stream::{BoxStream, StreamExt as _};
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 
+use frame_support::storage::StorageValue as _;
+use sp_core::storage::{StorageData, StorageKey};
+use sp_inherents::ProvideInherentData as _;
+use sp_runtime::transaction_validity::{InvalidTransaction, TransactionValidityError};
 use sp_runtime::{traits::Block as _, traits::Hash as _, BuildStorage as _, Digest};
 use sp_state_machine::backend::Backend as _;
 
 use radicle_registry_runtime::{
     event,
     genesis::{BalancesConfig, GenesisConfig},
-    registry, runtime_api, AccountId, Block, Hash, Hashing, Header, Runtime, RuntimeVersion,
+    registry, runtime_api, store, AccountId, Block, Hash, Hashing, Header, Runtime, RuntimeVersion,
 };
 
 use crate::backend;
@@ -41,13 +48,27 @@ use crate::interface::*;
 /// * Every [backend::Backend::submit] call creates a new block that only contains the submited
 ///   transaction.
 ///
-/// * The responses returned from the client never result in an [Error].
+/// * The responses returned from the client never result in an [Error] except for
+///   [Error::InvalidTransaction], [Error::BlockResourcesExhausted], or [Error::AncientEraBlock]
+///   when a submitted transaction is invalid. The emulator still creates an empty block in that
+///   case. Reading the state of a block other than the chain tip fails with
+///   [Error::Unsupported].
 ///
-/// * The block author is fixed to [BLOCK_AUTHOR].
+/// * Blocks are authored by [BLOCK_AUTHOR] except for blocks created with
+///   [EmulatorControl::seal_block].
+///
+/// * Block timestamps are taken from the system clock unless they are set with
+///   [EmulatorControl::set_timestamp].
+///
+/// * [backend::Backend::subscribe_new_heads] only yields blocks that are created after the
+///   subscription.
+///
+/// * The chain name is `"emulator"`.
+///
+/// * Blocks are not sealed. [backend::Backend::pow_algorithm] reports [PowKind::Dummy].
 #[derive(Clone)]
 pub struct Emulator {
     genesis_hash: Hash,
-    inherent_data_providers: sp_inherents::InherentDataProviders,
     state: Arc<Mutex<EmulatorState>>,
 }
 
@@ -75,58 +96,127 @@ impl EmulatorControl {
     /// ```
     pub fn add_blocks(&self, count: u32) {
         for _ in 0..count {
-            self.0.add_block(vec![]);
+            self.0.add_block(vec![], BLOCK_AUTHOR);
         }
     }
+
+    /// Adds an empty block authored by `author` to the emulator chain and returns its hash.
+    ///
+    /// The block is finalized like any other block, so `author` is credited the
+    /// [crate::BLOCK_REWARD].
+    ///
+    /// ```
+    /// # #[async_std::main]
+    /// # async fn main () {
+    /// # use radicle_registry_client::{ed25519, Client, ClientT, CryptoPair as _, BLOCK_REWARD};
+    /// let (client, emulator) = Client::new_emulator();
+    /// let author = ed25519::Pair::generate().0.public();
+    /// let block_hash = emulator.seal_block(author);
+    /// assert!(client.block_header(block_hash).await.unwrap().is_some());
+    /// assert_eq!(client.free_balance(&author).await.unwrap(), BLOCK_REWARD);
+    /// # }
+    /// ```
+    pub fn seal_block(&self, author: AccountId) -> BlockHash {
+        let (block, _, _) = self.0.add_block(vec![], author);
+        block.hash()
+    }
+
+    /// Adds `count` number of empty blocks to the emulator chain. The timestamp of each block is
+    /// `interval` milliseconds after the timestamp of the previous block.
+    pub fn advance_blocks(&self, count: u32, interval: u64) {
+        for _ in 0..count {
+            self.set_timestamp(self.timestamp() + interval);
+            self.0.add_block(vec![], BLOCK_AUTHOR);
+        }
+    }
+
+    /// Set the timestamp in milliseconds since the unix epoch used for the next block.
+    ///
+    /// The runtime requires the timestamps of consecutive blocks to be at least the minimum
+    /// period of the timestamp module apart. Earlier timestamps are increased accordingly.
+    pub fn set_timestamp(&self, timestamp: u64) {
+        self.0.state.lock().unwrap().next_timestamp = Some(timestamp);
+    }
+
+    /// Return the timestamp of the best block in milliseconds since the unix epoch.
+    pub fn timestamp(&self) -> u64 {
+        let mut state = self.0.state.lock().unwrap();
+        state.test_ext.execute_with(store::Timestamp::get)
+    }
 }
 
 /// Mutable state of the emulator.
 struct EmulatorState {
     test_ext: sp_io::TestExternalities,
     tip_header: Header,
-    headers: HashMap<BlockHash, Header>,
+    blocks: HashMap<BlockHash, Block>,
+    /// Events emitted in the blocks stored in `blocks`.
+    block_event_records: HashMap<BlockHash, Vec<event::Record>>,
+    /// Senders for the streams returned by [backend::Backend::subscribe_new_heads].
+    new_heads_senders: Vec<mpsc::UnboundedSender<Header>>,
+    /// Timestamp for the next block set with [EmulatorControl::set_timestamp].
+    next_timestamp: Option<u64>,
 }
 
+impl EmulatorState {
+    /// Fail with [Error::Unsupported] if `block_hash` is given and is not the hash of the chain
+    /// tip. The emulator only holds the state of the tip.
+    fn check_tip(&self, block_hash: Option<BlockHash>) -> Result<(), Error> {
+        match block_hash {
+            Some(block_hash) if block_hash != self.tip_header.hash() => Err(Error::Unsupported {
+                operation: "reading the state of a block other than the chain tip",
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Chain name reported by [backend::Backend::chain_name].
+const CHAIN_NAME: &str = "emulator";
+
 /// Block author account used when the emulator creates blocks.
 pub const BLOCK_AUTHOR: AccountId = ed25519::Public([0u8; 32]);
 
 impl Emulator {
     pub fn new() -> Self {
-        let genesis_config = make_genesis_config();
-        let mut test_ext = sp_io::TestExternalities::new(genesis_config.build_storage().unwrap());
-        let genesis_hash = init_runtime(&mut test_ext);
-
-        let registry_inherent_data = registry::AuthoringInherentData {
-            block_author: BLOCK_AUTHOR,
-        };
+        Self::from_genesis_config(default_genesis_config())
+    }
 
-        let inherent_data_providers = sp_inherents::InherentDataProviders::new();
+    /// Create an emulator with the genesis state built from `genesis_config`.
+    pub fn from_genesis_config(genesis_config: GenesisConfig) -> Self {
+        Self::from_storage(genesis_config.build_storage().unwrap())
+    }
 
-        // Can only fail if a provider with the same name is already registered.
-        inherent_data_providers
-            .register_provider(sp_timestamp::InherentDataProvider)
-            .unwrap();
-        inherent_data_providers
-            .register_provider(registry_inherent_data)
-            .unwrap();
+    /// Create an emulator that uses `storage` as the genesis state.
+    pub fn from_storage(storage: sp_core::storage::Storage) -> Self {
+        let mut test_ext = sp_io::TestExternalities::new(storage);
+        let genesis_hash = init_runtime(&mut test_ext);
 
         let tip_header = Header {
             parent_hash: Hash::zero(),
-            number: 1,
+            number: 0,
             state_root: Hash::zero(),
             extrinsics_root: Hash::zero(),
             digest: Digest::default(),
         };
-        let mut headers = HashMap::new();
-        headers.insert(tip_header.hash(), tip_header.clone());
+        let mut blocks = HashMap::new();
+        blocks.insert(
+            tip_header.hash(),
+            Block {
+                header: tip_header.clone(),
+                extrinsics: Vec::new(),
+            },
+        );
 
         Emulator {
             genesis_hash,
-            inherent_data_providers,
             state: Arc::new(Mutex::new(EmulatorState {
                 test_ext,
                 tip_header,
-                headers,
+                blocks,
+                block_event_records: HashMap::new(),
+                new_heads_senders: Vec::new(),
+                next_timestamp: None,
             })),
         }
     }
@@ -135,12 +225,17 @@ impl Emulator {
         EmulatorControl(self.clone())
     }
 
-    /// Add a block with `extrinsics` to the chain. Returns the added block and a list of events
-    /// recorded during the execution of the block.
+    /// Add a block with `extrinsics` authored by `author` to the chain. Returns the added block,
+    /// a list of events recorded during the execution of the block, and the validity errors of
+    /// the rejected extrinsics.
+    ///
+    /// Extrinsics that are invalid are not included in the block, like a node would reject them
+    /// from its transaction pool.
     fn add_block(
         &self,
         extrinsics: Vec<backend::UncheckedExtrinsic>,
-    ) -> (Block, Vec<event::Record>) {
+        author: AccountId,
+    ) -> (Block, Vec<event::Record>, Vec<TransactionValidityError>) {
         let mut state = self.state.lock().unwrap();
 
         let new_tip_header_init = Header {
@@ -149,27 +244,81 @@ impl Emulator {
             ..state.tip_header.clone()
         };
 
-        let (block, event_records) = state.test_ext.execute_with(move || {
+        let timestamp = state
+            .next_timestamp
+            .take()
+            .unwrap_or_else(system_time_timestamp);
+
+        let (block, event_records, rejected) = state.test_ext.execute_with(move || {
             runtime_api::initialize_block(&new_tip_header_init);
 
-            let inherent_data = self.inherent_data_providers.create_inherent_data().unwrap();
+            let mut inherent_data = sp_inherents::InherentData::new();
+            registry::AuthoringInherentData {
+                block_author: author,
+            }
+            .provide_inherent_data(&mut inherent_data)
+            .unwrap();
+            inherent_data
+                .put_data(sp_timestamp::INHERENT_IDENTIFIER, &timestamp)
+                .unwrap();
             let inherents = runtime_api::inherent_extrinsics(inherent_data);
-            let extrinsics = [inherents, extrinsics].concat();
+            for inherent in &inherents {
+                let _apply_result = runtime_api::apply_extrinsic(inherent.clone()).unwrap();
+            }
 
-            for extrinsic in &extrinsics {
-                let _apply_result = runtime_api::apply_extrinsic(extrinsic.clone()).unwrap();
+            let mut included_extrinsics = inherents;
+            let mut rejected = Vec::new();
+            for extrinsic in extrinsics {
+                match runtime_api::apply_extrinsic(extrinsic.clone()) {
+                    Ok(_) => included_extrinsics.push(extrinsic),
+                    Err(error) => rejected.push(error),
+                }
             }
 
             let header = runtime_api::finalize_block();
             let event_records = frame_system::Module::<Runtime>::events();
 
-            (Block { header, extrinsics }, event_records)
+            (
+                Block {
+                    header,
+                    extrinsics: included_extrinsics,
+                },
+                event_records,
+                rejected,
+            )
         });
 
         state.tip_header = block.header.clone();
-        state.headers.insert(block.hash(), block.header.clone());
+        state.blocks.insert(block.hash(), block.clone());
+        state
+            .block_event_records
+            .insert(block.hash(), event_records.clone());
+        let header = block.header.clone();
+        state
+            .new_heads_senders
+            .retain(|sender| sender.unbounded_send(header.clone()).is_ok());
+
+        (block, event_records, rejected)
+    }
 
-        (block, event_records)
+    /// Add a block that includes `extrinsic` to the chain.
+    ///
+    /// Fails if the extrinsic is invalid. The block is added without the extrinsic in that case.
+    fn add_block_with_extrinsic(
+        &self,
+        extrinsic: backend::UncheckedExtrinsic,
+    ) -> Result<(Block, Vec<event::Record>), Error> {
+        let (block, event_records, rejected) = self.add_block(vec![extrinsic], BLOCK_AUTHOR);
+        match rejected.into_iter().next() {
+            Some(TransactionValidityError::Invalid(InvalidTransaction::ExhaustsResources)) => {
+                Err(Error::BlockResourcesExhausted)
+            }
+            Some(TransactionValidityError::Invalid(InvalidTransaction::AncientBirthBlock)) => {
+                Err(Error::AncientEraBlock)
+            }
+            Some(_) => Err(Error::InvalidTransaction),
+            None => Ok((block, event_records)),
+        }
     }
 }
 
@@ -180,7 +329,7 @@ impl backend::Backend for Emulator {
         extrinsic: backend::UncheckedExtrinsic,
     ) -> Result<BoxFuture<'static, Result<backend::TransactionIncluded, Error>>, Error> {
         let tx_hash = Hashing::hash_of(&extrinsic);
-        let (block, event_records) = self.add_block(vec![extrinsic]);
+        let (block, event_records) = self.add_block_with_extrinsic(extrinsic)?;
         let event_records = event_records.into_iter().collect();
 
         let events =
@@ -196,30 +345,58 @@ impl backend::Backend for Emulator {
         ))))
     }
 
+    async fn submit_tracked(
+        &self,
+        extrinsic: backend::UncheckedExtrinsic,
+    ) -> Result<BoxStream<'static, Result<backend::TransactionProgress, Error>>, Error> {
+        // The transaction is included in a new block right away so we report all progress at
+        // once.
+        let tx_included = self.submit(extrinsic).await?.await?;
+        let progress = vec![
+            Ok(backend::TransactionProgress::InPool),
+            Ok(backend::TransactionProgress::InBlock(tx_included.block)),
+            Ok(backend::TransactionProgress::Included(tx_included)),
+        ];
+        Ok(futures::stream::iter(progress).boxed())
+    }
+
+    async fn submit_nowait(&self, extrinsic: backend::UncheckedExtrinsic) -> Result<TxHash, Error> {
+        let tx_hash = Hashing::hash_of(&extrinsic);
+        self.add_block_with_extrinsic(extrinsic)?;
+        Ok(tx_hash)
+    }
+
     async fn fetch(
         &self,
         key: &[u8],
         block_hash: Option<BlockHash>,
     ) -> Result<Option<Vec<u8>>, Error> {
-        if block_hash.is_some() {
-            panic!("Passing a block hash 'fetch' for the client emulator is not supported")
-        }
-
         let mut state = self.state.lock().unwrap();
+        state.check_tip(block_hash)?;
         let maybe_data = state.test_ext.execute_with(|| sp_io::storage::get(key));
         Ok(maybe_data)
     }
 
+    async fn fetch_many(
+        &self,
+        keys: Vec<Vec<u8>>,
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<Option<Vec<u8>>>, Error> {
+        let mut state = self.state.lock().unwrap();
+        state.check_tip(block_hash)?;
+        let values = state
+            .test_ext
+            .execute_with(|| keys.iter().map(|key| sp_io::storage::get(key)).collect());
+        Ok(values)
+    }
+
     async fn fetch_keys(
         &self,
         prefix: &[u8],
         block_hash: Option<BlockHash>,
     ) -> Result<Vec<Vec<u8>>, Error> {
-        if block_hash.is_some() {
-            panic!("Passing a block hash 'fetch_keys' for the client emulator is not supported")
-        }
-
         let state = self.state.lock().unwrap();
+        state.check_tip(block_hash)?;
         let backend = state.test_ext.commit_all();
 
         let mut keys = Vec::new();
@@ -227,6 +404,21 @@ impl backend::Backend for Emulator {
         Ok(keys)
     }
 
+    async fn count_keys(&self, prefix: &[u8], block_hash: Option<BlockHash>) -> Result<u64, Error> {
+        let state = self.state.lock().unwrap();
+        state.check_tip(block_hash)?;
+        let backend = state.test_ext.commit_all();
+
+        let mut count = 0;
+        backend.for_keys_with_prefix(prefix, |_| count += 1);
+        Ok(count)
+    }
+
+    async fn pending_extrinsics(&self) -> Result<Vec<backend::UncheckedExtrinsic>, Error> {
+        // Transactions are applied immediately on submission so there are never any pending.
+        Ok(Vec::new())
+    }
+
     async fn block_header(
         &self,
         block_hash_opt: Option<BlockHash>,
@@ -236,22 +428,140 @@ impl backend::Backend for Emulator {
             Some(block_hash) => block_hash,
             None => return Ok(Some(state.tip_header.clone())),
         };
-        Ok(state.headers.get(&block_hash).cloned())
+        Ok(state
+            .blocks
+            .get(&block_hash)
+            .map(|block| block.header.clone()))
+    }
+
+    async fn block_hash(&self, block_number: BlockNumber) -> Result<Option<BlockHash>, Error> {
+        let state = self.state.lock().unwrap();
+        Ok(state
+            .blocks
+            .iter()
+            .find(|(_, block)| block.header.number == block_number)
+            .map(|(block_hash, _)| *block_hash))
+    }
+
+    async fn block_hashes(
+        &self,
+        block_numbers: Vec<BlockNumber>,
+    ) -> Result<Vec<Option<BlockHash>>, Error> {
+        let state = self.state.lock().unwrap();
+        Ok(block_numbers
+            .into_iter()
+            .map(|block_number| {
+                state
+                    .blocks
+                    .iter()
+                    .find(|(_, block)| block.header.number == block_number)
+                    .map(|(block_hash, _)| *block_hash)
+            })
+            .collect())
+    }
+
+    async fn block_events(&self, block_hash: BlockHash) -> Result<Vec<Event>, Error> {
+        let state = self.state.lock().unwrap();
+        Ok(state
+            .block_event_records
+            .get(&block_hash)
+            .map(|records| records.iter().map(|record| record.event.clone()).collect())
+            .unwrap_or_default())
+    }
+
+    async fn block_event_records(
+        &self,
+        block_hash: BlockHash,
+    ) -> Result<Vec<event::Record>, Error> {
+        let state = self.state.lock().unwrap();
+        Ok(state
+            .block_event_records
+            .get(&block_hash)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn block(&self, block_hash: BlockHash) -> Result<Option<Block>, Error> {
+        let state = self.state.lock().unwrap();
+        Ok(state.blocks.get(&block_hash).cloned())
+    }
+
+    async fn subscribe_new_heads(
+        &self,
+    ) -> Result<BoxStream<'static, Result<BlockHeader, Error>>, Error> {
+        let (sender, receiver) = mpsc::unbounded();
+        self.state.lock().unwrap().new_heads_senders.push(sender);
+        Ok(receiver.map(Ok).boxed())
     }
 
     fn get_genesis_hash(&self) -> Hash {
         self.genesis_hash
     }
 
-    async fn runtime_version(&self) -> Result<RuntimeVersion, Error> {
+    async fn runtime_version(
+        &self,
+        _block_hash: Option<BlockHash>,
+    ) -> Result<RuntimeVersion, Error> {
+        // The emulator always runs the native runtime.
         Ok(radicle_registry_runtime::VERSION)
     }
+
+    async fn chain_name(&self) -> Result<String, Error> {
+        Ok(String::from(CHAIN_NAME))
+    }
+
+    async fn pow_algorithm(&self) -> Result<PowKind, Error> {
+        Ok(PowKind::Dummy)
+    }
+
+    async fn total_difficulty(&self, block_hash: BlockHash) -> Result<Option<U256>, Error> {
+        // Like the dummy proof-of-work of the node every block has difficulty one.
+        let state = self.state.lock().unwrap();
+        Ok(state
+            .blocks
+            .get(&block_hash)
+            .map(|block| U256::from(block.header.number)))
+    }
+}
+
+/// Read the genesis storage from a raw chain spec JSON file.
+///
+/// Raw chain specs are created with `radicle-registry-node build-spec --raw`.
+pub fn read_raw_genesis_storage(path: &Path) -> Result<sp_core::storage::Storage, Error> {
+    #[derive(serde::Deserialize)]
+    struct RawChainSpec {
+        genesis: RawGenesis,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct RawGenesis {
+        raw: RawStorage,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct RawStorage {
+        top: HashMap<StorageKey, StorageData>,
+    }
+
+    let file = std::fs::File::open(path).map_err(Error::ChainSpecRead)?;
+    let chain_spec: RawChainSpec =
+        serde_json::from_reader(std::io::BufReader::new(file)).map_err(Error::InvalidChainSpec)?;
+    Ok(sp_core::storage::Storage {
+        top: chain_spec
+            .genesis
+            .raw
+            .top
+            .into_iter()
+            .map(|(key, value)| (key.0, value.0))
+            .collect(),
+        children_default: Default::default(),
+    })
 }
 
-/// Create [GenesisConfig] for the emulated chain.
+/// [GenesisConfig] used by [Emulator::new].
 ///
 /// Initializes the balance of the `//Alice` account with `2^60` tokens.
-fn make_genesis_config() -> GenesisConfig {
+pub fn default_genesis_config() -> GenesisConfig {
     GenesisConfig {
         pallet_balances: Some(BalancesConfig {
             balances: vec![(
@@ -262,10 +572,19 @@ fn make_genesis_config() -> GenesisConfig {
             )],
         }),
         pallet_sudo: None,
+        registry: None,
         system: None,
     }
 }
 
+/// Current system time in milliseconds since the unix epoch.
+fn system_time_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("System time is before the unix epoch")
+        .as_millis() as u64
+}
+
 /// Initialize the runtime state so that it is usable and return the genesis hash.
 fn init_runtime(test_ext: &mut sp_io::TestExternalities) -> Hash {
     test_ext.execute_with(|| {
@@ -15,17 +15,21 @@
 
 //! Define trait for client backends and provide emulator and remote node implementation
 use futures::future::BoxFuture;
+use futures::stream::BoxStream;
 
-pub use radicle_registry_runtime::{Hash, Header, RuntimeVersion, UncheckedExtrinsic};
+pub use radicle_registry_runtime::{Block, Hash, Header, RuntimeVersion, UncheckedExtrinsic};
 
-use crate::event::Event;
+use crate::event::{self, Event};
 use crate::interface::*;
 
 mod emulator;
 mod remote_node;
 mod remote_node_with_executor;
 
-pub use emulator::{Emulator, EmulatorControl, BLOCK_AUTHOR as EMULATOR_BLOCK_AUTHOR};
+pub use emulator::{
+    default_genesis_config as emulator_genesis_config, read_raw_genesis_storage, Emulator,
+    EmulatorControl, BLOCK_AUTHOR as EMULATOR_BLOCK_AUTHOR,
+};
 pub use remote_node::RemoteNode;
 pub use remote_node_with_executor::RemoteNodeWithExecutor;
 
@@ -42,6 +46,16 @@ pub struct TransactionIncluded {
     pub events: Vec<Event>,
 }
 
+/// Progress of a transaction submitted with [Backend::submit_tracked].
+pub enum TransactionProgress {
+    /// The transaction is in the transaction pool of the node.
+    InPool,
+    /// The transaction has been included in the given block.
+    InBlock(BlockHash),
+    /// The transaction has been applied. This is the last item of the progress stream.
+    Included(TransactionIncluded),
+}
+
 /// Backend for talking to the ledger on a block chain.
 ///
 /// The interface is low-level and mostly agnostic of the runtime code. Transaction extra data and
@@ -55,13 +69,39 @@ pub trait Backend {
         xt: UncheckedExtrinsic,
     ) -> Result<BoxFuture<'static, Result<TransactionIncluded, Error>>, Error>;
 
+    /// Submit a signed transaction to the ledger and return a stream that reports its progress.
+    ///
+    /// The stream ends after [TransactionProgress::Included] or the first error.
+    async fn submit_tracked(
+        &self,
+        xt: UncheckedExtrinsic,
+    ) -> Result<BoxStream<'static, Result<TransactionProgress, Error>>, Error>;
+
+    /// Submit a signed transaction to the ledger without watching its status.
+    ///
+    /// Returns the transaction hash as soon as the transaction has been accepted into the
+    /// transaction pool.
+    async fn submit_nowait(&self, xt: UncheckedExtrinsic) -> Result<TxHash, Error>;
+
     /// Fetch a value from the runtime state storage at the given block.
+    ///
+    /// Nodes prune the state of old blocks by default. Fetching the state of an old block only
+    /// works against nodes that run with `--archive`.
     async fn fetch(
         &self,
         key: &[u8],
         block_hash: Option<BlockHash>,
     ) -> Result<Option<Vec<u8>>, Error>;
 
+    /// Fetch the values for all given keys from the runtime state storage at the given block.
+    ///
+    /// The returned values are in the same order as `keys`.
+    async fn fetch_many(
+        &self,
+        keys: Vec<Vec<u8>>,
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<Option<Vec<u8>>>, Error>;
+
     /// Fetch all keys with the given prefix from the state storage at the given block.
     async fn fetch_keys(
         &self,
@@ -69,13 +109,63 @@ pub trait Backend {
         block_hash: Option<BlockHash>,
     ) -> Result<Vec<Vec<u8>>, Error>;
 
+    /// Count the keys with the given prefix in the state storage at the given block.
+    ///
+    /// Unlike [Backend::fetch_keys] this does not need to hold all keys in memory.
+    async fn count_keys(&self, prefix: &[u8], block_hash: Option<BlockHash>) -> Result<u64, Error>;
+
+    /// Fetch the extrinsics that are ready or waiting in the transaction pool of the node.
+    async fn pending_extrinsics(&self) -> Result<Vec<UncheckedExtrinsic>, Error>;
+
     /// Fetch the header of the given block hash.
     /// If the block hash is `None`, fetch the header of the best chain tip.
     async fn block_header(&self, block_hash: Option<BlockHash>) -> Result<Option<Header>, Error>;
 
+    /// Fetch the hash of the block with the given number on the best chain.
+    async fn block_hash(&self, block_number: BlockNumber) -> Result<Option<BlockHash>, Error>;
+
+    /// Fetch the hashes of the blocks with the given numbers on the best chain with a single
+    /// request.
+    ///
+    /// The result has one entry for every item of `block_numbers` in the same order.
+    async fn block_hashes(
+        &self,
+        block_numbers: Vec<BlockNumber>,
+    ) -> Result<Vec<Option<BlockHash>>, Error>;
+
+    /// Fetch all events emitted in the given block in the order they were emitted.
+    async fn block_events(&self, block_hash: BlockHash) -> Result<Vec<Event>, Error>;
+
+    /// Fetch all event records emitted in the given block in the order they were emitted.
+    ///
+    /// In contrast to [Backend::block_events] the records include the phase the event was
+    /// emitted in.
+    async fn block_event_records(&self, block_hash: BlockHash)
+        -> Result<Vec<event::Record>, Error>;
+
+    /// Fetch the block with the given hash.
+    async fn block(&self, block_hash: BlockHash) -> Result<Option<Block>, Error>;
+
+    /// Subscribe to the headers of new blocks imported by the node.
+    async fn subscribe_new_heads(&self)
+        -> Result<BoxStream<'static, Result<Header, Error>>, Error>;
+
     /// Get the genesis hash of the blockchain. This must be obtained on backend creation.
     fn get_genesis_hash(&self) -> Hash;
 
-    /// Get the runtime version at the latest block
-    async fn runtime_version(&self) -> Result<RuntimeVersion, Error>;
+    /// Get the runtime version at the given block or at the latest block if `block_hash` is
+    /// `None`.
+    async fn runtime_version(&self, block_hash: Option<BlockHash>)
+        -> Result<RuntimeVersion, Error>;
+
+    /// Get the name of the chain from the chain spec.
+    async fn chain_name(&self) -> Result<String, Error>;
+
+    /// Get the proof-of-work algorithm from the chain properties.
+    async fn pow_algorithm(&self) -> Result<PowKind, Error>;
+
+    /// Get the total proof-of-work difficulty of the chain up to and including the given block.
+    ///
+    /// Returns `None` if the block is unknown.
+    async fn total_difficulty(&self, block_hash: BlockHash) -> Result<Option<U256>, Error>;
 }
@@ -17,11 +17,14 @@
 use futures::compat::{Future01CompatExt as _, Stream01CompatExt as _};
 use futures::future::BoxFuture;
 use futures::prelude::*;
+use futures::stream::BoxStream;
 use futures01::stream::Stream as _;
-use jsonrpc_core_client::RpcChannel;
+use jsonrpc_core_client::{RpcChannel, TypedClient};
 use lazy_static::lazy_static;
 use parity_scale_codec::{DecodeAll, Encode as _};
-use sc_rpc_api::{author::AuthorClient, chain::ChainClient, state::StateClient};
+use sc_rpc_api::{
+    author::AuthorClient, chain::ChainClient, state::StateClient, system::SystemClient,
+};
 use sp_core::{storage::StorageKey, twox_128};
 use sp_rpc::{list::ListOrValue, number::NumberOrHex};
 use sp_runtime::{generic::SignedBlock, traits::Hash as _};
@@ -40,6 +43,9 @@ struct Rpc {
     state: StateClient<BlockHash>,
     chain: ChainClient<BlockNumber, Hash, Header, SignedBlock<Block>>,
     author: AuthorClient<Hash, BlockHash>,
+    system: SystemClient<BlockHash, BlockNumber>,
+    /// Client for RPC methods that are specific to the registry node.
+    raw: TypedClient,
 }
 
 #[derive(Clone)]
@@ -48,6 +54,17 @@ pub struct RemoteNode {
     rpc: Arc<Rpc>,
 }
 
+/// Key of the chain spec property that holds the PoW algorithm. Must match the key used by the
+/// node.
+const POW_ALGORITHM_PROPERTY_KEY: &str = "pow_alg";
+
+/// Name of the RPC method that returns the total difficulty of the chain up to and including a
+/// block. Must match the method name used by the node.
+const TOTAL_DIFFICULTY_METHOD: &str = "pow_totalDifficulty";
+
+/// Number of keys requested per `state_getKeysPaged` call when counting keys.
+const COUNT_KEYS_PAGE_SIZE: u32 = 1000;
+
 lazy_static! {
     static ref SYSTEM_EVENTS_STORAGE_KEY: [u8; 32] = {
         let mut events_key = [0u8; 32];
@@ -67,6 +84,8 @@ impl RemoteNode {
             state: channel.clone().into(),
             chain: channel.clone().into(),
             author: channel.clone().into(),
+            system: channel.clone().into(),
+            raw: TypedClient::new(channel.clone()),
         });
         check_runtime_version(&rpc).await?;
         let genesis_hash_result = rpc
@@ -141,16 +160,7 @@ impl RemoteNode {
         tx_hash: TxHash,
         block_hash: BlockHash,
     ) -> Result<Vec<event::Event>, Error> {
-        let events_data = self
-            .fetch(SYSTEM_EVENTS_STORAGE_KEY.as_ref(), Some(block_hash))
-            .await?
-            .unwrap_or_default();
-        let event_records = Vec::<event::Record>::decode_all(&events_data).map_err(|error| {
-            Error::StateDecoding {
-                error,
-                key: SYSTEM_EVENTS_STORAGE_KEY.to_vec(),
-            }
-        })?;
+        let event_records = self.fetch_event_records(block_hash).await?;
 
         let signed_block = self
             .rpc
@@ -166,6 +176,22 @@ impl RemoteNode {
             }
         })
     }
+
+    /// Return all the event records emitted in the given block.
+    async fn fetch_event_records(
+        &self,
+        block_hash: BlockHash,
+    ) -> Result<Vec<event::Record>, Error> {
+        let events_data = self
+            .fetch(SYSTEM_EVENTS_STORAGE_KEY.as_ref(), Some(block_hash))
+            .await?
+            .unwrap_or_default();
+        Vec::<event::Record>::decode_all(&events_data).map_err(|error| Error::StateDecoding {
+            error,
+            item: "System.Events",
+            key: SYSTEM_EVENTS_STORAGE_KEY.to_vec(),
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -189,6 +215,97 @@ impl backend::Backend for RemoteNode {
         }))
     }
 
+    async fn submit_tracked(
+        &self,
+        xt: backend::UncheckedExtrinsic,
+    ) -> Result<BoxStream<'static, Result<backend::TransactionProgress, Error>>, Error> {
+        /// State of the progress stream between items.
+        enum Tracking<S> {
+            Watching(S),
+            InBlock(BlockHash),
+            Done,
+        }
+
+        let tx_hash = Hashing::hash_of(&xt);
+        let tx_status_stream = self
+            .rpc
+            .author
+            .watch_extrinsic(xt.encode().into())
+            .compat()
+            .await?;
+        let tx_status_stream = tx_status_stream.map_err(Error::from).compat();
+        let this = self.clone();
+
+        let progress_stream =
+            stream::unfold(Tracking::Watching(tx_status_stream), move |tracking| {
+                let this = this.clone();
+                async move {
+                    match tracking {
+                        Tracking::Watching(mut tx_status_stream) => loop {
+                            let tx_status = match tx_status_stream.try_next().await {
+                                Ok(Some(tx_status)) => tx_status,
+                                Ok(None) => {
+                                    return Some((
+                                        Err(Error::WatchExtrinsicStreamTerminated),
+                                        Tracking::Done,
+                                    ))
+                                }
+                                Err(error) => return Some((Err(error), Tracking::Done)),
+                            };
+                            match tx_status {
+                                TransactionStatus::Future | TransactionStatus::Ready => {
+                                    return Some((
+                                        Ok(backend::TransactionProgress::InPool),
+                                        Tracking::Watching(tx_status_stream),
+                                    ))
+                                }
+                                TransactionStatus::Broadcast(_) => continue,
+                                TransactionStatus::InBlock(block_hash) => {
+                                    return Some((
+                                        Ok(backend::TransactionProgress::InBlock(block_hash)),
+                                        Tracking::InBlock(block_hash),
+                                    ))
+                                }
+                                tx_status => {
+                                    return Some((
+                                        Err(Error::InvalidTransactionStatus { tx_hash, tx_status }),
+                                        Tracking::Done,
+                                    ))
+                                }
+                            }
+                        },
+                        Tracking::InBlock(block_hash) => {
+                            let progress = this
+                                .get_transaction_events(tx_hash, block_hash)
+                                .await
+                                .map(|events| {
+                                    backend::TransactionProgress::Included(
+                                        backend::TransactionIncluded {
+                                            tx_hash,
+                                            block: block_hash,
+                                            events,
+                                        },
+                                    )
+                                });
+                            Some((progress, Tracking::Done))
+                        }
+                        Tracking::Done => None,
+                    }
+                }
+            });
+        Ok(progress_stream.boxed())
+    }
+
+    async fn submit_nowait(&self, xt: backend::UncheckedExtrinsic) -> Result<TxHash, Error> {
+        let tx_hash = self
+            .rpc
+            .author
+            .submit_extrinsic(xt.encode().into())
+            .compat()
+            .await?;
+        Ok(tx_hash)
+    }
+
     async fn fetch(
         &self,
         key: &[u8],
@@ -199,6 +316,27 @@ impl backend::Backend for RemoteNode {
         Ok(maybe_data.map(|data| data.0))
     }
 
+    async fn fetch_many(
+        &self,
+        keys: Vec<Vec<u8>>,
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<Option<Vec<u8>>>, Error> {
+        let storage_keys = keys.iter().cloned().map(StorageKey).collect();
+        let change_sets = self
+            .rpc
+            .state
+            .query_storage_at(storage_keys, block_hash)
+            .compat()
+            .await?;
+        let values: std::collections::HashMap<Vec<u8>, Vec<u8>> = change_sets
+            .into_iter()
+            .flat_map(|change_set| change_set.changes)
+            .filter_map(|(key, maybe_data)| maybe_data.map(|data| (key.0, data.0)))
+            .collect();
+        // `keys` may contain duplicates so we must not remove the values from the map.
+        Ok(keys.iter().map(|key| values.get(key).cloned()).collect())
+    }
+
     async fn fetch_keys(
         &self,
         prefix: &[u8],
@@ -214,6 +352,40 @@ impl backend::Backend for RemoteNode {
         Ok(keys.into_iter().map(|key| key.0).collect())
     }
 
+    async fn count_keys(&self, prefix: &[u8], block_hash: Option<BlockHash>) -> Result<u64, Error> {
+        let prefix = StorageKey(Vec::from(prefix));
+        let mut count = 0;
+        let mut start_key = None;
+        loop {
+            let keys = self
+                .rpc
+                .state
+                .storage_keys_paged(
+                    Some(prefix.clone()),
+                    COUNT_KEYS_PAGE_SIZE,
+                    start_key,
+                    block_hash,
+                )
+                .compat()
+                .await?;
+            count += keys.len() as u64;
+            if keys.len() < COUNT_KEYS_PAGE_SIZE as usize {
+                return Ok(count);
+            }
+            start_key = keys.last().cloned();
+        }
+    }
+
+    async fn pending_extrinsics(&self) -> Result<Vec<backend::UncheckedExtrinsic>, Error> {
+        let extrinsics = self.rpc.author.pending_extrinsics().compat().await?;
+        extrinsics
+            .into_iter()
+            .map(|xt_bytes| {
+                backend::UncheckedExtrinsic::decode_all(&xt_bytes).map_err(Error::ExtrinsicDecoding)
+            })
+            .collect()
+    }
+
     async fn block_header(
         &self,
         block_hash: Option<BlockHash>,
@@ -226,19 +398,113 @@ impl backend::Backend for RemoteNode {
             .map_err(Error::from)
     }
 
+    async fn block_hash(&self, block_number: BlockNumber) -> Result<Option<BlockHash>, Error> {
+        let block_hash_result = self
+            .rpc
+            .chain
+            .block_hash(Some(NumberOrHex::Number(block_number).into()))
+            .compat()
+            .await?;
+        match block_hash_result {
+            ListOrValue::Value(maybe_block_hash) => Ok(maybe_block_hash),
+            response => Err(Error::InvalidBlockHashResponse { response }),
+        }
+    }
+
+    async fn block_hashes(
+        &self,
+        block_numbers: Vec<BlockNumber>,
+    ) -> Result<Vec<Option<BlockHash>>, Error> {
+        let block_count = block_numbers.len();
+        let block_numbers = block_numbers
+            .into_iter()
+            .map(NumberOrHex::Number)
+            .collect::<Vec<_>>();
+        let block_hashes_result = self
+            .rpc
+            .chain
+            .block_hash(Some(ListOrValue::List(block_numbers)))
+            .compat()
+            .await?;
+        match block_hashes_result {
+            ListOrValue::List(block_hashes) if block_hashes.len() == block_count => {
+                Ok(block_hashes)
+            }
+            response => Err(Error::InvalidBlockHashResponse { response }),
+        }
+    }
+
+    async fn block_events(&self, block_hash: BlockHash) -> Result<Vec<event::Event>, Error> {
+        let event_records = self.fetch_event_records(block_hash).await?;
+        Ok(event_records
+            .into_iter()
+            .map(|record| record.event)
+            .collect())
+    }
+
+    async fn block_event_records(
+        &self,
+        block_hash: BlockHash,
+    ) -> Result<Vec<event::Record>, Error> {
+        self.fetch_event_records(block_hash).await
+    }
+
+    async fn block(&self, block_hash: BlockHash) -> Result<Option<Block>, Error> {
+        let maybe_signed_block = self.rpc.chain.block(Some(block_hash)).compat().await?;
+        Ok(maybe_signed_block.map(|signed_block| signed_block.block))
+    }
+
+    async fn subscribe_new_heads(
+        &self,
+    ) -> Result<BoxStream<'static, Result<BlockHeader, Error>>, Error> {
+        let header_stream = self.rpc.chain.subscribe_new_heads().compat().await?;
+        Ok(header_stream.map_err(Error::from).compat().boxed())
+    }
+
     fn get_genesis_hash(&self) -> Hash {
         self.genesis_hash
     }
 
-    async fn runtime_version(&self) -> Result<RuntimeVersion, Error> {
-        runtime_version(&self.rpc, None).await
+    async fn runtime_version(
+        &self,
+        block_hash: Option<BlockHash>,
+    ) -> Result<RuntimeVersion, Error> {
+        runtime_version(&self.rpc, block_hash).await
+    }
+
+    async fn chain_name(&self) -> Result<String, Error> {
+        self.rpc
+            .system
+            .system_chain()
+            .compat()
+            .await
+            .map_err(Error::from)
+    }
+
+    async fn pow_algorithm(&self) -> Result<PowKind, Error> {
+        let mut properties = self.rpc.system.system_properties().compat().await?;
+        properties
+            .remove(POW_ALGORITHM_PROPERTY_KEY)
+            .and_then(|value| serde_json::from_value(value).ok())
+            .ok_or(Error::InvalidPowAlgorithmProperty)
+    }
+
+    async fn total_difficulty(&self, block_hash: BlockHash) -> Result<Option<U256>, Error> {
+        self.rpc
+            .raw
+            .call_method(TOTAL_DIFFICULTY_METHOD, "Option<U256>", (block_hash,))
+            .compat()
+            .await
+            .map_err(Error::from)
     }
 }
 
 async fn check_runtime_version(rpc: &Rpc) -> Result<(), Error> {
     const CURRENT_SPEC_VERSION: u32 = VERSION.spec_version;
     match runtime_version(rpc, None).await?.spec_version {
-        17 | CURRENT_SPEC_VERSION => Ok(()),
+        // Runtimes before spec version 33 do not know the `TxLongevity` signed extension and
+        // cannot decode extrinsics created by this client.
+        33..=CURRENT_SPEC_VERSION => Ok(()),
         other => Err(Error::IncompatibleRuntimeVersion(other)),
     }
 }
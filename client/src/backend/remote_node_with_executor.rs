@@ -14,12 +14,15 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 //! Provides [RemoteNodeWithExecutor] backend
+use futures::channel::mpsc;
 use futures::compat::Executor01CompatExt;
 use futures::future::BoxFuture;
+use futures::stream::{BoxStream, StreamExt as _};
 use futures::task::SpawnExt;
 use std::sync::Arc;
 
 use crate::backend;
+use crate::event;
 use crate::interface::*;
 
 /// Client backend that wraps [crate::backend::RemoteNode] but spawns all futures in
@@ -59,6 +62,37 @@ impl backend::Backend for RemoteNodeWithExecutor {
         Ok(Box::pin(exec.spawn_with_handle(fut).unwrap()))
     }
 
+    async fn submit_tracked(
+        &self,
+        xt: backend::UncheckedExtrinsic,
+    ) -> Result<BoxStream<'static, Result<backend::TransactionProgress, Error>>, Error> {
+        let backend = self.backend.clone();
+        let mut progress_stream = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.submit_tracked(xt).await })
+            .unwrap()
+            .await?;
+
+        let (sender, receiver) = mpsc::unbounded();
+        Executor01CompatExt::compat(self.runtime.executor())
+            .spawn(async move {
+                while let Some(item) = progress_stream.next().await {
+                    if sender.unbounded_send(item).is_err() {
+                        break;
+                    }
+                }
+            })
+            .unwrap();
+        Ok(receiver.boxed())
+    }
+
+    async fn submit_nowait(&self, xt: backend::UncheckedExtrinsic) -> Result<TxHash, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.submit_nowait(xt).await })
+            .unwrap();
+        handle.await
+    }
+
     async fn fetch(
         &self,
         key: &[u8],
@@ -72,6 +106,18 @@ impl backend::Backend for RemoteNodeWithExecutor {
         handle.await
     }
 
+    async fn fetch_many(
+        &self,
+        keys: Vec<Vec<u8>>,
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<Option<Vec<u8>>>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.fetch_many(keys, block_hash).await })
+            .unwrap();
+        handle.await
+    }
+
     async fn fetch_keys(
         &self,
         prefix: &[u8],
@@ -85,6 +131,23 @@ impl backend::Backend for RemoteNodeWithExecutor {
         handle.await
     }
 
+    async fn count_keys(&self, prefix: &[u8], block_hash: Option<BlockHash>) -> Result<u64, Error> {
+        let backend = self.backend.clone();
+        let prefix = Vec::from(prefix);
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.count_keys(&prefix, block_hash).await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn pending_extrinsics(&self) -> Result<Vec<backend::UncheckedExtrinsic>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.pending_extrinsics().await })
+            .unwrap();
+        handle.await
+    }
+
     async fn block_header(
         &self,
         block_hash: Option<BlockHash>,
@@ -96,11 +159,108 @@ impl backend::Backend for RemoteNodeWithExecutor {
         handle.await
     }
 
+    async fn block_hash(&self, block_number: BlockNumber) -> Result<Option<BlockHash>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.block_hash(block_number).await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn block_hashes(
+        &self,
+        block_numbers: Vec<BlockNumber>,
+    ) -> Result<Vec<Option<BlockHash>>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.block_hashes(block_numbers).await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn block_events(&self, block_hash: BlockHash) -> Result<Vec<Event>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.block_events(block_hash).await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn block_event_records(
+        &self,
+        block_hash: BlockHash,
+    ) -> Result<Vec<event::Record>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.block_event_records(block_hash).await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn block(&self, block_hash: BlockHash) -> Result<Option<backend::Block>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.block(block_hash).await })
+            .unwrap();
+        handle.await
+    }
+
+    /// The subscription stream is polled on the executor and its items are forwarded to the
+    /// returned stream.
+    async fn subscribe_new_heads(
+        &self,
+    ) -> Result<BoxStream<'static, Result<BlockHeader, Error>>, Error> {
+        let backend = self.backend.clone();
+        let mut header_stream = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.subscribe_new_heads().await })
+            .unwrap()
+            .await?;
+
+        let (sender, receiver) = mpsc::unbounded();
+        Executor01CompatExt::compat(self.runtime.executor())
+            .spawn(async move {
+                while let Some(item) = header_stream.next().await {
+                    if sender.unbounded_send(item).is_err() {
+                        break;
+                    }
+                }
+            })
+            .unwrap();
+        Ok(receiver.boxed())
+    }
+
     fn get_genesis_hash(&self) -> Hash {
         self.backend.get_genesis_hash()
     }
 
-    async fn runtime_version(&self) -> Result<RuntimeVersion, Error> {
-        self.backend.runtime_version().await
+    async fn runtime_version(
+        &self,
+        block_hash: Option<BlockHash>,
+    ) -> Result<RuntimeVersion, Error> {
+        self.backend.runtime_version(block_hash).await
+    }
+
+    async fn chain_name(&self) -> Result<String, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.chain_name().await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn pow_algorithm(&self) -> Result<PowKind, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.pow_algorithm().await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn total_difficulty(&self, block_hash: BlockHash) -> Result<Option<U256>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.total_difficulty(block_hash).await })
+            .unwrap();
+        handle.await
     }
 }
@@ -14,7 +14,7 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use failure::{Compat, Fail};
-use jsonrpc_core_client::RpcError;
+use jsonrpc_core_client::{jsonrpc_core::ErrorCode, RpcError};
 use parity_scale_codec::Error as CodecError;
 
 use crate::event::EventExtractionError;
@@ -23,14 +23,22 @@ use crate::event::EventExtractionError;
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     /// Failed to decode state value
-    #[error("Failed to decode state value")]
+    ///
+    /// This usually indicates that the chain runs a runtime that is incompatible with the client.
+    #[error("Failed to decode state value for {item}")]
     StateDecoding {
         #[source]
         error: CodecError,
+        /// Name of the storage item we tried to decode
+        item: &'static str,
         /// Key for the value we tried to decode
         key: Vec<u8>,
     },
 
+    /// Failed to decode an extrinsic received from the node
+    #[error("Failed to decode extrinsic")]
+    ExtrinsicDecoding(#[source] CodecError),
+
     /// Error from the underlying RPC connection
     #[error("Error from the underlying RPC connection")]
     Rpc(#[source] Compat<RpcError>),
@@ -39,10 +47,49 @@ pub enum Error {
     #[error("Invalid transaction")]
     InvalidTransaction,
 
+    /// The transaction does not fit into the block because the block is full or the transaction
+    /// exceeds the block weight or length limits for normal transactions.
+    ///
+    /// Submitting the transaction again later may succeed.
+    #[error("Transaction exhausts the block resources")]
+    BlockResourcesExhausted,
+
+    /// The era block of a mortal transaction is not one of the last
+    /// [radicle_registry_runtime::BlockHashCount] blocks of the chain. See
+    /// [crate::TransactionExtra::era].
+    ///
+    /// The transaction must be signed again with a recent era block.
+    #[error("Transaction era block is older than the block hash pruning window")]
+    AncientEraBlock,
+
+    /// The extrinsic is unsigned or its signature does not match the signed payload.
+    #[error("Extrinsic is not signed or has an invalid signature")]
+    InvalidExtrinsicSignature,
+
+    /// A mortal extrinsic cannot be verified without the hash of its era block.
+    #[error("Cannot verify mortal extrinsic without the hash of its era block")]
+    EraBlockHashMissing,
+
     /// Chain is running an incompatible runtime specification version
     #[error("Chain is running an incompatible runtime specification version {0}")]
     IncompatibleRuntimeVersion(u32),
 
+    /// The client is connected to a different chain than expected.
+    #[error("Connected to chain {found} but expected chain {expected}")]
+    WrongChain { expected: String, found: String },
+
+    /// The transaction to register an org or a user failed.
+    #[error("Registration transaction failed")]
+    RegistrationFailed(#[source] radicle_registry_core::TransactionError),
+
+    /// The transaction to update the on-chain runtime failed.
+    #[error("Runtime update transaction failed")]
+    RuntimeUpdateFailed(#[source] radicle_registry_core::TransactionError),
+
+    /// The on-chain runtime spec version did not change after a runtime update.
+    #[error("Runtime spec version {0} did not change after the runtime update")]
+    RuntimeVersionUnchanged(u32),
+
     /// Failed to extract required events for a transaction
     #[error("Failed to extract required events for transaction {tx_hash}")]
     EventExtraction {
@@ -60,6 +107,20 @@ pub enum Error {
         tx_hash: crate::TxHash,
     },
 
+    /// The chain properties do not contain a valid proof-of-work algorithm.
+    ///
+    /// The node is violating the application protocol.
+    #[error("Chain properties do not specify a valid proof-of-work algorithm")]
+    InvalidPowAlgorithmProperty,
+
+    /// Failed to read a chain spec file.
+    #[error("Failed to read chain spec file")]
+    ChainSpecRead(#[source] std::io::Error),
+
+    /// The chain spec file is not a valid raw chain spec.
+    #[error("Invalid raw chain spec")]
+    InvalidChainSpec(#[source] serde_json::Error),
+
     #[error("Could not obtain header of tip of best chain")]
     BestChainTipHeaderMissing,
 
@@ -67,6 +128,22 @@ pub enum Error {
     #[error("Block {block_hash} could not be found")]
     BlockMissing { block_hash: crate::BlockHash },
 
+    /// There is no block with the given number on the best chain.
+    #[error("Block number {block_number} could not be found")]
+    BlockNumberMissing { block_number: crate::BlockNumber },
+
+    /// The requested block range exceeds the limit of [crate::MAX_BLOCK_HEADERS_RANGE] blocks.
+    #[error("Block range from {from} to {to} exceeds the limit of {limit} blocks")]
+    RangeTooLarge {
+        from: crate::BlockNumber,
+        to: crate::BlockNumber,
+        limit: u32,
+    },
+
+    /// Block does not contain the inherent that sets the block author.
+    #[error("Block {block_hash} does not set a block author")]
+    BlockAuthorMissing { block_hash: crate::BlockHash },
+
     /// Invalid response from the node for the `chain.block_hash` method.
     ///
     /// The node is violating the application protocol.
@@ -81,6 +158,37 @@ pub enum Error {
     #[error("RPC subscription author.watch_extrinsic terminated prematurely")]
     WatchExtrinsicStreamTerminated,
 
+    /// RPC subscription chain.subscribe_new_heads terminated prematurely.
+    ///
+    /// The node is violating the application protocol.
+    #[error("RPC subscription chain.subscribe_new_heads terminated prematurely")]
+    NewHeadsStreamTerminated,
+
+    /// A transaction was submitted but not included in a block.
+    #[error("Transaction {tx_hash} was not included in a block")]
+    TransactionNotIncluded { tx_hash: crate::TxHash },
+
+    /// The block that included a transaction is no longer part of the best chain.
+    ///
+    /// The transaction may be included in another block of the best chain.
+    #[error("Block {block_hash} with transaction {tx_hash} is not part of the best chain")]
+    TransactionRetracted {
+        tx_hash: crate::TxHash,
+        block_hash: crate::BlockHash,
+    },
+
+    /// Advancing the account nonce by the number of requested nonces overflows.
+    #[error("Account nonce {nonce} overflows when advanced by {count}")]
+    NonceOverflow { nonce: u32, count: u32 },
+
+    /// The backend does not support the operation.
+    #[error("The backend does not support {operation}")]
+    Unsupported { operation: &'static str },
+
+    /// The awaited state was not reached in time.
+    #[error("Timed out after {timeout:?}")]
+    Timeout { timeout: std::time::Duration },
+
     /// Invalid [crate::backend::TransactionStatus] received in `author.watch_extrinsic` RPC
     /// subsription.
     ///
@@ -92,8 +200,22 @@ pub enum Error {
     },
 }
 
+/// Code of the RPC error returned by a node if it rejects an invalid transaction.
+const RPC_INVALID_TRANSACTION_CODE: i64 = 1010;
+
 impl From<RpcError> for Error {
     fn from(error: RpcError) -> Self {
+        if let RpcError::JsonRpcError(jsonrpc_error) = &error {
+            // The node serializes the `InvalidTransaction` reason as the error data.
+            if jsonrpc_error.code == ErrorCode::ServerError(RPC_INVALID_TRANSACTION_CODE) {
+                if jsonrpc_error.data == Some(serde_json::json!("ExhaustsResources")) {
+                    return Error::BlockResourcesExhausted;
+                }
+                if jsonrpc_error.data == Some(serde_json::json!("AncientBirthBlock")) {
+                    return Error::AncientEraBlock;
+                }
+            }
+        }
         Error::Rpc(error.compat())
     }
 }
@@ -18,20 +18,23 @@
 //! The [ClientT] trait defines one method for each transaction of the registry ledger as well as
 //! methods to get the ledger state.
 use futures::future::BoxFuture;
+use futures::stream::BoxStream;
 
 pub use radicle_registry_core::*;
 
 pub use radicle_registry_runtime::{
-    state, Balance, BlockNumber, Event, Hash, Header, RuntimeVersion,
+    state, Balance, BlockNumber, Call as RuntimeCall, Event, Hash, Header, RuntimeVersion,
 };
 pub use sp_core::crypto::{
     Pair as CryptoPair, Public as CryptoPublic, SecretStringError as CryptoError,
 };
-pub use sp_core::{ed25519, H256};
+pub use sp_core::{ed25519, H256, U256};
 
 pub use crate::error::Error;
 pub use crate::message::Message;
-pub use crate::transaction::{Transaction, TransactionExtra};
+pub use crate::transaction::{
+    expected_extrinsic_bytes, verify_extrinsic, Transaction, TransactionExtra,
+};
 
 /// The hash of a block. Uniquely identifies a block.
 #[doc(inline)]
@@ -59,6 +62,132 @@ pub struct TransactionIncluded {
     pub result: Result<(), TransactionError>,
 }
 
+/// State of a transaction submitted with [ClientT::submit_tracked].
+#[derive(Clone, Debug)]
+pub enum TxState {
+    /// The transaction is in the transaction pool of the node.
+    InPool,
+    /// The transaction has been included in the given block.
+    InBlock(BlockHash),
+    /// The transaction has been applied. This is the last state reported.
+    Included(TransactionIncluded),
+}
+
+/// Stream of [TxState]s returned by [ClientT::submit_tracked].
+///
+/// The stream ends after [TxState::Included] or the first error.
+pub type TxTracker = BoxStream<'static, Result<TxState, Error>>;
+
+/// An extrinsic of a block decoded with the runtime's extrinsic definition.
+///
+/// Returned by [ClientT::block_extrinsics].
+#[derive(Clone, Debug)]
+pub struct DecodedExtrinsic {
+    pub tx_hash: TxHash,
+    /// The author of the extrinsic. `None` for unsigned extrinsics like inherents.
+    pub signer: Option<AccountId>,
+    /// The account nonce of the signer. `None` for unsigned extrinsics.
+    pub nonce: Option<state::AccountTransactionIndex>,
+    /// The runtime call dispatched by the extrinsic.
+    pub call: RuntimeCall,
+    /// Events emitted while applying the extrinsic in the order they were emitted.
+    pub events: Vec<Event>,
+}
+
+/// A transaction found in a block of the best chain.
+///
+/// Returned by [ClientT::get_transaction].
+#[derive(Clone, Debug)]
+pub struct TransactionRecord {
+    /// The hash of the block the transaction is included in.
+    pub block: BlockHash,
+    /// The number of the block the transaction is included in.
+    pub block_number: BlockNumber,
+    /// The transaction and the events it emitted.
+    pub extrinsic: DecodedExtrinsic,
+    /// The dispatch result of the transaction.
+    ///
+    /// Unlike [TransactionIncluded::result] this does not depend on the message type. For
+    /// messages dispatched with [crate::message::Sudo] it does not reflect the result of the
+    /// wrapped message.
+    pub result: Result<(), TransactionError>,
+}
+
+/// Accounts with special privileges or a well-known key.
+///
+/// Returned by [ClientT::system_accounts].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SystemAccounts {
+    /// The current sudo key as stored on chain.
+    pub sudo_key: AccountId,
+    /// Accounts of the well-known development keys [crate::DEV_ACCOUNT_SEEDS] that exist on
+    /// chain.
+    ///
+    /// Development chains endow these accounts in their genesis config. The keys are public so
+    /// anybody can control these accounts.
+    pub dev_accounts: Vec<AccountId>,
+}
+
+/// Change of the free balance of an account.
+///
+/// Returned by [ClientT::subscribe_balance_changes].
+#[derive(Clone, Debug)]
+pub struct BalanceChange {
+    pub block_number: BlockNumber,
+    pub block_hash: BlockHash,
+    /// The free balance before the change.
+    pub previous: Balance,
+    /// The free balance after the change.
+    pub current: Balance,
+    /// Transfers in the block the account sent or received as `(sender, recipient, amount)`.
+    ///
+    /// Changes that are not caused by transfers, like fees or block rewards, have no transfers.
+    pub transfers: Vec<(AccountId, AccountId, Balance)>,
+}
+
+/// Average rewards credited to block authors over recent blocks.
+///
+/// Returned by [ClientT::reward_estimate].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardEstimate {
+    /// The number of blocks the estimate is based on.
+    pub blocks: u32,
+    /// The average total reward credited to the author of a block.
+    pub reward_per_block: Balance,
+    /// The average share of the transaction fees in a block that is credited to its author.
+    ///
+    /// Included in `reward_per_block`.
+    pub fees_per_block: Balance,
+}
+
+/// Nonce and free balance of an account. Returned by [ClientT::get_account].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Account {
+    /// The nonce of the account.
+    pub nonce: state::AccountTransactionIndex,
+    /// The free balance of the account.
+    pub free_balance: state::AccountBalance,
+}
+
+/// Everything the registry knows about an account.
+///
+/// Returned by [ClientT::account_profile]. All fields are read from the state of the same block.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountProfile {
+    /// The block whose state the profile was read from.
+    pub block_hash: BlockHash,
+    /// The free balance of the account.
+    pub balance: Balance,
+    /// The nonce of the account.
+    pub nonce: state::AccountTransactionIndex,
+    /// The user associated with the account, if any.
+    pub user_id: Option<Id>,
+    /// The orgs the associated user is a member of, sorted by ID.
+    pub orgs: Vec<Id>,
+    /// The projects registered in the domain of the associated user.
+    pub projects: Vec<ProjectId>,
+}
+
 /// Return type for all [ClientT] methods.
 pub type Response<T, Error> = BoxFuture<'static, Result<T, Error>>;
 
@@ -76,6 +205,19 @@ pub enum IdStatus {
     Retired,
 }
 
+/// Proof-of-work algorithm a chain uses to seal blocks.
+///
+/// The algorithm is part of the chain spec properties. See [ClientT::pow_algorithm].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum PowKind {
+    /// Every block is valid. Used by development chains where block confirmations provide no
+    /// security.
+    Dummy,
+
+    /// Blake3 based proof-of-work used by public networks.
+    Blake3,
+}
+
 /// Trait for ledger clients sending transactions and looking up state.
 #[async_trait::async_trait]
 pub trait ClientT {
@@ -113,9 +255,22 @@ pub trait ClientT {
         transaction: Transaction<Message_>,
     ) -> Result<Response<TransactionIncluded, Error>, Error>;
 
+    /// Submit a signed transaction and track its progress.
+    ///
+    /// In contrast to [ClientT::submit_transaction] the returned [TxTracker] also reports when
+    /// the transaction enters the transaction pool and when it is included in a block.
+    async fn submit_tracked<Message_: Message>(
+        &self,
+        transaction: Transaction<Message_>,
+    ) -> Result<TxTracker, Error>;
+
     /// Sign and submit a ledger message as a transaction to the blockchain.
     ///
     /// Same as [ClientT::submit_transaction] but takes care of signing the message.
+    ///
+    /// The client remembers the nonce of the author's next transaction, so consecutive calls only
+    /// fetch the nonce once. If the submission fails with a remembered nonce, the client fetches
+    /// the nonce from the chain and submits the message again.
     async fn sign_and_submit_message<Message_: Message>(
         &self,
         author: &ed25519::Pair,
@@ -123,6 +278,110 @@ pub trait ClientT {
         fee: Balance,
     ) -> Result<Response<TransactionIncluded, Error>, Error>;
 
+    /// Sign and submit a ledger message like [ClientT::sign_and_submit_message] and also return a
+    /// future that resolves once the transaction is confirmed.
+    ///
+    /// The first future resolves as soon as the transaction is included in a block. The chain
+    /// uses proof-of-work and has no finality. Instead the second future resolves when the block
+    /// that includes the transaction is `confirmations` blocks deep in the best chain. It fails
+    /// with [Error::TransactionRetracted] if the block is not part of the best chain at that
+    /// depth, for example because of a reorg.
+    ///
+    /// Each future can be awaited without awaiting the other.
+    async fn sign_and_submit_message_confirmed<Message_: Message>(
+        &self,
+        author: &ed25519::Pair,
+        message: Message_,
+        fee: Balance,
+        confirmations: u32,
+    ) -> Result<(Response<TransactionIncluded, Error>, Response<(), Error>), Error>;
+
+    /// Sign and submit a ledger message as a transaction without waiting for it to be included
+    /// in a block.
+    ///
+    /// Returns the transaction hash once the node accepted the transaction into its transaction
+    /// pool. The transaction may still fail or never be included. Use
+    /// [ClientT::sign_and_submit_message] to wait for the transaction result.
+    async fn submit_nowait<Message_: Message>(
+        &self,
+        author: &ed25519::Pair,
+        message: Message_,
+        fee: Balance,
+    ) -> Result<TxHash, Error>;
+
+    /// Sign and submit a message that is dispatched with the root origin by the sudo module.
+    ///
+    /// `sudo_key` must be the chain's sudo key. See [ClientT::sudo_key].
+    async fn sudo<Message_: Message>(
+        &self,
+        sudo_key: &ed25519::Pair,
+        message: Message_,
+        fee: Balance,
+    ) -> Result<Response<TransactionIncluded, Error>, Error>;
+
+    /// Sign and submit `messages` as a single [crate::message::Batch] transaction.
+    ///
+    /// The messages are applied in order and atomically. If one message fails, the changes of all
+    /// messages are reverted and [TransactionIncluded::result] is
+    /// [TransactionError::BatchInterrupted] with the index and the error of the failed message.
+    /// The author pays `fee` once for the whole batch. At most
+    /// [crate::MAX_BATCH_CALLS] messages can be batched.
+    async fn submit_batch(
+        &self,
+        author: &ed25519::Pair,
+        messages: Vec<Box<dyn Message>>,
+        fee: Balance,
+    ) -> Result<Response<TransactionIncluded, Error>, Error>;
+
+    /// Submit an unsigned [message::Faucet] transaction that mints `amount` for `recipient`.
+    ///
+    /// The transaction is only valid on chains that enable the faucet in their genesis config.
+    /// See [radicle_registry_runtime::registry::FAUCET_MAX_AMOUNT] and
+    /// [radicle_registry_runtime::registry::FAUCET_PERIOD] for the limits.
+    async fn faucet(
+        &self,
+        recipient: AccountId,
+        amount: Balance,
+    ) -> Result<Response<TransactionIncluded, Error>, Error>;
+
+    /// Replace the on-chain runtime with `code` and return the new runtime version.
+    ///
+    /// Submits [message::UpdateRuntime] signed by `sudo_key` and waits for the transaction to be
+    /// included. Fails with [Error::RuntimeVersionUnchanged] if the `spec_version` of the
+    /// on-chain runtime did not change.
+    async fn set_runtime_code(
+        &self,
+        sudo_key: &ed25519::Pair,
+        code: Vec<u8>,
+        fee: Balance,
+    ) -> Result<RuntimeVersion, Error>;
+
+    /// Fetch the account ID of the chain's sudo key.
+    async fn sudo_key(&self) -> Result<AccountId, Error>;
+
+    /// Fetch the accounts that can dispatch calls with the root origin, like
+    /// [message::AcceptProject].
+    ///
+    /// The runtime has no separate set of root accounts so this is the sudo key. UIs should use
+    /// this instead of [ClientT::sudo_key] to check for admin rights.
+    async fn root_accounts(&self) -> Result<Vec<AccountId>, Error>;
+
+    /// Fetch the accounts with special privileges or a well-known key. See [SystemAccounts].
+    async fn system_accounts(&self) -> Result<SystemAccounts, Error>;
+
+    /// Returns `true` if the accounts of newly registered orgs are derived from the org ID.
+    ///
+    /// In that case the account of an org is [crate::Client::derived_org_account]. Otherwise org accounts
+    /// are chosen at random. The mode is set in the genesis config of the chain.
+    async fn deterministic_org_accounts(&self) -> Result<bool, Error>;
+
+    /// Fetch the extrinsics of the given block and decode them together with the events they
+    /// emitted.
+    ///
+    /// Returns [Error::BlockMissing] if the block does not exist.
+    async fn block_extrinsics(&self, block_hash: BlockHash)
+        -> Result<Vec<DecodedExtrinsic>, Error>;
+
     /// Check whether a given account exists on chain.
     async fn account_exists(&self, account_id: &AccountId) -> Result<bool, Error>;
 
@@ -132,35 +391,287 @@ pub trait ClientT {
         account_id: &AccountId,
     ) -> Result<state::AccountTransactionIndex, Error>;
 
+    /// Return the `count` nonces the next transactions of the account must use.
+    ///
+    /// The nonces start with the nonce cached by the client for the account or with the account
+    /// nonce at the best chain tip. They are reserved in the cache so that messages the client
+    /// signs afterwards use later nonces. Fails with [Error::NonceOverflow] if the nonces exceed
+    /// the nonce range.
+    ///
+    /// The nonces are only valid if no other transactions from the account are submitted by
+    /// other clients in the meantime. Use [Transaction::new_signed_sequence] to sign a sequence of
+    /// messages with these nonces.
+    async fn next_nonces(
+        &self,
+        account_id: &AccountId,
+        count: u32,
+    ) -> Result<Vec<state::AccountTransactionIndex>, Error>;
+
     /// Fetch the header of the given block hash
     async fn block_header(&self, block_hash: BlockHash) -> Result<Option<BlockHeader>, Error>;
 
     /// Fetch the header of the best chain tip
     async fn block_header_best_chain(&self) -> Result<BlockHeader, Error>;
 
+    /// Return the number of the best chain tip.
+    ///
+    /// Same as the `number` of [ClientT::block_header_best_chain].
+    async fn best_block_number(&self) -> Result<BlockNumber, Error>;
+
+    /// Fetch the headers of the blocks numbered `from` to `to` inclusively on the best chain in
+    /// ascending order.
+    ///
+    /// Returns an empty list if `to` is less than `from`. Fails with [Error::RangeTooLarge] if the
+    /// range contains more than [crate::MAX_BLOCK_HEADERS_RANGE] blocks and with
+    /// [Error::BlockNumberMissing] if a block in the range does not exist.
+    async fn block_headers(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> Result<Vec<BlockHeader>, Error>;
+
     /// Return the genesis hash of the chain we are communicating with.
     fn genesis_hash(&self) -> Hash;
 
+    /// Fetch the header of the genesis block, i.e. the block with number `0`.
+    ///
+    /// The header includes the genesis state root which can be compared with a trusted value to
+    /// verify the chain.
+    async fn genesis_header(&self) -> Result<BlockHeader, Error>;
+
+    /// Estimate the number of blocks until a transaction paying `fee` would be included.
+    ///
+    /// `length` is the encoded length of the transaction, see [Transaction::to_bytes].
+    ///
+    /// Returns `0` if the transaction would be included in the next block. The estimate
+    /// simulates block authors greedily packing the pending transactions of the node with the
+    /// highest fees first, followed by the estimated transaction, until the block length limit is
+    /// reached. Pending transactions that pay the same fee are assumed to be included first. Block
+    /// weight is not modelled because all registry calls have zero weight. This is a heuristic:
+    /// transactions submitted later may still outbid the estimated one.
+    async fn estimate_inclusion_blocks(&self, fee: Balance, length: usize) -> Result<u32, Error>;
+
+    /// Estimate the reward per block for block authors from the last `window` blocks.
+    ///
+    /// The reward of a block is the [crate::BLOCK_REWARD] plus the share of the transaction fees
+    /// in the block that is not burned. The genesis block is not included. If there are no
+    /// blocks in the window the estimate contains only the block reward. Fails with
+    /// [Error::RangeTooLarge] if `window` is larger than [crate::MAX_BLOCK_HEADERS_RANGE].
+    async fn reward_estimate(&self, window: u32) -> Result<RewardEstimate, Error>;
+
+    /// Subscribe to the rewards credited to the authors of new blocks.
+    ///
+    /// For every new block the stream yields the block number, the block author, and the sum of
+    /// the [crate::BLOCK_REWARD] and the share of the transaction fees in the block that is not
+    /// burned. Only blocks that are imported after the subscription was created are included.
+    async fn subscribe_block_rewards(
+        &self,
+    ) -> Result<BoxStream<'static, Result<(BlockNumber, AccountId, Balance), Error>>, Error>;
+
+    /// Subscribe to the events emitted in new blocks.
+    ///
+    /// For every new block the stream yields the block hash and all events emitted in the block
+    /// in the order they were emitted. Only blocks that are imported after the subscription was
+    /// created are included.
+    async fn subscribe_events(
+        &self,
+    ) -> Result<BoxStream<'static, Result<(BlockHash, Vec<Event>), Error>>, Error>;
+
+    /// Subscribe to changes of the free balance of `account_id`.
+    ///
+    /// For every new block the free balance of the account is read from the state of that block.
+    /// The block selected with `Client::at` is ignored. The stream yields a [BalanceChange]
+    /// whenever the balance differs from the previous one.
+    async fn subscribe_balance_changes(
+        &self,
+        account_id: AccountId,
+    ) -> Result<BoxStream<'static, Result<BalanceChange, Error>>, Error>;
+
+    /// Subscribe to runtime upgrades that take effect on chain.
+    ///
+    /// For every new block the runtime version at that block is fetched. The stream
+    /// yields the new runtime version whenever its `spec_version` differs from the previous one.
+    /// The runtime version at the time of the call is not yielded.
+    async fn subscribe_runtime_upgrades(
+        &self,
+    ) -> Result<BoxStream<'static, Result<RuntimeVersion, Error>>, Error>;
+
+    /// Replay the events of all blocks on the best chain starting with the genesis block.
+    ///
+    /// See [ClientT::replay_from].
+    async fn replay_from_genesis(
+        &self,
+    ) -> Result<BoxStream<'static, Result<(BlockNumber, Vec<Event>), Error>>, Error>;
+
+    /// Replay the events of the blocks on the best chain starting with block `start`.
+    ///
+    /// The stream yields the block number and the events emitted in the block for every block
+    /// from `start` up to the tip of the best chain at the time of the call. Blocks are fetched
+    /// one by one while the stream is consumed. To resume an interrupted replay call this
+    /// method with the number of the block after the last processed block.
+    async fn replay_from(
+        &self,
+        start: BlockNumber,
+    ) -> Result<BoxStream<'static, Result<(BlockNumber, Vec<Event>), Error>>, Error>;
+
     /// Get the runtime version at the latest block
     async fn runtime_version(&self) -> Result<RuntimeVersion, Error>;
 
+    /// Get the name of the chain the client is connected to, for example `"Radicle Registry ffnet"`.
+    ///
+    /// The name is taken from the chain spec of the node.
+    async fn chain_name(&self) -> Result<String, Error>;
+
+    /// Get the proof-of-work algorithm the chain uses to seal blocks.
+    ///
+    /// Blocks on chains using [PowKind::Dummy] can be created at will, so confirmations on these
+    /// chains are meaningless.
+    async fn pow_algorithm(&self) -> Result<PowKind, Error>;
+
+    /// Fetch the total proof-of-work difficulty of the chain up to and including the given block.
+    ///
+    /// The node follows the fork with the highest total difficulty. Comparing the total
+    /// difficulty of competing chain tips allows checking the choice of the best chain
+    /// independently of the node. On the emulator every block has difficulty one.
+    ///
+    /// Returns [Error::BlockMissing] if the block is unknown.
+    async fn chain_total_work(&self, block_hash: BlockHash) -> Result<U256, Error>;
+
     async fn free_balance(&self, account_id: &AccountId) -> Result<Balance, Error>;
 
+    /// Fetch the free balances of the given accounts in a single request.
+    ///
+    /// The result contains an entry for every account in `account_ids` in the same order.
+    /// Accounts that do not exist have a balance of zero.
+    async fn free_balances(
+        &self,
+        account_ids: &[AccountId],
+    ) -> Result<Vec<(AccountId, Balance)>, Error>;
+
+    /// Fetch the nonce and the free balance of the account.
+    ///
+    /// Both values are read from the same storage entry with one request, so they always belong
+    /// to the same block.
+    async fn get_account(&self, account_id: &AccountId) -> Result<Account, Error>;
+
+    /// Fetch the funds of the account that are reserved and cannot be transferred.
+    ///
+    /// Funds are reserved for the deposit of [message::ReserveId]. See
+    /// [crate::ID_RESERVATION_DEPOSIT].
+    async fn reserved_balance(&self, account_id: &AccountId) -> Result<Balance, Error>;
+
+    /// Fetch the sum of the free and the reserved funds of the account.
+    async fn total_balance(&self, account_id: &AccountId) -> Result<Balance, Error>;
+
+    /// Fetch the total amount of funds in existence.
+    ///
+    /// Block rewards increase the total issuance. The burned share of transaction fees and
+    /// registration fees decrease it.
+    async fn total_issuance(&self) -> Result<Balance, Error>;
+
     async fn get_id_status(&self, id: &Id) -> Result<IdStatus, Error>;
 
+    /// Returns `true` if an org or user can be registered with the given ID.
+    ///
+    /// Mirrors the check of the runtime: the ID must not be taken by a user or an org and must not
+    /// be retired. Reservations made with [message::ReserveId] do not disclose the ID and are not
+    /// taken into account.
+    async fn is_id_available(&self, id: Id) -> Result<bool, Error>;
+
+    #[deprecated(note = "use Client::registry()")]
     async fn get_org(&self, org_id: Id) -> Result<Option<state::Orgs1Data>, Error>;
 
+    /// List the IDs of all registered orgs in ascending order.
+    #[deprecated(note = "use Client::registry()")]
     async fn list_orgs(&self) -> Result<Vec<Id>, Error>;
 
+    /// List the IDs of the members of an org in ascending order.
+    ///
+    /// Skips the first `offset` members and returns at most `limit` members. Reads the member
+    /// index instead of the whole org. Returns an empty list if the org does not exist.
+    #[deprecated(note = "use Client::registry()")]
+    async fn list_org_members(
+        &self,
+        org_id: Id,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<Id>, Error>;
+
+    #[deprecated(note = "use Client::registry()")]
     async fn get_user(&self, user_id: Id) -> Result<Option<state::Users1Data>, Error>;
 
+    /// List the IDs of all registered users in ascending order.
+    #[deprecated(note = "use Client::registry()")]
     async fn list_users(&self) -> Result<Vec<Id>, Error>;
 
+    /// Fetch the users with the given IDs in a single request.
+    ///
+    /// The result contains an entry for every ID in `user_ids` in the same order.
+    async fn get_users(&self, user_ids: Vec<Id>) -> Result<Vec<Option<state::Users1Data>>, Error>;
+
+    /// Find the transaction with the given hash in the most recent blocks of the best chain.
+    ///
+    /// Searches at most [crate::TRANSACTION_SEARCH_DEPTH] blocks starting with the best chain tip.
+    /// Returns `None` if the transaction is not found in these blocks.
+    async fn get_transaction(&self, tx_hash: TxHash) -> Result<Option<TransactionRecord>, Error>;
+
+    /// Return the ID of the user associated with `account_id`, if any.
+    async fn user_of_account(&self, account_id: AccountId) -> Result<Option<Id>, Error>;
+
+    /// Fetch the balance and nonce of the account together with its user, the orgs the user is a
+    /// member of, and the projects of the user.
+    ///
+    /// The data is read from the state of the block selected by `Client::at`, or the best chain
+    /// tip at the time of the call if no block is selected. If the account has no associated
+    /// user, `orgs` and `projects` are empty.
+    async fn account_profile(&self, account_id: AccountId) -> Result<AccountProfile, Error>;
+
+    #[deprecated(note = "use Client::registry()")]
     async fn get_project(
         &self,
         project_name: ProjectName,
         project_domain: ProjectDomain,
     ) -> Result<Option<state::Projects1Data>, Error>;
 
+    /// List the IDs of all registered projects in ascending order.
+    ///
+    /// Projects are ordered by name first and by domain second. See [ProjectDomain] for the
+    /// ordering of domains.
+    #[deprecated(note = "use Client::registry()")]
     async fn list_projects(&self) -> Result<Vec<ProjectId>, Error>;
+
+    /// Fetch the projects with the given IDs in a single request.
+    ///
+    /// The result contains an entry for every ID in `project_ids` in the same order.
+    async fn get_projects(
+        &self,
+        project_ids: Vec<ProjectId>,
+    ) -> Result<Vec<Option<state::Projects1Data>>, Error>;
+
+    /// Fetch a project that is waiting to be accepted or rejected by the sudo key.
+    async fn get_pending_project(
+        &self,
+        project_name: ProjectName,
+        project_domain: ProjectDomain,
+    ) -> Result<Option<state::PendingProjects1Data>, Error>;
+
+    /// List the IDs of all pending projects in ascending order.
+    ///
+    /// Projects are only pending if project moderation is enabled in the genesis config.
+    async fn list_pending_projects(&self) -> Result<Vec<ProjectId>, Error>;
+
+    /// Count the entries in the runtime state storage whose keys start with `prefix`.
+    ///
+    /// The values of the entries are not fetched. To count the entries of a storage map use the
+    /// `final_prefix()` of the map as the prefix.
+    async fn count_entries(&self, prefix: &[u8]) -> Result<u64, Error>;
+
+    /// Count the registered orgs. Cheaper than `list_orgs().len()`.
+    async fn org_count(&self) -> Result<u64, Error>;
+
+    /// Count the registered users. Cheaper than `list_users().len()`.
+    async fn user_count(&self) -> Result<u64, Error>;
+
+    /// Count the registered projects. Cheaper than `list_projects().len()`.
+    async fn project_count(&self) -> Result<u64, Error>;
 }
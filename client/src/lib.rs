@@ -29,25 +29,54 @@
 //! A [Transaction] can be created and signed offline using [Transaction::new_signed]. This
 //! constructor requires the account nonce and genesis hash of the chain. Those can be obtained
 //! using [ClientT::account_nonce] and [ClientT::genesis_hash]. See [Transaction] for more details.
-use std::sync::Arc;
+//!
+//! [Transaction::new_signed_sequence] signs a list of messages with consecutive nonces so that the
+//! transactions can be submitted later in order.
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use parity_scale_codec::{Decode, FullCodec};
+use parity_scale_codec::{Compact, Decode, Encode as _, FullCodec};
 
-use frame_support::storage::generator::{StorageMap, StorageValue};
+use frame_support::storage::generator::{StorageDoubleMap, StorageMap, StorageValue};
 use frame_support::storage::StoragePrefixedMap;
-use radicle_registry_runtime::{store, store::DecodeKey as _};
+use frame_support::traits::Get as _;
+use futures::channel::oneshot;
+use futures::future::{self, Future, FutureExt as _};
+use futures::stream::{BoxStream, StreamExt as _, TryStreamExt as _};
+use radicle_registry_runtime::{
+    call, fees::BURN_SHARE, store, store::DecodeKey as _, AvailableBlockRatio, Call as RuntimeCall,
+    Hashing, MaximumBlockLength, SignedExtra,
+};
+use sp_runtime::traits::{Hash as _, Header as _};
+use sp_runtime::Permill;
 
 mod backend;
 mod error;
 mod event;
 mod interface;
 pub mod message;
+pub mod registry_api;
 mod transaction;
 
 pub use crate::interface::*;
-pub use backend::{EmulatorControl, EMULATOR_BLOCK_AUTHOR};
+pub use backend::{emulator_genesis_config, EmulatorControl, EMULATOR_BLOCK_AUTHOR};
 pub use radicle_registry_core::{state, Balance};
 pub use radicle_registry_runtime::fees::{MINIMUM_TX_FEE, REGISTRATION_FEE};
+pub use radicle_registry_runtime::registry::{
+    BLOCK_REWARD, ID_RESERVATION_DEPOSIT, ID_RESERVATION_PERIOD, MAX_BATCH_CALLS,
+    MAX_MULTI_TRANSFER_RECIPIENTS, MAX_ORG_MEMBERS,
+};
+pub use registry_api::RegistryApi;
+
+/// Maximum number of blocks [ClientT::block_headers] fetches with one call.
+pub const MAX_BLOCK_HEADERS_RANGE: u32 = 1000;
+
+/// Number of blocks [ClientT::get_transaction] searches for a transaction.
+pub const TRANSACTION_SEARCH_DEPTH: u32 = 256;
+
+/// Secret URIs of the well-known ed25519 development keys that development chains endow.
+pub const DEV_ACCOUNT_SEEDS: &[&str] = &["//Alice", "//Bob", "//Alice//stash", "//Bob//stash"];
 
 /// Client to interact with the radicle registry ledger via an implementation of [ClientT].
 ///
@@ -56,6 +85,12 @@ pub use radicle_registry_runtime::fees::{MINIMUM_TX_FEE, REGISTRATION_FEE};
 #[derive(Clone)]
 pub struct Client {
     backend: Arc<dyn backend::Backend + Sync + Send>,
+    /// Nonce of the next transaction of accounts that the client signed messages for. Saves
+    /// fetching the nonce for every message. See [Client::next_nonce].
+    nonces: Arc<Mutex<HashMap<AccountId, state::AccountTransactionIndex>>>,
+    /// Block to read the state from. If `None` the state is read from the best chain tip. See
+    /// [Client::at].
+    at: Option<BlockHash>,
 }
 
 impl Client {
@@ -77,6 +112,213 @@ impl Client {
         Ok(Self::new(backend))
     }
 
+    /// Check that the client is connected to the chain named `expected`.
+    ///
+    /// Fails with [Error::WrongChain] if [ClientT::chain_name] returns a different name.
+    /// Transactions signed for one chain are useless on another chain, so users of the client
+    /// should call this after connecting.
+    pub async fn ensure_chain_name(&self, expected: &str) -> Result<(), Error> {
+        let found = self.chain_name().await?;
+        if found == expected {
+            Ok(())
+        } else {
+            Err(Error::WrongChain {
+                expected: expected.to_string(),
+                found,
+            })
+        }
+    }
+
+    /// Register the org `org_id` with `author` as its only member unless the org already exists.
+    ///
+    /// Returns `true` if the org was registered and `false` if it already existed. Fails with
+    /// [Error::RegistrationFailed] if the registration transaction fails and the org still does
+    /// not exist afterwards, for example because the ID is taken by a user.
+    #[allow(deprecated)]
+    pub async fn ensure_org_registered(
+        &self,
+        author: &ed25519::Pair,
+        org_id: Id,
+        fee: Balance,
+    ) -> Result<bool, Error> {
+        if self.get_org(org_id.clone()).await?.is_some() {
+            return Ok(false);
+        }
+
+        let message = message::RegisterOrg {
+            org_id: org_id.clone(),
+            reservation_salt: None,
+        };
+        let tx_included = self
+            .sign_and_submit_message(author, message, fee)
+            .await?
+            .await?;
+        match tx_included.result {
+            Ok(()) => Ok(true),
+            // Another transaction may have registered the org in the meantime.
+            Err(error) => match self.get_org(org_id).await? {
+                Some(_) => Ok(false),
+                None => Err(Error::RegistrationFailed(error)),
+            },
+        }
+    }
+
+    /// Register the user `user_id` associated with the `author` account unless that user
+    /// already exists.
+    ///
+    /// Returns `true` if the user was registered and `false` if it already existed. Fails with
+    /// [Error::RegistrationFailed] if the user exists but is associated with a different account
+    /// or if the registration transaction fails.
+    #[allow(deprecated)]
+    pub async fn ensure_user_registered(
+        &self,
+        author: &ed25519::Pair,
+        user_id: Id,
+        fee: Balance,
+    ) -> Result<bool, Error> {
+        let author_account = author.public();
+        let check_existing = |user: state::Users1Data| {
+            if user.account_id() == author_account {
+                Ok(false)
+            } else {
+                Err(Error::RegistrationFailed(
+                    RegistryError::IdAlreadyTaken.into(),
+                ))
+            }
+        };
+
+        if let Some(user) = self.get_user(user_id.clone()).await? {
+            return check_existing(user);
+        }
+
+        let message = message::RegisterUser {
+            user_id: user_id.clone(),
+            reservation_salt: None,
+        };
+        let tx_included = self
+            .sign_and_submit_message(author, message, fee)
+            .await?
+            .await?;
+        match tx_included.result {
+            Ok(()) => Ok(true),
+            // Another transaction may have registered the user in the meantime.
+            Err(error) => match self.get_user(user_id).await? {
+                Some(user) => check_existing(user),
+                None => Err(Error::RegistrationFailed(error)),
+            },
+        }
+    }
+
+    /// Wait until the org `org_id` exists and return it.
+    ///
+    /// The org is looked up immediately and whenever a new block is imported. Fails with
+    /// [Error::Timeout] if the org does not exist after `timeout`.
+    #[allow(deprecated)]
+    pub async fn wait_for_org(
+        &self,
+        org_id: Id,
+        timeout: Duration,
+    ) -> Result<state::Orgs1Data, Error> {
+        self.wait_for(timeout, || self.get_org(org_id.clone()))
+            .await
+    }
+
+    /// Wait until the user `user_id` exists and return it.
+    ///
+    /// See [Client::wait_for_org].
+    #[allow(deprecated)]
+    pub async fn wait_for_user(
+        &self,
+        user_id: Id,
+        timeout: Duration,
+    ) -> Result<state::Users1Data, Error> {
+        self.wait_for(timeout, || self.get_user(user_id.clone()))
+            .await
+    }
+
+    /// Wait until the project `project_name` exists in `project_domain` and return it.
+    ///
+    /// See [Client::wait_for_org].
+    #[allow(deprecated)]
+    pub async fn wait_for_project(
+        &self,
+        project_name: ProjectName,
+        project_domain: ProjectDomain,
+        timeout: Duration,
+    ) -> Result<state::Projects1Data, Error> {
+        self.wait_for(timeout, || {
+            self.get_project(project_name.clone(), project_domain.clone())
+        })
+        .await
+    }
+
+    /// Call `fetch` now and for every new block until it returns a value.
+    ///
+    /// Fails with [Error::Timeout] if `fetch` does not return a value after `timeout`.
+    async fn wait_for<T, Fetch, FetchFuture>(
+        &self,
+        timeout: Duration,
+        fetch: Fetch,
+    ) -> Result<T, Error>
+    where
+        Fetch: Fn() -> FetchFuture,
+        FetchFuture: Future<Output = Result<Option<T>, Error>>,
+    {
+        let wait = async {
+            // We subscribe before the first lookup so that we don’t miss a block.
+            let mut new_heads = self.backend.subscribe_new_heads().await?;
+            loop {
+                if let Some(value) = fetch().await? {
+                    return Ok(value);
+                }
+                match new_heads.next().await {
+                    Some(header) => {
+                        header?;
+                    }
+                    // No new blocks will be reported so we can only wait for the timeout.
+                    None => future::pending::<()>().await,
+                }
+            }
+        };
+        futures::pin_mut!(wait);
+        match future::select(wait, futures_timer::Delay::new(timeout)).await {
+            future::Either::Left((result, _)) => result,
+            future::Either::Right(_) => Err(Error::Timeout { timeout }),
+        }
+    }
+
+    /// Find the account that is charged the transaction fee when `author` submits `message`.
+    ///
+    /// Mirrors the fee payment logic of the runtime. The org pays for messages that concern an org
+    /// if the user associated with `author` is a member of that org. In all other cases the
+    /// author pays.
+    #[allow(deprecated)]
+    pub async fn fee_payer_for<Message_: Message + Clone>(
+        &self,
+        author: &AccountId,
+        message: &Message_,
+    ) -> Result<AccountId, Error> {
+        let call = message.clone().into_runtime_call();
+        let org_id = match radicle_registry_runtime::fees::payer_org(&call) {
+            Some(org_id) => org_id.clone(),
+            None => return Ok(*author),
+        };
+        let org = match self.get_org(org_id).await? {
+            Some(org) => org,
+            None => return Ok(*author),
+        };
+        let members = self.get_users(org.members().clone()).await?;
+        let author_is_member = members
+            .iter()
+            .flatten()
+            .any(|user| user.account_id() == *author);
+        if author_is_member {
+            Ok(org.account_id())
+        } else {
+            Ok(*author)
+        }
+    }
+
     /// Create a new client that emulates the registry ledger in memory. Also returns a control
     /// handle to manipulate the emulator state. See [backend::Emulator] and [EmulatorControl] for
     /// details.
@@ -87,10 +329,236 @@ impl Client {
         (client, control)
     }
 
+    /// Create a new client that emulates the registry ledger in memory with the genesis state
+    /// built from `genesis_config`.
+    ///
+    /// Use [emulator_genesis_config] as a starting point for the genesis config.
+    pub fn new_emulator_with_genesis(
+        genesis_config: radicle_registry_runtime::genesis::GenesisConfig,
+    ) -> (Self, EmulatorControl) {
+        let emulator = backend::Emulator::from_genesis_config(genesis_config);
+        let control = emulator.control();
+        let client = Self::new(emulator);
+        (client, control)
+    }
+
+    /// Compute the account that is assigned to the org `org_id` on chains where
+    /// [ClientT::deterministic_org_accounts] is `true`.
+    ///
+    /// The account is derived from the Blake2 256 hash of the SCALE encoded org ID.
+    pub fn derived_org_account(org_id: &Id) -> AccountId {
+        radicle_registry_runtime::registry::derived_org_account(org_id)
+    }
+
+    /// Queries for orgs, users, and projects grouped by entity.
+    ///
+    /// ```no_run
+    /// # use radicle_registry_client::*;
+    /// # async fn example(client: Client) -> Result<(), Error> {
+    /// let org_ids = client.registry().orgs().list().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn registry(&self) -> RegistryApi<'_> {
+        RegistryApi::new(self)
+    }
+
+    /// Create a client that emulates the registry ledger in memory with the genesis state read
+    /// from a raw chain spec file.
+    ///
+    /// Raw chain specs are created with `radicle-registry-node build-spec --raw`. This allows
+    /// querying the genesis state of a chain without running a node. Transactions submitted to
+    /// the client are only applied to the in-memory state.
+    pub fn new_from_genesis_json(path: impl AsRef<std::path::Path>) -> Result<Self, Error> {
+        let storage = backend::read_raw_genesis_storage(path.as_ref())?;
+        Ok(Self::new(backend::Emulator::from_storage(storage)))
+    }
+
     fn new(backend: impl backend::Backend + Sync + Send + 'static) -> Self {
         Client {
             backend: Arc::new(backend),
+            nonces: Arc::new(Mutex::new(HashMap::new())),
+            at: None,
+        }
+    }
+
+    /// Return a client that reads the registry state at the block with the given hash instead of
+    /// the best chain tip.
+    ///
+    /// This applies to all [ClientT] methods that read the state, for example
+    /// [ClientT::free_balance] or [ClientT::get_org]. Nodes prune the state of old blocks by
+    /// default. Reading the state of an old block only works against nodes that run with
+    /// `--archive`. The emulator only holds the state of the chain tip and fails with
+    /// [Error::Unsupported] for other blocks.
+    ///
+    /// Transactions are still signed with the current nonce of the author.
+    pub fn at(&self, block_hash: BlockHash) -> Client {
+        Client {
+            at: Some(block_hash),
+            ..self.clone()
+        }
+    }
+
+    /// Create a transaction for `call` signed by `author` using the next nonce of `author`.
+    ///
+    /// Also returns whether the nonce was taken from the cache.
+    async fn sign_call<Message_: Message>(
+        &self,
+        author: &ed25519::Pair,
+        call: RuntimeCall,
+        fee: Balance,
+    ) -> Result<(Transaction<Message_>, bool), Error> {
+        let (nonce, cached) = self.next_nonce(&author.public(), 1).await?;
+        let runtime_transaction_version = self.runtime_version().await?.transaction_version;
+        let transaction = Transaction::new_signed_call(
+            author,
+            call,
+            TransactionExtra {
+                nonce,
+                genesis_hash: self.genesis_hash(),
+                fee,
+                runtime_transaction_version,
+                longevity: None,
+                era: None,
+            },
+        );
+        Ok((transaction, cached))
+    }
+
+    /// Sign `message` with the next nonce of `author` and pass the transaction to `submit`.
+    ///
+    /// If submitting fails with a cached nonce the transactions of `author` may have been
+    /// submitted by another client. In that case the nonce is fetched from the chain and the
+    /// message is signed and submitted again.
+    async fn sign_and_submit_with<Message_, T, Submit, SubmitFuture>(
+        &self,
+        author: &ed25519::Pair,
+        message: Message_,
+        fee: Balance,
+        submit: Submit,
+    ) -> Result<T, Error>
+    where
+        Message_: Message,
+        Submit: Fn(Transaction<Message_>) -> SubmitFuture,
+        SubmitFuture: Future<Output = Result<T, Error>>,
+    {
+        let call = message.into_runtime_call();
+        let (transaction, cached) = self.sign_call(author, call.clone(), fee).await?;
+        let result = match submit(transaction).await {
+            Err(_) if cached => {
+                self.forget_nonce(&author.public());
+                let (transaction, _) = self.sign_call(author, call, fee).await?;
+                submit(transaction).await
+            }
+            result => result,
+        };
+        if result.is_err() {
+            self.forget_nonce(&author.public());
+        }
+        result
+    }
+
+    /// Submit `transaction` and return a future for its inclusion and a future for its
+    /// confirmation. See [ClientT::sign_and_submit_message_confirmed].
+    async fn submit_transaction_confirmed<Message_: Message>(
+        &self,
+        transaction: Transaction<Message_>,
+        confirmations: u32,
+    ) -> Result<(Response<TransactionIncluded, Error>, Response<(), Error>), Error> {
+        let tx_hash = Hashing::hash_of(&transaction.extrinsic);
+        let tx_included_future = self.backend.submit(transaction.extrinsic).await?;
+
+        // Both responses poll the shared future so that either one can be awaited on its own.
+        // The inclusion result is sent to the inclusion response because errors are not `Clone`.
+        let (tx_included_sender, tx_included_receiver) = oneshot::channel();
+        let included_block = async move {
+            let tx_included = tx_included_future.await;
+            let block = tx_included
+                .as_ref()
+                .ok()
+                .map(|tx_included| tx_included.block);
+            let _ = tx_included_sender.send(tx_included);
+            block
         }
+        .boxed()
+        .shared();
+
+        let tx_included_response = {
+            let included_block = included_block.clone();
+            async move {
+                included_block.await;
+                let tx_included = tx_included_receiver
+                    .await
+                    .expect("Inclusion result is sent before the shared future completes")?;
+                transaction_included::<Message_>(tx_included)
+            }
+        };
+
+        let backend = self.backend.clone();
+        let confirmed_response = async move {
+            let block_hash = included_block
+                .await
+                .ok_or(Error::TransactionNotIncluded { tx_hash })?;
+            wait_for_confirmations(backend, tx_hash, block_hash, confirmations).await
+        };
+
+        Ok((tx_included_response.boxed(), confirmed_response.boxed()))
+    }
+
+    /// Return the first of the nonces for the next `count` transactions of `account_id` and
+    /// advance the cached nonce past them.
+    ///
+    /// The nonce is fetched from the chain if it is not cached. Also returns whether the nonce
+    /// was taken from the cache. Fails with [Error::NonceOverflow] if the last nonce exceeds the
+    /// nonce range.
+    async fn next_nonce(
+        &self,
+        account_id: &AccountId,
+        count: u32,
+    ) -> Result<(state::AccountTransactionIndex, bool), Error> {
+        if let Some(nonce) = self.take_cached_nonces(account_id, None, count)? {
+            return Ok((nonce, true));
+        }
+        let chain_nonce = self
+            .fetch_map_value_at::<store::Account, _, _>(*account_id, None)
+            .await?
+            .nonce;
+        let nonce = self
+            .take_cached_nonces(account_id, Some(chain_nonce), count)?
+            .expect("Nonce is cached");
+        Ok((nonce, false))
+    }
+
+    /// Return the cached nonce of `account_id` and advance it by `count`. If no nonce is cached
+    /// and `chain_nonce` is given it is cached first.
+    ///
+    /// Another call may have cached a nonce while `chain_nonce` was fetched. The cached nonce
+    /// takes precedence so that both calls use different nonces.
+    fn take_cached_nonces(
+        &self,
+        account_id: &AccountId,
+        chain_nonce: Option<state::AccountTransactionIndex>,
+        count: u32,
+    ) -> Result<Option<state::AccountTransactionIndex>, Error> {
+        let mut nonces = self.nonces.lock().unwrap();
+        if let Some(chain_nonce) = chain_nonce {
+            nonces.entry(*account_id).or_insert(chain_nonce);
+        }
+        let next_nonce = match nonces.get_mut(account_id) {
+            Some(next_nonce) => next_nonce,
+            None => return Ok(None),
+        };
+        let nonce = *next_nonce;
+        *next_nonce = nonce
+            .checked_add(count)
+            .ok_or(Error::NonceOverflow { nonce, count })?;
+        Ok(Some(nonce))
+    }
+
+    /// Remove the cached nonce of `account_id` so that the nonce of the next transaction is
+    /// fetched from the chain.
+    fn forget_nonce(&self, account_id: &AccountId) {
+        self.nonces.lock().unwrap().remove(account_id);
     }
 
     /// Fetch a value from the state storage based on a [StorageValue] implementation provided by
@@ -99,7 +567,6 @@ impl Client {
     /// ```ignore
     /// client.fetch_value::<frame_balance::TotalIssuance<Runtime>, _>();
     /// ```
-    #[allow(dead_code)]
     async fn fetch_value<S: StorageValue<Value>, Value: FullCodec + Send + 'static>(
         &self,
     ) -> Result<S::Query, Error>
@@ -108,12 +575,13 @@ impl Client {
     {
         let backend = self.backend.clone();
         let key = S::storage_value_final_key();
-        let maybe_data = backend.fetch(&key, None).await?;
+        let maybe_data = backend.fetch(&key, self.at).await?;
         let value = match maybe_data {
             Some(data) => {
                 let value =
                     Decode::decode(&mut &data[..]).map_err(|error| Error::StateDecoding {
                         error,
+                        item: std::any::type_name::<S>(),
                         key: key.to_vec(),
                     })?;
                 Some(value)
@@ -123,6 +591,43 @@ impl Client {
         Ok(S::from_optional_value_to_query(value))
     }
 
+    /// Fetch the values for multiple keys of a [StorageMap] with a single backend request.
+    ///
+    /// The result contains the query value for every key in the same order as `keys`. If
+    /// `block_hash` is `None` the values are read from the best chain tip.
+    async fn fetch_map_values<
+        S: StorageMap<Key, Value>,
+        Key: FullCodec,
+        Value: FullCodec + Send + 'static,
+    >(
+        &self,
+        keys: Vec<Key>,
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<S::Query>, Error>
+    where
+        S::Query: Send + 'static,
+    {
+        let backend = self.backend.clone();
+        let keys: Vec<Vec<u8>> = keys.into_iter().map(S::storage_map_final_key).collect();
+        let values = backend.fetch_many(keys.clone(), block_hash).await?;
+        keys.into_iter()
+            .zip(values)
+            .map(|(key, maybe_data)| {
+                let value = match maybe_data {
+                    Some(data) => Some(Decode::decode(&mut &data[..]).map_err(|error| {
+                        Error::StateDecoding {
+                            error,
+                            item: std::any::type_name::<S>(),
+                            key,
+                        }
+                    })?),
+                    None => None,
+                };
+                Ok(S::from_optional_value_to_query(value))
+            })
+            .collect()
+    }
+
     /// Check that a key exists in a state store.
     async fn store_contains_key<
         S: StorageMap<Key, Value>,
@@ -139,7 +644,10 @@ impl Client {
         // We cannot move this code into the async block. The compiler complains about a processing
         // cycle (E0391)
         let key = S::storage_map_final_key(key);
-        backend.fetch(&key, None).await.map(|data| data.is_some())
+        backend
+            .fetch(&key, self.at)
+            .await
+            .map(|data| data.is_some())
     }
 
     /// Fetch a value from a map in the state storage based on a [StorageMap] implementation
@@ -156,6 +664,23 @@ impl Client {
         &self,
         key: Key,
     ) -> Result<S::Query, Error>
+    where
+        S::Query: Send + 'static,
+    {
+        self.fetch_map_value_at::<S, _, _>(key, self.at).await
+    }
+
+    /// Same as [Client::fetch_map_value] but reads the value from the state of the given block.
+    /// If `block_hash` is `None` the value is read from the best chain tip.
+    async fn fetch_map_value_at<
+        S: StorageMap<Key, Value>,
+        Key: FullCodec,
+        Value: FullCodec + Send + 'static,
+    >(
+        &self,
+        key: Key,
+        block_hash: Option<BlockHash>,
+    ) -> Result<S::Query, Error>
     where
         S::Query: Send + 'static,
     {
@@ -163,11 +688,15 @@ impl Client {
         // We cannot move this code into the async block. The compiler complains about a processing
         // cycle (E0391)
         let key = S::storage_map_final_key(key);
-        let maybe_data = backend.fetch(&key, None).await?;
+        let maybe_data = backend.fetch(&key, block_hash).await?;
         let value = match maybe_data {
             Some(data) => {
-                let value = Decode::decode(&mut &data[..])
-                    .map_err(|error| Error::StateDecoding { error, key })?;
+                let value =
+                    Decode::decode(&mut &data[..]).map_err(|error| Error::StateDecoding {
+                        error,
+                        item: std::any::type_name::<S>(),
+                        key,
+                    })?;
                 Some(value)
             }
             None => None,
@@ -186,42 +715,148 @@ impl ClientT for Client {
         let tx_included_future = backend.submit(transaction.extrinsic).await?;
         Ok(Box::pin(async move {
             let tx_included = tx_included_future.await?;
-            let events = tx_included.events;
-            let tx_hash = tx_included.tx_hash;
-            let block = tx_included.block;
-            let result = Message_::result_from_events(events)
-                .map_err(|error| Error::EventExtraction { error, tx_hash })?;
-            Ok(TransactionIncluded {
-                tx_hash,
-                block,
-                result,
-            })
+            transaction_included::<Message_>(tx_included)
         }))
     }
 
+    async fn submit_tracked<Message_: Message>(
+        &self,
+        transaction: Transaction<Message_>,
+    ) -> Result<TxTracker, Error> {
+        let progress_stream = self.backend.submit_tracked(transaction.extrinsic).await?;
+        Ok(progress_stream
+            .map(|progress| {
+                Ok(match progress? {
+                    backend::TransactionProgress::InPool => TxState::InPool,
+                    backend::TransactionProgress::InBlock(block_hash) => {
+                        TxState::InBlock(block_hash)
+                    }
+                    backend::TransactionProgress::Included(tx_included) => {
+                        TxState::Included(transaction_included::<Message_>(tx_included)?)
+                    }
+                })
+            })
+            .boxed())
+    }
+
     async fn sign_and_submit_message<Message_: Message>(
         &self,
         author: &ed25519::Pair,
         message: Message_,
         fee: Balance,
     ) -> Result<Response<TransactionIncluded, Error>, Error> {
-        let account_id = author.public();
-        let key_pair = author.clone();
-        let genesis_hash = self.genesis_hash();
-        let client = self.clone();
-        let nonce = client.account_nonce(&account_id).await?;
-        let runtime_transaction_version = self.runtime_version().await?.transaction_version;
-        let transaction = Transaction::new_signed(
-            &key_pair,
-            message,
-            TransactionExtra {
-                nonce,
-                genesis_hash,
-                fee,
-                runtime_transaction_version,
-            },
-        );
-        client.submit_transaction(transaction).await
+        self.sign_and_submit_with(author, message, fee, |transaction| {
+            self.submit_transaction(transaction)
+        })
+        .await
+    }
+
+    async fn sign_and_submit_message_confirmed<Message_: Message>(
+        &self,
+        author: &ed25519::Pair,
+        message: Message_,
+        fee: Balance,
+        confirmations: u32,
+    ) -> Result<(Response<TransactionIncluded, Error>, Response<(), Error>), Error> {
+        self.sign_and_submit_with(author, message, fee, |transaction| {
+            self.submit_transaction_confirmed(transaction, confirmations)
+        })
+        .await
+    }
+
+    async fn submit_nowait<Message_: Message>(
+        &self,
+        author: &ed25519::Pair,
+        message: Message_,
+        fee: Balance,
+    ) -> Result<TxHash, Error> {
+        self.sign_and_submit_with(author, message, fee, |transaction| {
+            self.backend.submit_nowait(transaction.extrinsic)
+        })
+        .await
+    }
+
+    async fn sudo<Message_: Message>(
+        &self,
+        sudo_key: &ed25519::Pair,
+        message: Message_,
+        fee: Balance,
+    ) -> Result<Response<TransactionIncluded, Error>, Error> {
+        self.sign_and_submit_message(sudo_key, message::Sudo(message), fee)
+            .await
+    }
+
+    async fn submit_batch(
+        &self,
+        author: &ed25519::Pair,
+        messages: Vec<Box<dyn Message>>,
+        fee: Balance,
+    ) -> Result<Response<TransactionIncluded, Error>, Error> {
+        self.sign_and_submit_message(author, message::Batch::new(messages), fee)
+            .await
+    }
+
+    async fn faucet(
+        &self,
+        recipient: AccountId,
+        amount: Balance,
+    ) -> Result<Response<TransactionIncluded, Error>, Error> {
+        let transaction = Transaction::new_unsigned(message::Faucet { recipient, amount });
+        self.submit_transaction(transaction).await
+    }
+
+    async fn set_runtime_code(
+        &self,
+        sudo_key: &ed25519::Pair,
+        code: Vec<u8>,
+        fee: Balance,
+    ) -> Result<RuntimeVersion, Error> {
+        let old_spec_version = self.runtime_version().await?.spec_version;
+        let tx_included = self
+            .sign_and_submit_message(sudo_key, message::UpdateRuntime { code }, fee)
+            .await?
+            .await?;
+        tx_included.result.map_err(Error::RuntimeUpdateFailed)?;
+
+        let new_version = self.runtime_version().await?;
+        if new_version.spec_version == old_spec_version {
+            return Err(Error::RuntimeVersionUnchanged(old_spec_version));
+        }
+        Ok(new_version)
+    }
+
+    async fn total_issuance(&self) -> Result<Balance, Error> {
+        self.fetch_value::<store::TotalIssuance, _>().await
+    }
+
+    async fn deterministic_org_accounts(&self) -> Result<bool, Error> {
+        self.fetch_value::<store::DeterministicOrgAccounts, _>()
+            .await
+    }
+
+    async fn sudo_key(&self) -> Result<AccountId, Error> {
+        self.fetch_value::<store::SudoKey, _>().await
+    }
+
+    async fn root_accounts(&self) -> Result<Vec<AccountId>, Error> {
+        Ok(vec![self.sudo_key().await?])
+    }
+
+    async fn system_accounts(&self) -> Result<SystemAccounts, Error> {
+        let sudo_key = self.sudo_key().await?;
+        let mut dev_accounts = Vec::new();
+        for seed in DEV_ACCOUNT_SEEDS {
+            let account_id = ed25519::Pair::from_string(seed, None)
+                .expect("Development key seeds are valid")
+                .public();
+            if self.account_exists(&account_id).await? {
+                dev_accounts.push(account_id);
+            }
+        }
+        Ok(SystemAccounts {
+            sudo_key,
+            dev_accounts,
+        })
     }
 
     async fn block_header(&self, block_hash: BlockHash) -> Result<Option<BlockHeader>, Error> {
@@ -233,10 +868,93 @@ impl ClientT for Client {
         maybe_header.ok_or_else(|| Error::BestChainTipHeaderMissing)
     }
 
+    async fn best_block_number(&self) -> Result<BlockNumber, Error> {
+        Ok(self.block_header_best_chain().await?.number)
+    }
+
+    async fn block_headers(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> Result<Vec<BlockHeader>, Error> {
+        if to < from {
+            return Ok(Vec::new());
+        }
+        if to - from >= MAX_BLOCK_HEADERS_RANGE {
+            return Err(Error::RangeTooLarge {
+                from,
+                to,
+                limit: MAX_BLOCK_HEADERS_RANGE,
+            });
+        }
+
+        let block_numbers = (from..=to).collect::<Vec<_>>();
+        let block_hashes = self.backend.block_hashes(block_numbers.clone()).await?;
+        let header_futures = block_numbers.into_iter().zip(block_hashes).map(
+            |(block_number, maybe_block_hash)| async move {
+                let block_hash =
+                    maybe_block_hash.ok_or(Error::BlockNumberMissing { block_number })?;
+                self.backend
+                    .block_header(Some(block_hash))
+                    .await?
+                    .ok_or(Error::BlockMissing { block_hash })
+            },
+        );
+        futures::future::try_join_all(header_futures).await
+    }
+
+    async fn block_extrinsics(
+        &self,
+        block_hash: BlockHash,
+    ) -> Result<Vec<DecodedExtrinsic>, Error> {
+        let block = self
+            .backend
+            .block(block_hash)
+            .await?
+            .ok_or(Error::BlockMissing { block_hash })?;
+        let event_records = self.backend.block_event_records(block_hash).await?;
+
+        Ok(block
+            .extrinsics
+            .into_iter()
+            .enumerate()
+            .map(|(index, xt)| {
+                let events = event_records
+                    .iter()
+                    .filter(|record| event::transaction_index(record) == Some(index as u32))
+                    .map(|record| record.event.clone())
+                    .collect();
+                let (signer, nonce) = match &xt.signature {
+                    Some((signer, _, extra)) => (Some(*signer), Some(extrinsic_nonce(extra))),
+                    None => (None, None),
+                };
+                DecodedExtrinsic {
+                    tx_hash: Hashing::hash_of(&xt),
+                    signer,
+                    nonce,
+                    call: xt.function,
+                    events,
+                }
+            })
+            .collect())
+    }
+
     fn genesis_hash(&self) -> Hash {
         self.backend.get_genesis_hash()
     }
 
+    async fn genesis_header(&self) -> Result<BlockHeader, Error> {
+        let block_hash = self
+            .backend
+            .block_hash(0)
+            .await?
+            .ok_or(Error::BlockNumberMissing { block_number: 0 })?;
+        self.backend
+            .block_header(Some(block_hash))
+            .await?
+            .ok_or(Error::BlockMissing { block_hash })
+    }
+
     async fn account_exists(&self, account_id: &AccountId) -> Result<bool, Error> {
         self.store_contains_key::<store::Account, _, _>(*account_id)
             .await
@@ -252,6 +970,16 @@ impl ClientT for Client {
         Ok(account_info.nonce)
     }
 
+    async fn next_nonces(
+        &self,
+        account_id: &AccountId,
+        count: u32,
+    ) -> Result<Vec<state::AccountTransactionIndex>, Error> {
+        // `next_nonce` fails if the range overflows.
+        let (nonce, _) = self.next_nonce(account_id, count).await?;
+        Ok((nonce..nonce + count).collect())
+    }
+
     async fn free_balance(&self, account_id: &AccountId) -> Result<state::AccountBalance, Error> {
         let account_info = self
             .fetch_map_value::<store::Account, _, _>(*account_id)
@@ -259,6 +987,45 @@ impl ClientT for Client {
         Ok(account_info.data.free)
     }
 
+    async fn free_balances(
+        &self,
+        account_ids: &[AccountId],
+    ) -> Result<Vec<(AccountId, Balance)>, Error> {
+        let account_infos = self
+            .fetch_map_values::<store::Account, _, _>(account_ids.to_vec(), self.at)
+            .await?;
+        Ok(account_ids
+            .iter()
+            .copied()
+            .zip(account_infos.into_iter().map(|info| info.data.free))
+            .collect())
+    }
+
+    async fn get_account(&self, account_id: &AccountId) -> Result<Account, Error> {
+        let account_info = self
+            .fetch_map_value::<store::Account, _, _>(*account_id)
+            .await?;
+        Ok(Account {
+            nonce: account_info.nonce,
+            free_balance: account_info.data.free,
+        })
+    }
+
+    async fn reserved_balance(&self, account_id: &AccountId) -> Result<Balance, Error> {
+        let account_info = self
+            .fetch_map_value::<store::Account, _, _>(*account_id)
+            .await?;
+        Ok(account_info.data.reserved)
+    }
+
+    async fn total_balance(&self, account_id: &AccountId) -> Result<Balance, Error> {
+        let account_info = self
+            .fetch_map_value::<store::Account, _, _>(*account_id)
+            .await?;
+        Ok(account_info.data.free + account_info.data.reserved)
+    }
+
+    #[allow(deprecated)]
     async fn get_id_status(&self, id: &Id) -> Result<IdStatus, Error> {
         if self.get_org(id.clone()).await?.is_some() || self.get_user(id.clone()).await?.is_some() {
             Ok(IdStatus::Taken)
@@ -272,22 +1039,45 @@ impl ClientT for Client {
         }
     }
 
+    async fn is_id_available(&self, id: Id) -> Result<bool, Error> {
+        Ok(self.get_id_status(&id).await? == IdStatus::Available)
+    }
+
     async fn get_org(&self, id: Id) -> Result<Option<state::Orgs1Data>, Error> {
         self.fetch_map_value::<store::Orgs1, _, _>(id.clone()).await
     }
 
     async fn list_orgs(&self) -> Result<Vec<Id>, Error> {
         let orgs_prefix = store::Orgs1::final_prefix();
-        let keys = self.backend.fetch_keys(&orgs_prefix, None).await?;
+        let keys = self.backend.fetch_keys(&orgs_prefix, self.at).await?;
         let mut org_ids: Vec<Id> = Vec::with_capacity(keys.len());
         for key in keys {
             let org_id = store::Orgs1::decode_key(&key)
                 .expect("Invalid runtime state key. Cannot extract org ID");
             org_ids.push(org_id)
         }
+        org_ids.sort();
         Ok(org_ids)
     }
 
+    async fn list_org_members(
+        &self,
+        org_id: Id,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<Id>, Error> {
+        let members_prefix = store::OrgMembers1::storage_double_map_final_key1(org_id);
+        let keys = self.backend.fetch_keys(&members_prefix, self.at).await?;
+        let mut user_ids: Vec<Id> = Vec::with_capacity(keys.len());
+        for key in keys {
+            let (_org_id, user_id) = store::OrgMembers1::decode_key(&key)
+                .expect("Invalid runtime state key. Cannot extract org member ID");
+            user_ids.push(user_id);
+        }
+        user_ids.sort();
+        Ok(user_ids.into_iter().skip(offset).take(limit).collect())
+    }
+
     async fn get_user(&self, id: Id) -> Result<Option<state::Users1Data>, Error> {
         self.fetch_map_value::<store::Users1, _, _>(id.clone())
             .await
@@ -295,17 +1085,143 @@ impl ClientT for Client {
 
     async fn list_users(&self) -> Result<Vec<Id>, Error> {
         let users_prefix = store::Users1::final_prefix();
-        let keys = self.backend.fetch_keys(&users_prefix, None).await?;
+        let keys = self.backend.fetch_keys(&users_prefix, self.at).await?;
         let mut user_ids: Vec<Id> = Vec::with_capacity(keys.len());
         for key in keys {
             let user_id = store::Users1::decode_key(&key)
                 .expect("Invalid runtime state key. Cannot extract user ID");
             user_ids.push(user_id);
         }
-
+        user_ids.sort();
         Ok(user_ids)
     }
 
+    async fn get_users(&self, user_ids: Vec<Id>) -> Result<Vec<Option<state::Users1Data>>, Error> {
+        self.fetch_map_values::<store::Users1, _, _>(user_ids, self.at)
+            .await
+    }
+
+    async fn user_of_account(&self, account_id: AccountId) -> Result<Option<Id>, Error> {
+        self.fetch_map_value::<store::AccountUsers1, _, _>(account_id)
+            .await
+    }
+
+    async fn get_transaction(&self, tx_hash: TxHash) -> Result<Option<TransactionRecord>, Error> {
+        let mut header = self.block_header_best_chain().await?;
+        for _ in 0..TRANSACTION_SEARCH_DEPTH {
+            let block_hash = header.hash();
+            let block = self
+                .backend
+                .block(block_hash)
+                .await?
+                .ok_or(Error::BlockMissing { block_hash })?;
+            // Only decode the extrinsics and fetch the events of the block that includes the
+            // transaction.
+            if block
+                .extrinsics
+                .iter()
+                .any(|xt| Hashing::hash_of(xt) == tx_hash)
+            {
+                let extrinsic = self
+                    .block_extrinsics(block_hash)
+                    .await?
+                    .into_iter()
+                    .find(|extrinsic| extrinsic.tx_hash == tx_hash)
+                    .expect("Block contains the transaction");
+                let result = event::get_dispatch_result(&extrinsic.events)
+                    .map_err(|error| Error::EventExtraction { error, tx_hash })?;
+                return Ok(Some(TransactionRecord {
+                    block: block_hash,
+                    block_number: header.number,
+                    extrinsic,
+                    result,
+                }));
+            }
+
+            if header.number == 0 {
+                break;
+            }
+            let parent_hash = header.parent_hash;
+            header =
+                self.backend
+                    .block_header(Some(parent_hash))
+                    .await?
+                    .ok_or(Error::BlockMissing {
+                        block_hash: parent_hash,
+                    })?;
+        }
+        Ok(None)
+    }
+
+    async fn account_profile(&self, account_id: AccountId) -> Result<AccountProfile, Error> {
+        let block_hash = match self.at {
+            Some(block_hash) => block_hash,
+            None => self.block_header_best_chain().await?.hash(),
+        };
+        let at = Some(block_hash);
+
+        let account_info = self
+            .fetch_map_value_at::<store::Account, _, _>(account_id, at)
+            .await?;
+        let user_id = self
+            .fetch_map_value_at::<store::AccountUsers1, _, _>(account_id, at)
+            .await?;
+
+        let (orgs, projects) = match &user_id {
+            Some(user_id) => {
+                let projects = self
+                    .fetch_map_value_at::<store::Users1, _, _>(user_id.clone(), at)
+                    .await?
+                    .map(|user| {
+                        user.projects()
+                            .iter()
+                            .map(|project_name| {
+                                (project_name.clone(), ProjectDomain::User(user_id.clone()))
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let org_keys = self
+                    .backend
+                    .fetch_keys(&store::Orgs1::final_prefix(), at)
+                    .await?;
+                let org_ids = org_keys
+                    .iter()
+                    .map(|key| {
+                        store::Orgs1::decode_key(key)
+                            .expect("Invalid runtime state key. Cannot extract org ID")
+                    })
+                    .collect::<Vec<Id>>();
+                let org_data = self
+                    .fetch_map_values::<store::Orgs1, _, _>(org_ids.clone(), at)
+                    .await?;
+                let mut orgs = org_ids
+                    .into_iter()
+                    .zip(org_data)
+                    .filter(|(_, org)| {
+                        org.as_ref()
+                            .map(|org| org.members().contains(user_id))
+                            .unwrap_or(false)
+                    })
+                    .map(|(org_id, _)| org_id)
+                    .collect::<Vec<Id>>();
+                orgs.sort();
+                (orgs, projects)
+            }
+            None => (Vec::new(), Vec::new()),
+        };
+
+        Ok(AccountProfile {
+            block_hash,
+            balance: account_info.data.free,
+            nonce: account_info.nonce,
+            user_id,
+            orgs,
+            projects,
+        })
+    }
+
     async fn get_project(
         &self,
         project_name: ProjectName,
@@ -318,26 +1234,428 @@ impl ClientT for Client {
 
     async fn list_projects(&self) -> Result<Vec<ProjectId>, Error> {
         let project_prefix = store::Projects1::final_prefix();
-        let keys = self.backend.fetch_keys(&project_prefix, None).await?;
+        let keys = self.backend.fetch_keys(&project_prefix, self.at).await?;
         let mut project_ids = Vec::with_capacity(keys.len());
         for key in keys {
             let project_id = store::Projects1::decode_key(&key)
                 .expect("Invalid runtime state key. Cannot extract project ID");
             project_ids.push(project_id);
         }
+        project_ids.sort();
         Ok(project_ids)
     }
 
+    async fn get_projects(
+        &self,
+        project_ids: Vec<ProjectId>,
+    ) -> Result<Vec<Option<state::Projects1Data>>, Error> {
+        self.fetch_map_values::<store::Projects1, _, _>(project_ids, self.at)
+            .await
+    }
+
+    async fn get_pending_project(
+        &self,
+        project_name: ProjectName,
+        project_domain: ProjectDomain,
+    ) -> Result<Option<state::PendingProjects1Data>, Error> {
+        self.fetch_map_value::<store::PendingProjects1, _, _>((project_name, project_domain))
+            .await
+    }
+
+    async fn list_pending_projects(&self) -> Result<Vec<ProjectId>, Error> {
+        let prefix = store::PendingProjects1::final_prefix();
+        let keys = self.backend.fetch_keys(&prefix, self.at).await?;
+        let mut project_ids = Vec::with_capacity(keys.len());
+        for key in keys {
+            let project_id = store::PendingProjects1::decode_key(&key)
+                .expect("Invalid runtime state key. Cannot extract project ID");
+            project_ids.push(project_id);
+        }
+        project_ids.sort();
+        Ok(project_ids)
+    }
+
+    async fn count_entries(&self, prefix: &[u8]) -> Result<u64, Error> {
+        self.backend.count_keys(prefix, self.at).await
+    }
+
+    async fn org_count(&self) -> Result<u64, Error> {
+        self.count_entries(&store::Orgs1::final_prefix()).await
+    }
+
+    async fn user_count(&self) -> Result<u64, Error> {
+        self.count_entries(&store::Users1::final_prefix()).await
+    }
+
+    async fn project_count(&self) -> Result<u64, Error> {
+        self.count_entries(&store::Projects1::final_prefix()).await
+    }
+
+    async fn estimate_inclusion_blocks(&self, fee: Balance, length: usize) -> Result<u32, Error> {
+        let pending_extrinsics = self.backend.pending_extrinsics().await?;
+        let mut ahead: Vec<(Balance, usize)> = pending_extrinsics
+            .iter()
+            .filter_map(|xt| {
+                let (_, _, extra) = xt.signature.as_ref()?;
+                Some((extra.5.fee, xt.encode().len()))
+            })
+            .filter(|(xt_fee, _)| *xt_fee >= fee)
+            .collect();
+        ahead.sort_by(|(fee_a, _), (fee_b, _)| fee_b.cmp(fee_a));
+
+        let block_length = AvailableBlockRatio::get() * MaximumBlockLength::get();
+        let lengths = ahead
+            .into_iter()
+            .map(|(_, length)| length)
+            .chain(std::iter::once(length));
+        Ok(count_full_blocks(lengths, block_length as usize))
+    }
+
+    async fn reward_estimate(&self, window: u32) -> Result<RewardEstimate, Error> {
+        let to = self.best_block_number().await?;
+        let from = to.saturating_sub(window).saturating_add(1).max(1);
+        if window > MAX_BLOCK_HEADERS_RANGE {
+            return Err(Error::RangeTooLarge {
+                from,
+                to,
+                limit: MAX_BLOCK_HEADERS_RANGE,
+            });
+        }
+        let burn_share = if self.fetch_value::<store::BurnFees, _>().await? {
+            BURN_SHARE
+        } else {
+            Permill::zero()
+        };
+
+        let block_numbers = (from..=to).collect::<Vec<_>>();
+        let block_hashes = self.backend.block_hashes(block_numbers.clone()).await?;
+        let reward_futures = block_numbers.into_iter().zip(block_hashes).map(
+            |(block_number, maybe_block_hash)| async move {
+                let block_hash =
+                    maybe_block_hash.ok_or(Error::BlockNumberMissing { block_number })?;
+                let block = self
+                    .backend
+                    .block(block_hash)
+                    .await?
+                    .ok_or(Error::BlockMissing { block_hash })?;
+                block_reward(&block, burn_share)
+                    .map(|(_, reward)| reward)
+                    .ok_or(Error::BlockAuthorMissing { block_hash })
+            },
+        );
+        let rewards = futures::future::try_join_all(reward_futures).await?;
+
+        let blocks = rewards.len() as u32;
+        if blocks == 0 {
+            return Ok(RewardEstimate {
+                blocks,
+                reward_per_block: BLOCK_REWARD,
+                fees_per_block: 0,
+            });
+        }
+        let reward_per_block = rewards.iter().sum::<Balance>() / Balance::from(blocks);
+        Ok(RewardEstimate {
+            blocks,
+            reward_per_block,
+            fees_per_block: reward_per_block - BLOCK_REWARD,
+        })
+    }
+
+    async fn subscribe_block_rewards(
+        &self,
+    ) -> Result<BoxStream<'static, Result<(BlockNumber, AccountId, Balance), Error>>, Error> {
+        let header_stream = self.backend.subscribe_new_heads().await?;
+        let burn_share = if self.fetch_value::<store::BurnFees, _>().await? {
+            BURN_SHARE
+        } else {
+            Permill::zero()
+        };
+        let backend = self.backend.clone();
+        Ok(header_stream
+            .and_then(move |header| {
+                let backend = backend.clone();
+                async move {
+                    let block_hash = header.hash();
+                    let block = backend
+                        .block(block_hash)
+                        .await?
+                        .ok_or(Error::BlockMissing { block_hash })?;
+                    let (author, reward) = block_reward(&block, burn_share)
+                        .ok_or(Error::BlockAuthorMissing { block_hash })?;
+                    Ok((header.number, author, reward))
+                }
+            })
+            .boxed())
+    }
+
+    async fn subscribe_events(
+        &self,
+    ) -> Result<BoxStream<'static, Result<(BlockHash, Vec<Event>), Error>>, Error> {
+        let header_stream = self.backend.subscribe_new_heads().await?;
+        let backend = self.backend.clone();
+        Ok(header_stream
+            .and_then(move |header| {
+                let backend = backend.clone();
+                async move {
+                    let block_hash = header.hash();
+                    let events = backend.block_events(block_hash).await?;
+                    Ok((block_hash, events))
+                }
+            })
+            .boxed())
+    }
+
+    async fn subscribe_balance_changes(
+        &self,
+        account_id: AccountId,
+    ) -> Result<BoxStream<'static, Result<BalanceChange, Error>>, Error> {
+        let header_stream = self.backend.subscribe_new_heads().await?;
+        // The balances are read from the state of the announced blocks regardless of the block
+        // selected with `Client::at`.
+        let mut previous = self
+            .fetch_map_value_at::<store::Account, _, _>(account_id, None)
+            .await?
+            .data
+            .free;
+        let client = self.clone();
+        let backend = self.backend.clone();
+        Ok(header_stream
+            .and_then(move |header| {
+                let client = client.clone();
+                async move {
+                    let account_info = client
+                        .fetch_map_value_at::<store::Account, _, _>(account_id, Some(header.hash()))
+                        .await?;
+                    Ok((header, account_info.data.free))
+                }
+            })
+            .try_filter_map(move |(header, current)| {
+                let change = if current != previous {
+                    Some((header, previous, current))
+                } else {
+                    None
+                };
+                previous = current;
+                futures::future::ready(Ok(change))
+            })
+            .and_then(move |(header, previous, current)| {
+                let backend = backend.clone();
+                async move {
+                    let block_hash = header.hash();
+                    let events = backend.block_events(block_hash).await?;
+                    Ok(BalanceChange {
+                        block_number: header.number,
+                        block_hash,
+                        previous,
+                        current,
+                        transfers: account_transfers(&events, account_id),
+                    })
+                }
+            })
+            .boxed())
+    }
+
+    async fn subscribe_runtime_upgrades(
+        &self,
+    ) -> Result<BoxStream<'static, Result<RuntimeVersion, Error>>, Error> {
+        let header_stream = self.backend.subscribe_new_heads().await?;
+        let mut spec_version = self.backend.runtime_version(None).await?.spec_version;
+        let backend = self.backend.clone();
+        Ok(header_stream
+            .and_then(move |header| {
+                let backend = backend.clone();
+                async move { backend.runtime_version(Some(header.hash())).await }
+            })
+            .try_filter_map(move |runtime_version| {
+                let upgraded = runtime_version.spec_version != spec_version;
+                spec_version = runtime_version.spec_version;
+                futures::future::ready(Ok(if upgraded {
+                    Some(runtime_version)
+                } else {
+                    None
+                }))
+            })
+            .boxed())
+    }
+
+    async fn replay_from_genesis(
+        &self,
+    ) -> Result<BoxStream<'static, Result<(BlockNumber, Vec<Event>), Error>>, Error> {
+        self.replay_from(0).await
+    }
+
+    async fn replay_from(
+        &self,
+        start: BlockNumber,
+    ) -> Result<BoxStream<'static, Result<(BlockNumber, Vec<Event>), Error>>, Error> {
+        let best_block_number = self.best_block_number().await?;
+        let backend = self.backend.clone();
+        Ok(futures::stream::iter(start..=best_block_number)
+            .then(move |block_number| {
+                let backend = backend.clone();
+                async move {
+                    let block_hash = backend
+                        .block_hash(block_number)
+                        .await?
+                        .ok_or(Error::BlockNumberMissing { block_number })?;
+                    let events = backend.block_events(block_hash).await?;
+                    Ok((block_number, events))
+                }
+            })
+            .boxed())
+    }
+
     async fn runtime_version(&self) -> Result<RuntimeVersion, Error> {
-        self.backend.runtime_version().await
+        self.backend.runtime_version(None).await
+    }
+
+    async fn chain_name(&self) -> Result<String, Error> {
+        self.backend.chain_name().await
+    }
+
+    async fn pow_algorithm(&self) -> Result<PowKind, Error> {
+        self.backend.pow_algorithm().await
+    }
+
+    async fn chain_total_work(&self, block_hash: BlockHash) -> Result<U256, Error> {
+        self.backend
+            .total_difficulty(block_hash)
+            .await?
+            .ok_or(Error::BlockMissing { block_hash })
+    }
+}
+
+/// Build the [TransactionIncluded] for a message from the events its transaction emitted.
+fn transaction_included<Message_: Message>(
+    tx_included: backend::TransactionIncluded,
+) -> Result<TransactionIncluded, Error> {
+    let tx_hash = tx_included.tx_hash;
+    let result = Message_::result_from_events(tx_included.events)
+        .map_err(|error| Error::EventExtraction { error, tx_hash })?;
+    Ok(TransactionIncluded {
+        tx_hash,
+        block: tx_included.block,
+        result,
+    })
+}
+
+/// Wait until the block `block_hash` that includes the transaction `tx_hash` is `confirmations`
+/// blocks deep in the best chain.
+///
+/// Fails with [Error::TransactionRetracted] if the block is not part of the best chain at that
+/// depth.
+async fn wait_for_confirmations(
+    backend: Arc<dyn backend::Backend + Sync + Send>,
+    tx_hash: TxHash,
+    block_hash: BlockHash,
+    confirmations: u32,
+) -> Result<(), Error> {
+    let mut header_stream = backend.subscribe_new_heads().await?;
+    let block_number = backend
+        .block_header(Some(block_hash))
+        .await?
+        .ok_or(Error::BlockMissing { block_hash })?
+        .number;
+    let confirmed_number = block_number + confirmations;
+
+    let mut best_number = backend
+        .block_header(None)
+        .await?
+        .ok_or(Error::BestChainTipHeaderMissing)?
+        .number;
+    while best_number < confirmed_number {
+        best_number = header_stream
+            .try_next()
+            .await?
+            .ok_or(Error::NewHeadsStreamTerminated)?
+            .number;
+    }
+
+    if backend.block_hash(block_number).await? == Some(block_hash) {
+        Ok(())
+    } else {
+        Err(Error::TransactionRetracted {
+            tx_hash,
+            block_hash,
+        })
     }
 }
 
+/// Get the account nonce from the [SignedExtra] of an extrinsic.
+fn extrinsic_nonce(extra: &SignedExtra) -> state::AccountTransactionIndex {
+    // The nonce of `CheckNonce` is private but it is encoded as a compact integer.
+    let (_, _, _, check_nonce, _, _, _) = extra;
+    let Compact(nonce) =
+        Compact::<state::AccountTransactionIndex>::decode(&mut check_nonce.encode().as_slice())
+            .expect("CheckNonce is encoded as a compact integer");
+    nonce
+}
+
 /// Parse an [AccountId] from str expected to be in the ss58 format, failing otherwise.
 pub fn parse_ss58_address(address: &str) -> Result<AccountId, sp_core::crypto::PublicError> {
     sp_core::crypto::Ss58Codec::from_ss58check(address)
 }
 
+/// Get the author of `block` and the total reward credited to them for the block.
+///
+/// `burn_share` is the share of every transaction fee that is burned instead of credited to the
+/// author. Returns `None` if the block does not contain the inherent that sets the block author.
+fn block_reward(block: &backend::Block, burn_share: Permill) -> Option<(AccountId, Balance)> {
+    let mut author = None;
+    let mut reward = BLOCK_REWARD;
+    for xt in &block.extrinsics {
+        match &xt.signature {
+            Some((_, _, extra)) => {
+                let fee = extra.5.fee;
+                reward += fee - burn_share * fee;
+            }
+            None => {
+                if let RuntimeCall::Registry(call::Registry::set_block_author(block_author)) =
+                    &xt.function
+                {
+                    author = Some(*block_author);
+                }
+            }
+        }
+    }
+    author.map(|author| (author, reward))
+}
+
+/// Get the transfers from [event::Balances::Transfer] events that `account_id` sent or received.
+fn account_transfers(
+    events: &[Event],
+    account_id: AccountId,
+) -> Vec<(AccountId, AccountId, Balance)> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            Event::pallet_balances(event::Balances::Transfer(sender, recipient, amount))
+                if *sender == account_id || *recipient == account_id =>
+            {
+                Some((*sender, *recipient, *amount))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Count the blocks that are filled up when packing transactions with the given encoded lengths
+/// in order into blocks with a maximum length of `block_length`.
+///
+/// This is the index of the block that the last transaction is packed into.
+fn count_full_blocks(lengths: impl IntoIterator<Item = usize>, block_length: usize) -> u32 {
+    let mut full_blocks = 0;
+    let mut used_length = 0;
+    for length in lengths {
+        if used_length > 0 && used_length + length > block_length {
+            full_blocks += 1;
+            used_length = 0;
+        }
+        used_length += length;
+    }
+    full_blocks
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -350,4 +1668,30 @@ mod test {
         fn is_sync_send(_x: impl Sync + Send + 'static) {}
         is_sync_send(Client::new_emulator().0);
     }
+
+    #[test]
+    fn count_full_blocks_packing() {
+        assert_eq!(count_full_blocks(vec![], 10), 0);
+        assert_eq!(count_full_blocks(vec![4, 6], 10), 0);
+        assert_eq!(count_full_blocks(vec![4, 6, 1], 10), 1);
+        assert_eq!(count_full_blocks(vec![4, 7, 4, 7], 10), 3);
+    }
+
+    /// Test that malformed state data returned by the backend results in an error instead of a
+    /// panic.
+    #[async_std::test]
+    async fn fetch_malformed_state() {
+        let mut storage =
+            sp_runtime::BuildStorage::build_storage(&emulator_genesis_config()).unwrap();
+        storage.top.insert(
+            store::SudoKey::storage_value_final_key().to_vec(),
+            b"<html>".to_vec(),
+        );
+        let client = Client::new(backend::Emulator::from_storage(storage));
+
+        match client.sudo_key().await {
+            Err(Error::StateDecoding { .. }) => {}
+            result => panic!("Unexpected result {:?}", result),
+        }
+    }
 }
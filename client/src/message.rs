@@ -17,7 +17,7 @@
 
 pub use radicle_registry_core::message::*;
 use radicle_registry_core::*;
-use radicle_registry_runtime::{call, Call as RuntimeCall};
+use radicle_registry_runtime::{call, error, Call as RuntimeCall};
 
 use crate::{event, event::Event};
 
@@ -25,133 +25,247 @@ use crate::{event, event::Event};
 ///
 /// For every [RuntimeCall] that is exposed to the user we implement [Message] for the parameters
 /// struct of the runtime message.
-pub trait Message: Send + 'static {
+pub trait Message: IntoRuntimeCallBoxed + Send + 'static {
     /// Parse all runtime events emitted by the message and return the appropriate message result.
     ///
     /// Returns an error if the event list is not well formed. For example if an expected event is
     /// missing.
     fn result_from_events(
         events: Vec<Event>,
-    ) -> Result<Result<(), TransactionError>, event::EventExtractionError>;
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError>
+    where
+        Self: Sized;
 
-    fn into_runtime_call(self) -> RuntimeCall;
+    fn into_runtime_call(self) -> RuntimeCall
+    where
+        Self: Sized;
 }
 
-impl Message for message::RegisterProject {
-    fn result_from_events(
-        events: Vec<Event>,
-    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
-        event::get_dispatch_result(&events)
-    }
+/// Converts a boxed [Message] into its [RuntimeCall]. Implemented for every [Message].
+///
+/// This allows messages of different types to be collected as `Box<dyn Message>`, for example
+/// for [Batch].
+pub trait IntoRuntimeCallBoxed {
+    fn into_runtime_call_boxed(self: Box<Self>) -> RuntimeCall;
+}
 
-    fn into_runtime_call(self) -> RuntimeCall {
-        call::Registry::register_project(self).into()
+impl<Message_: Message> IntoRuntimeCallBoxed for Message_ {
+    fn into_runtime_call_boxed(self: Box<Self>) -> RuntimeCall {
+        (*self).into_runtime_call()
     }
 }
 
-impl Message for message::RegisterMember {
-    fn result_from_events(
-        events: Vec<Event>,
-    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
-        event::get_dispatch_result(&events)
-    }
+/// Implement [Message] for messages that dispatch a registry call and whose result is the
+/// dispatch result of the transaction.
+///
+/// ```ignore
+/// impl_registry_message! {
+///     message::RegisterOrg => register_org,
+/// }
+/// ```
+macro_rules! impl_registry_message {
+    ($($message:ty => $call:ident),* $(,)?) => {
+        $(
+            impl Message for $message {
+                fn result_from_events(
+                    events: Vec<Event>,
+                ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+                    event::get_dispatch_result(&events)
+                }
 
-    fn into_runtime_call(self) -> RuntimeCall {
-        call::Registry::register_member(self).into()
-    }
+                fn into_runtime_call(self) -> RuntimeCall {
+                    call::Registry::$call(self).into()
+                }
+            }
+        )*
+    };
 }
 
-impl Message for message::RegisterOrg {
+impl_registry_message! {
+    message::RegisterProject => register_project,
+    message::AcceptProject => accept_project,
+    message::RejectProject => reject_project,
+    message::UnregisterProject => unregister_project,
+    message::RegisterMember => register_member,
+    message::RegisterOrg => register_org,
+    message::UnregisterOrg => unregister_org,
+    message::RegisterUser => register_user,
+    message::UnregisterUser => unregister_user,
+    message::ReserveId => reserve_id,
+    message::CancelReservation => cancel_reservation,
+    message::LeaveOrg => leave_org,
+    message::MultiTransfer => multi_transfer,
+    message::Faucet => faucet,
+}
+
+impl Message for message::Transfer {
     fn result_from_events(
         events: Vec<Event>,
     ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
-        event::get_dispatch_result(&events)
+        transfer_result_from_events(events)
     }
 
     fn into_runtime_call(self) -> RuntimeCall {
-        call::Registry::register_org(self).into()
+        call::Registry::transfer(self).into()
     }
 }
 
-impl Message for message::UnregisterOrg {
+impl Message for message::TransferFromOrg {
     fn result_from_events(
         events: Vec<Event>,
     ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
-        event::get_dispatch_result(&events)
+        transfer_result_from_events(events)
     }
 
     fn into_runtime_call(self) -> RuntimeCall {
-        call::Registry::unregister_org(self).into()
+        call::Registry::transfer_from_org(self).into()
     }
 }
 
-impl Message for message::RegisterUser {
-    fn into_runtime_call(self) -> RuntimeCall {
-        call::Registry::register_user(self).into()
+/// A successful transfer emits the `Transferred` registry event. Runtimes before spec version 38
+/// do not emit the event. In that case the result is taken from the dispatch result.
+///
+/// A transfer that fails because it would leave the sender below the existential deposit results
+/// in [TransactionError::WouldReapAccount].
+fn transfer_result_from_events(
+    events: Vec<Event>,
+) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+    let transferred = events.iter().any(|event| {
+        matches!(
+            event,
+            event::Event::registry(event::Registry::Transferred(..))
+        )
+    });
+    if transferred {
+        return Ok(Ok(()));
+    }
+    match event::get_dispatch_result(&events)? {
+        Err(TransactionError::OtherDispatchError(dispatch_error))
+            if is_keep_alive_error(&dispatch_error) =>
+        {
+            Ok(Err(TransactionError::WouldReapAccount))
+        }
+        result => Ok(result),
     }
+}
 
-    fn result_from_events(
-        events: Vec<Event>,
-    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
-        event::get_dispatch_result(&events)
+/// Returns `true` if `dispatch_error` is the `KeepAlive` error of the balances module.
+///
+/// The error message is ignored because it is not transmitted with the error.
+fn is_keep_alive_error(dispatch_error: &DispatchError) -> bool {
+    match (
+        dispatch_error,
+        DispatchError::from(error::Balances::KeepAlive),
+    ) {
+        (
+            DispatchError::Module { index, error, .. },
+            DispatchError::Module {
+                index: keep_alive_index,
+                error: keep_alive_error,
+                ..
+            },
+        ) => *index == keep_alive_index && *error == keep_alive_error,
+        _ => false,
     }
 }
 
-impl Message for message::UnregisterUser {
+impl Message for message::UpdateRuntime {
+    /// The only unequivocal sign we get that a wasm update was successful is the
+    /// `RawEvent::CodeUpdated` event. Anything else is considered a failed update.
     fn result_from_events(
         events: Vec<Event>,
     ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
-        event::get_dispatch_result(&events)
+        let result = events
+            .into_iter()
+            .find_map(|event| match event {
+                event::Event::system(event::System::CodeUpdated) => Some(()),
+                _ => None,
+            })
+            .ok_or_else(|| TransactionError::from(RegistryError::FailedChainRuntimeUpdate));
+        Ok(result)
     }
 
     fn into_runtime_call(self) -> RuntimeCall {
-        call::Registry::unregister_user(self).into()
+        let set_code_call: RuntimeCall = call::System::set_code(self.code).into();
+        call::Sudo::sudo(Box::new(set_code_call)).into()
     }
 }
 
-impl Message for message::Transfer {
+/// Wraps a message so that it is dispatched by the sudo module with the root origin.
+///
+/// The transaction must be signed by the chain's sudo key. See [crate::ClientT::sudo_key].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sudo<Message_>(pub Message_);
+
+impl<Message_: Message> Message for Sudo<Message_> {
+    /// The transaction result is the result of the sudo call if dispatching the sudo call itself
+    /// succeeded. The result of the wrapped call is taken from the `Sudid` event.
     fn result_from_events(
         events: Vec<Event>,
     ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
-        event::get_dispatch_result(&events)
+        if let Err(error) = event::get_dispatch_result(&events)? {
+            return Ok(Err(error));
+        }
+        events
+            .into_iter()
+            .find_map(|event| match event {
+                event::Event::pallet_sudo(event::Sudo::Sudid(result)) => {
+                    Some(result.map_err(TransactionError::from))
+                }
+                _ => None,
+            })
+            .ok_or(event::EventExtractionError::EventMissing)
     }
 
     fn into_runtime_call(self) -> RuntimeCall {
-        call::Registry::transfer(self).into()
+        call::Sudo::sudo(Box::new(self.0.into_runtime_call())).into()
     }
 }
 
-impl Message for message::TransferFromOrg {
-    fn result_from_events(
-        events: Vec<Event>,
-    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
-        event::get_dispatch_result(&events)
-    }
+/// Dispatches the calls of multiple messages atomically in one transaction. See
+/// [crate::ClientT::submit_batch].
+///
+/// If one of the messages fails, the changes of all messages are reverted. The author of the
+/// transaction pays the transaction fee once for all messages.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Batch(pub Vec<RuntimeCall>);
 
-    fn into_runtime_call(self) -> RuntimeCall {
-        call::Registry::transfer_from_org(self).into()
+impl Batch {
+    pub fn new(messages: Vec<Box<dyn Message>>) -> Self {
+        Batch(
+            messages
+                .into_iter()
+                .map(|message| message.into_runtime_call_boxed())
+                .collect(),
+        )
     }
 }
 
-impl Message for message::UpdateRuntime {
-    /// The only unequivocal sign we get that a wasm update was successful is the
-    /// `RawEvent::CodeUpdated` event. Anything else is considered a failed update.
+impl Message for Batch {
+    /// If a call of the batch failed the runtime emits the `BatchInterrupted` event and the result
+    /// is [TransactionError::BatchInterrupted] with the index of the failed call.
     fn result_from_events(
         events: Vec<Event>,
     ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
-        let result = events
-            .into_iter()
-            .find_map(|event| match event {
-                event::Event::system(event::System::CodeUpdated) => Some(()),
-                _ => None,
-            })
-            .ok_or_else(|| TransactionError::from(RegistryError::FailedChainRuntimeUpdate));
-        Ok(result)
+        let dispatch_result = event::get_dispatch_result(&events)?;
+        let batch_error = events.into_iter().find_map(|event| match event {
+            event::Event::registry(event::Registry::BatchInterrupted(
+                failed_index,
+                dispatch_error,
+            )) => Some(BatchError {
+                failed_index,
+                dispatch_error,
+            }),
+            _ => None,
+        });
+        match (dispatch_result, batch_error) {
+            (Err(_), Some(batch_error)) => Ok(Err(batch_error.into())),
+            (dispatch_result, _) => Ok(dispatch_result),
+        }
     }
 
     fn into_runtime_call(self) -> RuntimeCall {
-        let set_code_call: RuntimeCall = call::System::set_code(self.code).into();
-        call::Sudo::sudo(Box::new(set_code_call)).into()
+        call::Registry::batch(self.0).into()
     }
 }
 
@@ -162,6 +276,31 @@ mod test {
     use radicle_registry_runtime::event;
     use radicle_registry_runtime::Event;
 
+    #[test]
+    fn registry_message_runtime_call() {
+        let message = message::RegisterOrg {
+            org_id: "monadic".parse().unwrap(),
+            reservation_salt: None,
+        };
+        assert_eq!(
+            message.clone().into_runtime_call(),
+            RuntimeCall::Registry(call::Registry::register_org(message))
+        );
+    }
+
+    #[test]
+    fn registry_message_dispatch_result() {
+        let events = vec![Event::system(event::System::ExtrinsicFailed(
+            RegistryError::IdAlreadyTaken.into(),
+            Default::default(),
+        ))];
+        let result = message::RegisterOrg::result_from_events(events).unwrap();
+        assert_eq!(
+            result,
+            Err(TransactionError::from(RegistryError::IdAlreadyTaken))
+        );
+    }
+
     #[test]
     fn update_runtime_event_ok() {
         let events = vec![
@@ -198,4 +337,152 @@ mod test {
             ))
         )
     }
+
+    #[test]
+    fn transfer_event_ok() {
+        let events = vec![
+            Event::registry(event::Registry::Transferred(
+                Default::default(),
+                Default::default(),
+                1,
+            )),
+            Event::system(event::System::ExtrinsicSuccess(Default::default())),
+        ];
+        let result = message::Transfer::result_from_events(events).unwrap();
+        assert_eq!(result, Ok(()))
+    }
+
+    /// Test that the dispatch result is used for runtimes that do not emit the `Transferred`
+    /// event.
+    #[test]
+    fn transfer_event_missing() {
+        let events = vec![Event::system(event::System::ExtrinsicSuccess(
+            Default::default(),
+        ))];
+        let result = message::TransferFromOrg::result_from_events(events).unwrap();
+        assert_eq!(result, Ok(()));
+
+        let events = vec![Event::system(event::System::ExtrinsicFailed(
+            sp_runtime::DispatchError::BadOrigin,
+            Default::default(),
+        ))];
+        let result = message::Transfer::result_from_events(events).unwrap();
+        assert_eq!(
+            result,
+            Err(TransactionError::from(sp_runtime::DispatchError::BadOrigin))
+        );
+    }
+
+    #[test]
+    fn transfer_would_reap_account() {
+        let events = vec![Event::system(event::System::ExtrinsicFailed(
+            DispatchError::from(error::Balances::KeepAlive),
+            Default::default(),
+        ))];
+        let result = message::Transfer::result_from_events(events).unwrap();
+        assert_eq!(result, Err(TransactionError::WouldReapAccount));
+
+        let events = vec![Event::system(event::System::ExtrinsicFailed(
+            DispatchError::from(error::Balances::InsufficientBalance),
+            Default::default(),
+        ))];
+        let result = message::TransferFromOrg::result_from_events(events).unwrap();
+        assert_eq!(
+            result,
+            Err(TransactionError::from(DispatchError::from(
+                error::Balances::InsufficientBalance
+            )))
+        );
+    }
+
+    #[test]
+    fn sudo_runtime_call() {
+        let call = Sudo(message::Transfer {
+            recipient: Default::default(),
+            amount: 1,
+        })
+        .into_runtime_call();
+        assert!(matches!(call, RuntimeCall::Sudo(_)));
+    }
+
+    #[test]
+    fn batch_runtime_call() {
+        let register_org = message::RegisterOrg {
+            org_id: "monadic".parse().unwrap(),
+            reservation_salt: None,
+        };
+        let transfer = message::Transfer {
+            recipient: Default::default(),
+            amount: 1,
+        };
+        let call = Batch::new(vec![
+            Box::new(register_org.clone()),
+            Box::new(transfer.clone()),
+        ])
+        .into_runtime_call();
+        assert_eq!(
+            call,
+            RuntimeCall::Registry(call::Registry::batch(vec![
+                register_org.into_runtime_call(),
+                transfer.into_runtime_call(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn batch_interrupted() {
+        let dispatch_error = DispatchError::from(RegistryError::IdAlreadyTaken);
+        let events = vec![
+            Event::registry(event::Registry::BatchInterrupted(1, dispatch_error)),
+            Event::system(event::System::ExtrinsicFailed(
+                dispatch_error,
+                Default::default(),
+            )),
+        ];
+        let result = Batch::result_from_events(events).unwrap();
+        assert_eq!(
+            result,
+            Err(TransactionError::BatchInterrupted(BatchError {
+                failed_index: 1,
+                dispatch_error,
+            }))
+        );
+    }
+
+    #[test]
+    fn sudo_event_ok() {
+        let events = vec![
+            Event::pallet_sudo(event::Sudo::Sudid(Ok(()))),
+            Event::system(event::System::ExtrinsicSuccess(Default::default())),
+        ];
+        let result = Sudo::<message::Transfer>::result_from_events(events).unwrap();
+        assert_eq!(result, Ok(()))
+    }
+
+    #[test]
+    fn sudo_event_dispatch_error() {
+        let events = vec![
+            Event::pallet_sudo(event::Sudo::Sudid(Err(
+                sp_runtime::DispatchError::BadOrigin,
+            ))),
+            Event::system(event::System::ExtrinsicSuccess(Default::default())),
+        ];
+        let result = Sudo::<message::Transfer>::result_from_events(events).unwrap();
+        assert_eq!(
+            result,
+            Err(TransactionError::from(sp_runtime::DispatchError::BadOrigin))
+        )
+    }
+
+    #[test]
+    fn sudo_event_missing() {
+        let events = vec![Event::system(event::System::ExtrinsicSuccess(
+            Default::default(),
+        ))];
+        let result = Sudo::<message::Transfer>::result_from_events(events);
+        assert!(matches!(
+            result,
+            Err(event::EventExtractionError::EventMissing)
+        ))
+    }
 }
@@ -0,0 +1,139 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Namespaced queries for registry entities. See [crate::Client::registry].
+use radicle_registry_runtime::store;
+
+use crate::interface::*;
+use crate::Client;
+
+/// Queries for orgs, users, and projects stored in the registry.
+///
+/// Obtained with [Client::registry].
+pub struct RegistryApi<'a> {
+    client: &'a Client,
+}
+
+impl<'a> RegistryApi<'a> {
+    pub(crate) fn new(client: &'a Client) -> Self {
+        RegistryApi { client }
+    }
+
+    pub fn orgs(&self) -> Orgs<'a> {
+        Orgs {
+            client: self.client,
+        }
+    }
+
+    pub fn users(&self) -> Users<'a> {
+        Users {
+            client: self.client,
+        }
+    }
+
+    pub fn projects(&self) -> Projects<'a> {
+        Projects {
+            client: self.client,
+        }
+    }
+}
+
+/// Queries for registered orgs. See [RegistryApi::orgs].
+pub struct Orgs<'a> {
+    client: &'a Client,
+}
+
+#[allow(deprecated)]
+impl Orgs<'_> {
+    /// Same as [ClientT::get_org].
+    pub async fn get(&self, org_id: Id) -> Result<Option<state::Orgs1Data>, Error> {
+        self.client.get_org(org_id).await
+    }
+
+    /// Same as [ClientT::list_orgs].
+    pub async fn list(&self) -> Result<Vec<Id>, Error> {
+        self.client.list_orgs().await
+    }
+
+    /// Same as [ClientT::list_org_members].
+    pub async fn members(&self, org_id: Id, offset: usize, limit: usize) -> Result<Vec<Id>, Error> {
+        self.client.list_org_members(org_id, offset, limit).await
+    }
+
+    /// Returns `true` if an org with the given ID is registered.
+    pub async fn exists(&self, org_id: Id) -> Result<bool, Error> {
+        self.client
+            .store_contains_key::<store::Orgs1, _, _>(org_id)
+            .await
+    }
+}
+
+/// Queries for registered users. See [RegistryApi::users].
+pub struct Users<'a> {
+    client: &'a Client,
+}
+
+#[allow(deprecated)]
+impl Users<'_> {
+    /// Same as [ClientT::get_user].
+    pub async fn get(&self, user_id: Id) -> Result<Option<state::Users1Data>, Error> {
+        self.client.get_user(user_id).await
+    }
+
+    /// Same as [ClientT::list_users].
+    pub async fn list(&self) -> Result<Vec<Id>, Error> {
+        self.client.list_users().await
+    }
+
+    /// Returns `true` if a user with the given ID is registered.
+    pub async fn exists(&self, user_id: Id) -> Result<bool, Error> {
+        self.client
+            .store_contains_key::<store::Users1, _, _>(user_id)
+            .await
+    }
+}
+
+/// Queries for registered projects. See [RegistryApi::projects].
+pub struct Projects<'a> {
+    client: &'a Client,
+}
+
+#[allow(deprecated)]
+impl Projects<'_> {
+    /// Same as [ClientT::get_project].
+    pub async fn get(
+        &self,
+        project_name: ProjectName,
+        project_domain: ProjectDomain,
+    ) -> Result<Option<state::Projects1Data>, Error> {
+        self.client.get_project(project_name, project_domain).await
+    }
+
+    /// Same as [ClientT::list_projects].
+    pub async fn list(&self) -> Result<Vec<ProjectId>, Error> {
+        self.client.list_projects().await
+    }
+
+    /// Returns `true` if a project with the given name is registered in the given domain.
+    pub async fn exists(
+        &self,
+        project_name: ProjectName,
+        project_domain: ProjectDomain,
+    ) -> Result<bool, Error> {
+        self.client
+            .store_contains_key::<store::Projects1, _, _>((project_name, project_domain))
+            .await
+    }
+}
@@ -15,14 +15,16 @@
 
 //! Provides [Transaction] and [TransactionExtra].
 use core::marker::PhantomData;
-use parity_scale_codec::Encode;
+use frame_support::traits::Get as _;
+use parity_scale_codec::{Decode as _, Encode};
 use sp_runtime::generic::{Era, SignedPayload};
-use sp_runtime::traits::{Hash as _, SignedExtension};
+use sp_runtime::traits::{Hash as _, SignedExtension, Verify as _};
 
-use crate::{ed25519, message::Message, CryptoPair as _, TxHash};
+use crate::{ed25519, message::Message, AccountId, BlockHash, CryptoPair as _, Error, TxHash};
 use radicle_registry_core::state::AccountTransactionIndex;
 use radicle_registry_runtime::{
-    fees::PayTxFee, Balance, Call as RuntimeCall, Hash, Hashing, SignedExtra, UncheckedExtrinsic,
+    fees::PayTxFee, longevity::TxLongevity, Balance, BlockHashCount, BlockNumber,
+    Call as RuntimeCall, Hash, Hashing, SignedExtra, UncheckedExtrinsic,
 };
 
 #[derive(Clone, Debug)]
@@ -55,6 +57,8 @@ use radicle_registry_runtime::{
 ///     genesis_hash: genesis_hash,
 ///     fee: 10,
 ///     runtime_transaction_version,
+///     longevity: Some(64),
+///     era: None,
 /// };
 ///
 /// let recipient = ed25519::Pair::from_string("//Bob", None).unwrap();
@@ -90,9 +94,61 @@ impl<Message_: Message> Transaction<Message_> {
         }
     }
 
+    /// Create and sign a transaction for a runtime call that was created from a `Message_`.
+    pub(crate) fn new_signed_call(
+        signer: &ed25519::Pair,
+        call: RuntimeCall,
+        transaction_extra: TransactionExtra,
+    ) -> Self {
+        Transaction {
+            _phantom_data: PhantomData,
+            extrinsic: signed_extrinsic(signer, call, transaction_extra),
+        }
+    }
+
+    /// Create an unsigned transaction for the given message.
+    ///
+    /// The runtime only accepts unsigned transactions for [crate::message::Faucet].
+    pub fn new_unsigned(message: Message_) -> Self {
+        Transaction {
+            _phantom_data: PhantomData,
+            extrinsic: UncheckedExtrinsic::new_unsigned(message.into_runtime_call()),
+        }
+    }
+
+    /// Create and sign a transaction for each of the given messages.
+    ///
+    /// The transactions are assigned consecutive nonces starting with `transaction_extra.nonce`.
+    /// They must be submitted in order to be valid.
+    pub fn new_signed_sequence(
+        signer: &ed25519::Pair,
+        messages: Vec<Message_>,
+        transaction_extra: TransactionExtra,
+    ) -> Vec<Self> {
+        messages
+            .into_iter()
+            .zip(transaction_extra.nonce..)
+            .map(|(message, nonce)| {
+                Self::new_signed(
+                    signer,
+                    message,
+                    TransactionExtra {
+                        nonce,
+                        ..transaction_extra
+                    },
+                )
+            })
+            .collect()
+    }
+
     pub fn hash(self) -> TxHash {
         Hashing::hash_of(&self.extrinsic)
     }
+
+    /// Return the SCALE encoded extrinsic as it is submitted to the node.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.extrinsic.encode()
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -107,6 +163,17 @@ pub struct TransactionExtra {
     ///
     /// Use [crate::ClientT::runtime_version] to get the current version.
     pub runtime_transaction_version: u32,
+    /// Number of blocks after which the transaction is dropped from the transaction pool if it
+    /// has not been included. If `None` the transaction stays in the pool until it is included or
+    /// the node drops it. Transactions with a longevity of zero are rejected as invalid.
+    pub longevity: Option<BlockNumber>,
+    /// Number and hash of a recent block that makes the transaction mortal. If `None` the
+    /// transaction is immortal.
+    ///
+    /// A mortal transaction is only valid while the given block is one of the last
+    /// [BlockHashCount] blocks of the chain and cannot be replayed afterwards. Submitting it
+    /// later fails with [Error::AncientEraBlock].
+    pub era: Option<(BlockNumber, BlockHash)>,
 }
 
 /// Return a properly signed [UncheckedExtrinsic] for the given parameters that passes all
@@ -127,6 +194,66 @@ fn signed_extrinsic(
     UncheckedExtrinsic::new_signed(call, signer.public(), signature, extra)
 }
 
+/// Verify the signature of an extrinsic offline and return the account that signed it.
+///
+/// The signed payload is reconstructed the same way [Transaction::new_signed] creates it from
+/// `genesis_hash` and `runtime_transaction_version`. A mortal extrinsic does not contain the hash
+/// of its era block so it must be provided with `era_block_hash`. See [TransactionExtra::era].
+///
+/// Returns [Error::InvalidExtrinsicSignature] if the extrinsic is unsigned or the signature does
+/// not match and [Error::EraBlockHashMissing] if the extrinsic is mortal and `era_block_hash` is
+/// `None`.
+pub fn verify_extrinsic(
+    xt: &UncheckedExtrinsic,
+    genesis_hash: Hash,
+    runtime_transaction_version: u32,
+    era_block_hash: Option<BlockHash>,
+) -> Result<AccountId, Error> {
+    let (signer, signature, extra) = xt
+        .signature
+        .clone()
+        .ok_or(Error::InvalidExtrinsicSignature)?;
+    // `CheckEra` does not expose its era but is encoded like it.
+    let era =
+        Era::decode(&mut &extra.2.encode()[..]).map_err(|_| Error::InvalidExtrinsicSignature)?;
+    let era_hash = if era.is_immortal() {
+        genesis_hash
+    } else {
+        era_block_hash.ok_or(Error::EraBlockHashMissing)?
+    };
+    let additional_signed = (
+        runtime_transaction_version,
+        // Genesis hash
+        genesis_hash,
+        // Era
+        era_hash,
+        (),
+        (),
+        (),
+        (),
+    );
+    let raw_payload = SignedPayload::from_raw(xt.function.clone(), extra, additional_signed);
+    let is_valid = raw_payload.using_encoded(|payload| signature.verify(payload, &signer));
+    if is_valid {
+        Ok(signer)
+    } else {
+        Err(Error::InvalidExtrinsicSignature)
+    }
+}
+
+/// Return the encoded extrinsic that [Transaction::new_signed] creates for the given parameters.
+///
+/// Ed25519 signatures are deterministic so the result only depends on the inputs. This allows
+/// other implementations to check their encoding and signing against the test vectors in
+/// `client/tests/extrinsic_vectors.rs`.
+pub fn expected_extrinsic_bytes<Message_: Message>(
+    signer: &ed25519::Pair,
+    message: Message_,
+    transaction_extra: TransactionExtra,
+) -> Vec<u8> {
+    Transaction::new_signed(signer, message, transaction_extra).to_bytes()
+}
+
 /// Return the [SignedExtra] data that is part of [UncheckedExtrinsic] and the associated
 /// `AdditionalSigned` data included in the signature.
 fn transaction_extra_to_runtime_extra(
@@ -137,17 +264,27 @@ fn transaction_extra_to_runtime_extra(
 ) {
     let check_version = frame_system::CheckTxVersion::new();
     let check_genesis = frame_system::CheckGenesis::new();
-    let check_era = frame_system::CheckEra::from(Era::Immortal);
+    let (era, era_hash) = match extra.era {
+        Some((block_number, block_hash)) => (
+            Era::mortal(u64::from(BlockHashCount::get()), u64::from(block_number)),
+            block_hash,
+        ),
+        None => (Era::Immortal, extra.genesis_hash),
+    };
+    let check_era = frame_system::CheckEra::from(era);
     let check_nonce = frame_system::CheckNonce::from(extra.nonce);
     let check_weight = frame_system::CheckWeight::new();
     let pay_tx_fee = PayTxFee { fee: extra.fee };
+    let tx_longevity = TxLongevity {
+        longevity: extra.longevity,
+    };
 
     let additional_signed = (
         extra.runtime_transaction_version,
         // Genesis hash
         extra.genesis_hash,
         // Era
-        extra.genesis_hash,
+        era_hash,
         check_nonce
             .additional_signed()
             .expect("statically returns Ok"),
@@ -157,6 +294,9 @@ fn transaction_extra_to_runtime_extra(
         pay_tx_fee
             .additional_signed()
             .expect("statically returns Ok"),
+        tx_longevity
+            .additional_signed()
+            .expect("statically returns Ok"),
     );
 
     let extra = (
@@ -166,6 +306,7 @@ fn transaction_extra_to_runtime_extra(
         check_nonce,
         check_weight,
         pay_tx_fee,
+        tx_longevity,
     );
 
     (extra, additional_signed)
@@ -186,6 +327,7 @@ mod test {
         let genesis_config = GenesisConfig {
             pallet_balances: None,
             pallet_sudo: None,
+            registry: None,
             system: None,
         };
         let mut test_ext = sp_io::TestExternalities::new(genesis_config.build_storage().unwrap());
@@ -211,6 +353,8 @@ mod test {
                 genesis_hash,
                 fee: 3,
                 runtime_transaction_version: radicle_registry_runtime::VERSION.transaction_version,
+                longevity: None,
+                era: None,
             },
         );
 
@@ -234,10 +378,116 @@ mod test {
                 genesis_hash: H256::random(),
                 fee: 9,
                 runtime_transaction_version: radicle_registry_runtime::VERSION.transaction_version,
+                longevity: None,
+                era: None,
             },
         );
         let extrinsic_hash = Hashing::hash_of(&signed_tx.extrinsic);
 
         assert_eq!(signed_tx.hash(), extrinsic_hash);
     }
+
+    #[test]
+    /// Assert that [verify_extrinsic] returns the signer for valid extrinsics and rejects
+    /// extrinsics signed for a different chain or transaction version.
+    fn check_verify_extrinsic() {
+        let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+        let genesis_hash = H256::random();
+        let transaction_version = 7;
+        let xt = signed_extrinsic(
+            &alice,
+            frame_system::Call::fill_block(Perbill::from_parts(0)).into(),
+            TransactionExtra {
+                nonce: 3,
+                genesis_hash,
+                fee: 9,
+                runtime_transaction_version: transaction_version,
+                longevity: None,
+                era: None,
+            },
+        );
+
+        assert_eq!(
+            verify_extrinsic(&xt, genesis_hash, transaction_version, None).unwrap(),
+            alice.public()
+        );
+        assert!(matches!(
+            verify_extrinsic(&xt, H256::random(), transaction_version, None),
+            Err(Error::InvalidExtrinsicSignature)
+        ));
+        assert!(matches!(
+            verify_extrinsic(&xt, genesis_hash, transaction_version + 1, None),
+            Err(Error::InvalidExtrinsicSignature)
+        ));
+    }
+
+    #[test]
+    /// Assert that [verify_extrinsic] verifies mortal extrinsics against the era block hash.
+    fn check_verify_mortal_extrinsic() {
+        let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+        let genesis_hash = H256::random();
+        let era_block_hash = H256::random();
+        let transaction_version = radicle_registry_runtime::VERSION.transaction_version;
+        let xt = signed_extrinsic(
+            &alice,
+            frame_system::Call::fill_block(Perbill::from_parts(0)).into(),
+            TransactionExtra {
+                nonce: 3,
+                genesis_hash,
+                fee: 9,
+                runtime_transaction_version: transaction_version,
+                longevity: None,
+                era: Some((12, era_block_hash)),
+            },
+        );
+
+        assert_eq!(
+            verify_extrinsic(&xt, genesis_hash, transaction_version, Some(era_block_hash)).unwrap(),
+            alice.public()
+        );
+        assert!(matches!(
+            verify_extrinsic(&xt, genesis_hash, transaction_version, Some(H256::random())),
+            Err(Error::InvalidExtrinsicSignature)
+        ));
+        assert!(matches!(
+            verify_extrinsic(&xt, genesis_hash, transaction_version, None),
+            Err(Error::EraBlockHashMissing)
+        ));
+    }
+
+    #[test]
+    /// Check that a signed sequence of transactions uses consecutive nonces.
+    fn check_transaction_sequence_nonces() {
+        let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+        let transaction_extra = TransactionExtra {
+            nonce: 5,
+            genesis_hash: H256::random(),
+            fee: 9,
+            runtime_transaction_version: radicle_registry_runtime::VERSION.transaction_version,
+            longevity: None,
+            era: None,
+        };
+        let messages: Vec<_> = (1..=3)
+            .map(|amount| message::Transfer {
+                recipient: alice.public(),
+                amount,
+            })
+            .collect();
+
+        let sequence =
+            Transaction::new_signed_sequence(&alice, messages.clone(), transaction_extra);
+
+        assert_eq!(sequence.len(), messages.len());
+        for ((tx, message), nonce) in sequence.into_iter().zip(messages).zip(5..) {
+            let expected_tx = Transaction::new_signed(
+                &alice,
+                message,
+                TransactionExtra {
+                    nonce,
+                    ..transaction_extra
+                },
+            );
+            assert_eq!(tx.hash(), expected_tx.hash());
+        }
+    }
 }
@@ -17,6 +17,10 @@
 //!
 //! Note that chain state is shared between the test runs.
 //! To avoid AccountUserAssociated errors, use a distinct author for each test.
+//!
+//! Tests that behave the same on the emulator and on a node are implemented in `runtime-tests`
+//! with [for_each_backend] instead. They run against the node if the
+//! `RADICLE_REGISTRY_TEST_NODE_HOST` environment variable is set.
 
 use serial_test::serial;
 
@@ -28,17 +32,28 @@ use radicle_registry_test_utils::*;
 async fn register_project() {
     let _ = env_logger::try_init();
     let node_host = url::Host::parse("127.0.0.1").unwrap();
-    let client = Client::create_with_executor(node_host).await.unwrap();
-    let author = key_pair_with_funds(&client).await;
+    let (client, author) = remote_with_funded_author(node_host).await;
 
     for domain in generate_project_domains(&client, &author).await {
         let initial_balance = match &domain {
             ProjectDomain::Org(org_id) => {
-                let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+                let org = client
+                    .registry()
+                    .orgs()
+                    .get(org_id.clone())
+                    .await
+                    .unwrap()
+                    .unwrap();
                 client.free_balance(&org.account_id()).await.unwrap()
             }
             ProjectDomain::User(user_id) => {
-                let user = client.get_user(user_id.clone()).await.unwrap().unwrap();
+                let user = client
+                    .registry()
+                    .users()
+                    .get(user_id.clone())
+                    .await
+                    .unwrap()
+                    .unwrap();
                 client.free_balance(&user.account_id()).await.unwrap()
             }
         };
@@ -50,14 +65,18 @@ async fn register_project() {
         assert_eq!(tx_included.result, Ok(()));
 
         let project = client
-            .get_project(project_name.clone(), domain.clone())
+            .registry()
+            .projects()
+            .get(project_name.clone(), domain.clone())
             .await
             .unwrap()
             .unwrap();
         assert_eq!(project.metadata().clone(), message.metadata.clone());
 
         let has_project = client
-            .list_projects()
+            .registry()
+            .projects()
+            .list()
             .await
             .unwrap()
             .iter()
@@ -66,11 +85,23 @@ async fn register_project() {
 
         let (projects, account_id) = match &domain {
             ProjectDomain::Org(org_id) => {
-                let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+                let org = client
+                    .registry()
+                    .orgs()
+                    .get(org_id.clone())
+                    .await
+                    .unwrap()
+                    .unwrap();
                 (org.projects().clone(), org.account_id())
             }
             ProjectDomain::User(user_id) => {
-                let user = client.get_user(user_id.clone()).await.unwrap().unwrap();
+                let user = client
+                    .registry()
+                    .users()
+                    .get(user_id.clone())
+                    .await
+                    .unwrap()
+                    .unwrap();
                 (user.projects().clone(), user.account_id())
             }
         };
@@ -99,13 +130,20 @@ async fn register_member() {
         &author,
         message::RegisterOrg {
             org_id: org_id.clone(),
+            reservation_salt: None,
         },
     )
     .await;
     assert_eq!(org_registered_tx.result, Ok(()));
 
     // The org needs funds to submit transactions.
-    let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+    let org = client
+        .registry()
+        .orgs()
+        .get(org_id.clone())
+        .await
+        .unwrap()
+        .unwrap();
     let initial_balance = 1000;
     transfer(&client, &author, org.account_id(), initial_balance).await;
 
@@ -125,7 +163,13 @@ async fn register_member() {
     .await;
     assert_eq!(tx_applied.result, Ok(()));
 
-    let re_org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+    let re_org = client
+        .registry()
+        .orgs()
+        .get(org_id.clone())
+        .await
+        .unwrap()
+        .unwrap();
     assert_eq!(re_org.members().len(), 2);
     assert!(
         re_org.members().contains(&author_id),
@@ -170,13 +214,14 @@ async fn register_org() {
         &author,
         message::RegisterOrg {
             org_id: org_id.clone(),
+            reservation_salt: None,
         },
         random_fee,
     )
     .await;
     assert_eq!(tx_included.result, Ok(()));
 
-    let opt_org = client.get_org(org_id.clone()).await.unwrap();
+    let opt_org = client.registry().orgs().get(org_id.clone()).await.unwrap();
     assert!(opt_org.is_some(), "Registered org not found in orgs list");
     let org = opt_org.unwrap();
     assert_eq!(*org.members(), vec![user_id]);
@@ -206,13 +251,19 @@ async fn register_user() {
         &author,
         message::RegisterUser {
             user_id: user_id.clone(),
+            reservation_salt: None,
         },
         random_fee,
     )
     .await;
     assert_eq!(tx_included.result, Ok(()));
 
-    let maybe_user = client.get_user(user_id.clone()).await.unwrap();
+    let maybe_user = client
+        .registry()
+        .users()
+        .get(user_id.clone())
+        .await
+        .unwrap();
     assert!(
         maybe_user.is_some(),
         "Registered user not found in users list"
@@ -248,6 +299,8 @@ async fn invalid_transaction() {
             genesis_hash: Hash::zero(),
             fee: 123,
             runtime_transaction_version,
+            longevity: None,
+            era: None,
         },
     );
 
@@ -264,8 +317,7 @@ async fn invalid_transaction() {
 #[serial]
 async fn insufficient_fee() {
     let node_host = url::Host::parse("127.0.0.1").unwrap();
-    let client = Client::create_with_executor(node_host).await.unwrap();
-    let tx_author = key_pair_with_funds(&client).await;
+    let (client, tx_author) = remote_with_funded_author(node_host).await;
     let insufficient_fee: Balance = 0;
 
     let whatever_message = random_register_org_message();
@@ -303,68 +355,13 @@ async fn insufficient_funds() {
     }
 }
 
-/// Test that an org can not be registered with an id already taken by another org.
-#[async_std::test]
-#[serial]
-async fn register_org_with_id_taken_by_org() {
-    let node_host = url::Host::parse("127.0.0.1").unwrap();
-    let client = Client::create_with_executor(node_host).await.unwrap();
-    let (author, _) = key_pair_with_associated_user(&client).await;
-    let (org_id, _) = register_random_org(&client, &author).await;
-
-    let register_org_message = message::RegisterOrg { org_id };
-    let tx_included_twice = submit_ok(&client, &author, register_org_message).await;
-    assert_eq!(
-        tx_included_twice.result,
-        Err(RegistryError::IdAlreadyTaken.into())
-    );
-}
-
-/// Test that an org can not be registered with an id already taken by a user.
-#[async_std::test]
-#[serial]
-async fn register_org_with_taken_user_id() {
-    let node_host = url::Host::parse("127.0.0.1").unwrap();
-    let client = Client::create_with_executor(node_host).await.unwrap();
-    let (author, id) = key_pair_with_associated_user(&client).await;
-
-    let register_org_message = message::RegisterOrg { org_id: id.clone() };
-    let tx_included_org = submit_ok(&client, &author, register_org_message.clone()).await;
-    assert_eq!(
-        tx_included_org.result,
-        Err(RegistryError::IdAlreadyTaken.into())
-    );
-}
-
-/// Test that a user can not be registered with an id already taken by another user.
-#[async_std::test]
-#[serial]
-async fn register_user_with_id_taken_by_user() {
-    let node_host = url::Host::parse("127.0.0.1").unwrap();
-    let client = Client::create_with_executor(node_host).await.unwrap();
-    let (author, id) = key_pair_with_associated_user(&client).await;
-
-    let register_user_message = message::RegisterUser { user_id: id };
-    let tx_included_twice = submit_ok(&client, &author, register_user_message).await;
-    assert_eq!(
-        tx_included_twice.result,
-        Err(RegistryError::IdAlreadyTaken.into())
-    );
-}
-
-/// Test that a user can not be registered with an id already taken by an org.
+/// Test that the dev node reports the dummy PoW algorithm.
 #[async_std::test]
 #[serial]
-async fn register_user_with_id_taken_by_org() {
+async fn pow_algorithm_dev_chain() {
     let node_host = url::Host::parse("127.0.0.1").unwrap();
     let client = Client::create_with_executor(node_host).await.unwrap();
-    let (author, _) = key_pair_with_associated_user(&client).await;
-    let (org_id, _) = register_random_org(&client, &author).await;
 
-    let register_user_message = message::RegisterUser { user_id: org_id };
-    let tx_included_user = submit_ok(&client, &author, register_user_message).await;
-    assert_eq!(
-        tx_included_user.result,
-        Err(RegistryError::IdAlreadyTaken.into())
-    );
+    let pow_algorithm = client.pow_algorithm().await.unwrap();
+    assert_eq!(pow_algorithm, PowKind::Dummy);
 }
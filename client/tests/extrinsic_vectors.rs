@@ -0,0 +1,294 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Canonical encodings of signed extrinsics for every message type.
+//!
+//! The vectors are meant for other implementations of the transaction format to validate their
+//! encoding and signing against. A failing test means that the encoding of extrinsics changed
+//! and that these implementations must be updated.
+//!
+//! All transactions are signed by the ed25519 key pair with the secret seed `[1u8; 32]` and
+//! use `transaction_extra()`. The signed payload is the encoded call followed by the encoded
+//! `SignedExtra` and its `AdditionalSigned` data: the runtime transaction version, the genesis
+//! hash for `CheckGenesis` and the genesis hash again for the immortal `CheckEra`. Payloads
+//! longer than 256 bytes are hashed with Blake2-256 before signing.
+
+use std::convert::TryFrom;
+
+use radicle_registry_client::*;
+
+fn signer() -> ed25519::Pair {
+    ed25519::Pair::from_seed(&[1u8; 32])
+}
+
+fn recipient() -> AccountId {
+    ed25519::Pair::from_seed(&[2u8; 32]).public()
+}
+
+fn transaction_extra() -> TransactionExtra {
+    TransactionExtra {
+        nonce: 5,
+        genesis_hash: H256::repeat_byte(0x11),
+        fee: 1000,
+        runtime_transaction_version: 34,
+        longevity: None,
+        era: None,
+    }
+}
+
+fn org_id() -> Id {
+    Id::try_from("monadic").unwrap()
+}
+
+fn user_id() -> Id {
+    Id::try_from("cloudhead").unwrap()
+}
+
+/// Commitment of [signer] to reserve [org_id] with the salt `[0x22; 32]`.
+fn commitment() -> H256 {
+    reservation_commitment(&org_id(), &signer().public(), &H256::repeat_byte(0x22))
+}
+
+fn project_name() -> ProjectName {
+    ProjectName::try_from("radicle-registry").unwrap()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[test]
+fn register_project() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::RegisterProject {
+            project_name: project_name(),
+            project_domain: ProjectDomain::Org(org_id()),
+            metadata: Bytes128::from_vec(b"metadata".to_vec()).unwrap(),
+        },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "6502848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5ceafeeb6334a15f2fa1d87da1066cb846af37ebd19cea825dd2a270707bf4d0b73549d7a04562036eb48fed814513bad3a548d3a08329657121d410a52e33530c0014e80300000000000000000000000000000005004072616469636c652d7265676973747279001c6d6f6e61646963206d65746164617461");
+}
+
+#[test]
+fn accept_project() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::Sudo(message::AcceptProject {
+            project_name: project_name(),
+            project_domain: ProjectDomain::User(user_id()),
+        }),
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "5102848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c0a44f51fcb38365ab362f74ebd9f0a763a67c0f92f6cf26a742c3385b7344b1ba15dfec78ff5cf1c3218cca148d29db15f7f7991abd7483f4ebb33b0349fa6080014e803000000000000000000000000000000040005014072616469636c652d72656769737472790124636c6f756468656164");
+}
+
+#[test]
+fn reject_project() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::Sudo(message::RejectProject {
+            project_name: project_name(),
+            project_domain: ProjectDomain::User(user_id()),
+        }),
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "5102848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c64f11fcc6e3bc781792c7fbae6583e974855a8999f81900a5efeacaf09f9037c14d357d180c598c1472c41a86ab9be4e64b66d6e34fca1b11fd1237fa647210e0014e803000000000000000000000000000000040005024072616469636c652d72656769737472790124636c6f756468656164");
+}
+
+#[test]
+fn register_member() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::RegisterMember {
+            user_id: user_id(),
+            org_id: org_id(),
+        },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "2102848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c51343f0802927f6e8495ba7116ba833dd32b7ce8f654f4cf12038df4231bd1dfed6fe9b426b952b507fac7a0469f298b676189248ced87ac7cbaead4d07b5c070014e803000000000000000000000000000000050324636c6f7564686561641c6d6f6e61646963");
+}
+
+#[test]
+fn leave_org() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::LeaveOrg { org_id: org_id() },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "f901848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c4772228f9ef94052cc61760592ffad11a5882ccba40dcd168b7e33ba8fb9d79251a5bac4f7a7db8bf0a588d4a4bb3fb90e2518f4df179b7023d23c31d96f460b0014e80300000000000000000000000000000005041c6d6f6e61646963");
+}
+
+#[test]
+fn register_org() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::RegisterOrg {
+            org_id: org_id(),
+            reservation_salt: None,
+        },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "fd01848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5cff5a1823ce84ec3b7ebed6604e1e5a5d311bbc3d410dbc789adc3f2f8c5eab36b70d230ecaf252adfe80a916a59f361e204f93041567b801b54ec7f7895a2a060014e80300000000000000000000000000000005051c6d6f6e6164696300");
+}
+
+#[test]
+fn unregister_org() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::UnregisterOrg { org_id: org_id() },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "f901848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5cca194d81625ac042225ef12c4814382b2b60ba4b874742c089601e974d8b1293af8655442e0878d446a4a8ea30dcc7c0c09fe8090ce54bc4431a8b1305b5ff090014e80300000000000000000000000000000005061c6d6f6e61646963");
+}
+
+#[test]
+fn register_user() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::RegisterUser {
+            user_id: user_id(),
+            reservation_salt: None,
+        },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "0502848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c751040abbe9602c74ae215373c45d8dfd99ec314a3be4db7e3bef05009cd5e13f8cb8d6be20625c1925c314c387f8675481da55fade2171a3823d4ef63bf34050014e803000000000000000000000000000000050724636c6f75646865616400");
+}
+
+#[test]
+fn reservation_commitment_vector() {
+    assert_eq!(
+        to_hex(commitment().as_bytes()),
+        "bdccef403ee045b420529efc24892abd4a2f6724275952d98edf4fd2eba40f81"
+    );
+}
+
+#[test]
+fn reserve_id() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::ReserveId {
+            commitment: commitment(),
+        },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "5902848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5cd278ad866b5cf7fd558d2550293429a33d75c483fb7d71cbc0017f447d2b7b7752ff009c90e353270dcf51d3c538a0b001175f30a1889f0c00296fd987fad2070014e8030000000000000000000000000000000508bdccef403ee045b420529efc24892abd4a2f6724275952d98edf4fd2eba40f81");
+}
+
+#[test]
+fn cancel_reservation() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::CancelReservation {
+            commitment: commitment(),
+        },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "5902848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c130ae136680a158bb8ce1dd7410f5c13fafba03d50a80afef9d6d359a1dc1325db547a891fb3c64ba37ad1950e59912e3edd567f5ad345d5b7444ba2fc662b060014e8030000000000000000000000000000000509bdccef403ee045b420529efc24892abd4a2f6724275952d98edf4fd2eba40f81");
+}
+
+#[test]
+fn unregister_user() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::UnregisterUser { user_id: user_id() },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "0102848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5caf203e29dafdc4b82a9ba3e486a0a8b2fb34e9068d193c867f20fdaa70354445dcde0e25f861ceeeabf6e88f23c463a11dd5e4cf1337469d62874ff9ed52990d0014e803000000000000000000000000000000050a24636c6f756468656164");
+}
+
+#[test]
+fn transfer_from_org() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::TransferFromOrg {
+            org_id: org_id(),
+            recipient: recipient(),
+            amount: 1_000_000,
+        },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "b902848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c342325878f8a7c22e7a4423121cc5b4ed42d1a9b6edb0563d394a5dd89158c0eb063cac24e76db856b393c13036a42b588a135c3a9b4f979b820087d46d9ac040014e803000000000000000000000000000000050b1c6d6f6e616469638139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b39440420f00000000000000000000000000");
+}
+
+#[test]
+fn transfer() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::Transfer {
+            recipient: recipient(),
+            amount: 1_000_000,
+        },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "9902848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c8f0de395d4e3b3722591cb5ecee6d406507c3d53ed605084ea8c749dfd604b2ba6b38ac24bb1746bc2f48641ec2922cd14d1c111a89ea77fb2c4f2cc4943c9040014e803000000000000000000000000000000050c8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b39440420f00000000000000000000000000");
+}
+
+#[test]
+fn multi_transfer() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::MultiTransfer {
+            transfers: vec![
+                (recipient(), 1_000_000),
+                (ed25519::Pair::from_seed(&[3u8; 32]).public(), 2_000_000),
+            ],
+        },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "5d03848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5ca09330e848085959cfd554010330429302a2ae9ca46132ddfb2351432eb6557d5848928bc24fcbf2eff45fed0b919d99d4529b103b94144655e6fc277549c0020014e803000000000000000000000000000000050f088139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b39440420f00000000000000000000000000ed4928c628d1c2c6eae90338905995612959273a5c63f93636c14614ac8737d180841e00000000000000000000000000");
+}
+
+#[test]
+fn transfer_with_longevity() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::Transfer {
+            recipient: recipient(),
+            amount: 1_000_000,
+        },
+        TransactionExtra {
+            longevity: Some(64),
+            era: None,
+            ..transaction_extra()
+        },
+    );
+    assert_eq!(to_hex(&bytes), "a902848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c8ddafa37f13022b2ddf5b30b66dedb08e5c16c7c095fef00f1180ed8600a70515d7dc4a2823872a7e7eb9f2d00e64fd1b7e451293c261ae2f610ac55535c84060014e80300000000000000000000000000000140000000050c8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b39440420f00000000000000000000000000");
+}
+
+#[test]
+/// The encoded payload exceeds 256 bytes so its Blake2-256 hash is signed instead.
+fn update_runtime() {
+    let bytes = expected_extrinsic_bytes(
+        &signer(),
+        message::UpdateRuntime {
+            code: vec![0u8; 256],
+        },
+        transaction_extra(),
+    );
+    assert_eq!(to_hex(&bytes), "e905848a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5cab31eadeac489a2b574c59e6d7b32f4b29bdd97974f948b09ad9b5e29b53c3f923deee938cf5bc36f15875f3f89ce94a237df633b27ef373d10887d07fefff080014e80300000000000000000000000000000004000003010400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000");
+}
+
+#[test]
+/// Faucet transactions are unsigned.
+fn faucet() {
+    let bytes = Transaction::new_unsigned(message::Faucet {
+        recipient: recipient(),
+        amount: 1_000_000,
+    })
+    .to_bytes();
+    assert_eq!(to_hex(&bytes), "cc04050d8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b39440420f00000000000000000000000000");
+}
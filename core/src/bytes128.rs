@@ -20,7 +20,15 @@ use core::convert::TryFrom;
 use parity_scale_codec::{Decode, Encode, Error as CodecError, Input};
 
 /// Byte vector that is limited to 128 bytes.
-#[derive(Encode, Clone, Debug, Eq, PartialEq)]
+///
+/// The content is neither padded nor truncated. A value with fewer than 128 bytes is SCALE encoded
+/// like a `Vec<u8>`: the compact encoded length followed by the bytes. Decoding restores the
+/// exact bytes and length and fails if the encoded length is larger than 128 bytes.
+///
+/// [core::fmt::Display] and [core::fmt::Debug] render the content as text if it is valid UTF-8
+/// without control characters and as `0x` prefixed hex otherwise. [core::fmt::Display] also renders
+/// text that starts with `0x` as hex so that it cannot be mistaken for other content.
+#[derive(Encode, Clone, Eq, PartialEq)]
 pub struct Bytes128(Vec<u8>);
 
 impl Bytes128 {
@@ -36,6 +44,72 @@ impl Bytes128 {
             Ok(Bytes128(vector))
         }
     }
+
+    /// Build a [Bytes128] from a copy of `slice`. Fails if `slice` is longer than 128 bytes.
+    ///
+    /// Same as `Bytes128::try_from(slice)`. The length of `slice` is preserved, see [Bytes128].
+    pub fn try_from_slice(slice: &[u8]) -> Result<Self, InordinateVectorError> {
+        Bytes128::from_vec(slice.to_vec())
+    }
+
+    /// Returns the content as a string if it is valid UTF-8 without control characters.
+    fn as_printable_str(&self) -> Option<&str> {
+        core::str::from_utf8(&self.0)
+            .ok()
+            .filter(|string| !string.chars().any(char::is_control))
+    }
+
+    fn fmt_content(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self
+            .as_printable_str()
+            .filter(|string| !string.starts_with("0x"))
+        {
+            Some(string) => write!(f, "{}", string),
+            None => {
+                write!(f, "0x")?;
+                for byte in &self.0 {
+                    write!(f, "{:02x}", byte)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for Bytes128 {
+    type Error = InordinateVectorError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Bytes128::try_from_slice(value)
+    }
+}
+
+impl core::str::FromStr for Bytes128 {
+    type Err = InordinateVectorError;
+
+    /// Build a [Bytes128] from the UTF-8 encoding of `s`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes128::try_from(s.as_bytes())
+    }
+}
+
+impl core::fmt::Display for Bytes128 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_content(f)
+    }
+}
+
+impl core::fmt::Debug for Bytes128 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_printable_str() {
+            Some(string) => write!(f, "Bytes128({:?})", string),
+            None => {
+                write!(f, "Bytes128(")?;
+                self.fmt_content(f)?;
+                write!(f, ")")
+            }
+        }
+    }
 }
 
 impl TryFrom<Vec<u8>> for Bytes128 {
@@ -143,6 +217,68 @@ mod test {
         assert!(decoding_result.is_err())
     }
 
+    #[test]
+    fn from_str() {
+        let bytes128: Bytes128 = "{\"url\":\"https://radicle.xyz\"}".parse().unwrap();
+        assert_eq!(
+            Vec::from(bytes128),
+            b"{\"url\":\"https://radicle.xyz\"}".to_vec()
+        );
+
+        let inordinate_string = "a".repeat(Bytes128::MAXIMUM_SUPPORTED_LENGTH + 1);
+        assert_eq!(
+            inordinate_string.parse::<Bytes128>(),
+            Err(InordinateVectorError())
+        );
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let slice: &[u8] = &[1, 2, 3];
+        assert_eq!(Bytes128::try_from(slice), Ok(Bytes128(vec![1, 2, 3])));
+
+        let inordinate_vector = random_vector(Bytes128::MAXIMUM_SUPPORTED_LENGTH + 1);
+        assert_eq!(
+            Bytes128::try_from(&inordinate_vector[..]),
+            Err(InordinateVectorError())
+        );
+    }
+
+    #[test]
+    fn short_content_round_trip() {
+        let bytes128 = Bytes128::try_from_slice(&[0xab, 0xcd]).unwrap();
+        let encoded = bytes128.encode();
+        assert_eq!(encoded, vec![2 << 2, 0xab, 0xcd]);
+
+        let decoded = Bytes128::decode(&mut &encoded[..]).unwrap();
+        assert_eq!(Vec::from(decoded), vec![0xab, 0xcd]);
+
+        assert_eq!(
+            Bytes128::try_from_slice(&[0; 129]),
+            Err(InordinateVectorError())
+        );
+    }
+
+    #[test]
+    fn display_and_debug() {
+        let text: Bytes128 = "radicle".parse().unwrap();
+        assert_eq!(text.to_string(), "radicle");
+        assert_eq!(format!("{:?}", text), "Bytes128(\"radicle\")");
+
+        let binary = Bytes128(vec![0, 0xab, 0xff]);
+        assert_eq!(binary.to_string(), "0x00abff");
+        assert_eq!(format!("{:?}", binary), "Bytes128(0x00abff)");
+    }
+
+    #[test]
+    fn display_hex_like_text() {
+        let hex_like_text: Bytes128 = "0x00abff".parse().unwrap();
+        let binary = Bytes128(vec![0, 0xab, 0xff]);
+        assert_ne!(hex_like_text.to_string(), binary.to_string());
+        assert_eq!(hex_like_text.to_string(), "0x3078303061626666");
+        assert_eq!(format!("{:?}", hex_like_text), "Bytes128(\"0x00abff\")");
+    }
+
     fn random_vector(size: usize) -> Vec<u8> {
         (0..size).map(|_| rand::random::<u8>()).collect()
     }
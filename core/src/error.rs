@@ -30,6 +30,45 @@ pub enum TransactionError {
 
     #[cfg_attr(feature = "std", error("{0:?}"))]
     OtherDispatchError(DispatchError),
+
+    /// A transfer failed because it would leave the sender account with less than the existential
+    /// deposit.
+    ///
+    /// The runtime reports this as a [DispatchError] of the balances module. The client detects
+    /// it for [crate::message::Transfer] and [crate::message::TransferFromOrg].
+    #[cfg_attr(
+        feature = "std",
+        error("transfer would leave account below minimum balance")
+    )]
+    WouldReapAccount,
+
+    /// A call of a batch failed. See [BatchError].
+    #[cfg_attr(feature = "std", error(transparent))]
+    BatchInterrupted(#[cfg_attr(feature = "std", from)] BatchError),
+}
+
+/// Error of a batch transaction that failed because one of its calls failed.
+///
+/// The changes of all calls in the batch are reverted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+#[cfg_attr(
+    feature = "std",
+    error("call {failed_index} of the batch failed: {}", TransactionError::from(*.dispatch_error))
+)]
+pub struct BatchError {
+    /// Index of the failed call in the batch.
+    pub failed_index: u32,
+
+    /// Error the failed call returned.
+    pub dispatch_error: DispatchError,
+}
+
+impl BatchError {
+    /// The error of the failed call as a [TransactionError].
+    pub fn error(&self) -> TransactionError {
+        TransactionError::from(self.dispatch_error)
+    }
 }
 
 impl From<DispatchError> for TransactionError {
@@ -140,6 +179,48 @@ pub enum RegistryError {
         error("the author has insufficient funds to cover the registration fee")
     )]
     FailedRegistrationFeePayment = 19,
+
+    #[cfg_attr(
+        feature = "std",
+        error("the author already holds a reservation with the commitment")
+    )]
+    DuplicateReservation = 20,
+
+    #[cfg_attr(
+        feature = "std",
+        error("the author holds no reservation with the commitment")
+    )]
+    InexistentReservation = 21,
+
+    #[cfg_attr(
+        feature = "std",
+        error("the org has reached the maximum number of members")
+    )]
+    MemberLimitReached = 22,
+
+    #[cfg_attr(
+        feature = "std",
+        error("the last member of an org cannot leave it, unregister the org instead")
+    )]
+    LastOrgMember = 23,
+
+    #[cfg_attr(feature = "std", error("the pending project does not exist"))]
+    InexistentPendingProject = 24,
+
+    #[cfg_attr(
+        feature = "std",
+        error("the transaction has more recipients than allowed")
+    )]
+    TooManyRecipients = 25,
+
+    #[cfg_attr(feature = "std", error("the batch has more calls than allowed"))]
+    TooManyBatchCalls = 26,
+
+    #[cfg_attr(
+        feature = "std",
+        error("the batch contains a call that cannot be batched")
+    )]
+    InvalidBatchCall = 27,
 }
 
 // The index with which the registry runtime module is declared
@@ -22,7 +22,10 @@ use alloc::string::{String, ToString};
 use core::convert::{From, Into, TryFrom};
 use parity_scale_codec as codec;
 
-#[derive(codec::Encode, Clone, Debug, Eq, PartialEq)]
+/// The ID of an org or a user.
+///
+/// IDs are ordered lexicographically by their string representation.
+#[derive(codec::Encode, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 #[cfg_attr(feature = "std", serde(try_from = "String"))]
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub struct Id(String);
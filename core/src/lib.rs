@@ -20,8 +20,8 @@
 extern crate alloc;
 
 use parity_scale_codec::{Decode, Encode};
-use sp_core::ed25519;
-use sp_runtime::traits::BlakeTwo256;
+use sp_core::{ed25519, H256};
+use sp_runtime::traits::{BlakeTwo256, Hash as _};
 
 pub use sp_runtime::DispatchError;
 
@@ -38,7 +38,7 @@ mod project_name;
 pub use project_name::{InvalidProjectNameError, ProjectName};
 
 mod error;
-pub use error::{RegistryError, TransactionError};
+pub use error::{BatchError, RegistryError, TransactionError};
 
 /// The hashing algorithm to use
 pub type Hashing = BlakeTwo256;
@@ -48,6 +48,9 @@ pub type Hashing = BlakeTwo256;
 /// Each account has an associated [state::AccountBalance] and [state::AccountTransactionIndex].
 pub type AccountId = ed25519::Public;
 
+/// An index to a block.
+pub type BlockNumber = u32;
+
 /// Amout of currency denominated in μRAD.
 ///
 /// The non-negative balance of anything storing the amount of currency.
@@ -61,10 +64,15 @@ pub const fn rad_to_balance(rad: u64) -> Balance {
 }
 
 /// The id of a project. Used as storage key.
+///
+/// Project IDs are ordered by their name first and then by their domain. The ordering does not
+/// depend on the SCALE encoding and is stable across runtime versions.
 pub type ProjectId = (ProjectName, ProjectDomain);
 
 /// The domain under which a [crate::state::Projects1Data] lives.
-#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+///
+/// Domains are ordered by their ID with org domains ordered before user domains.
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum ProjectDomain {
     Org(Id),
@@ -78,3 +86,56 @@ impl ProjectDomain {
         }
     }
 }
+
+/// Commitment to reserve `id` for `account_id` with [message::ReserveId].
+///
+/// The commitment is the [Hashing] hash of the SCALE encoded `(id, account_id, salt)`. It hides
+/// the ID until the reservation is revealed by registering an org or a user with `salt`. Since
+/// the account is part of the hash a commitment copied by another account cannot be revealed.
+pub fn reservation_commitment(id: &Id, account_id: &AccountId, salt: &H256) -> H256 {
+    Hashing::hash_of(&(id, account_id, salt))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::convert::TryFrom;
+    use std::collections::{BTreeSet, HashSet};
+
+    fn project_id(name: &str, domain: ProjectDomain) -> ProjectId {
+        (ProjectName::try_from(name).unwrap(), domain)
+    }
+
+    /// Test that equal project IDs encode and hash equally and that project IDs are ordered by
+    /// name first and by domain second.
+    #[test]
+    fn project_id_ordering() {
+        let org = ProjectDomain::Org(Id::try_from("monadic").unwrap());
+        let user = ProjectDomain::User(Id::try_from("alice").unwrap());
+
+        let a = project_id("radicle", org.clone());
+        let b = project_id("radicle", org.clone());
+        assert_eq!(a, b);
+        assert_eq!(a.encode(), b.encode());
+        assert_eq!(a.cmp(&b), core::cmp::Ordering::Equal);
+
+        let ids = vec![
+            project_id("upstream", org.clone()),
+            project_id("radicle", user.clone()),
+            project_id("radicle", org.clone()),
+            a.clone(),
+        ];
+        let hash_set = ids.iter().cloned().collect::<HashSet<_>>();
+        assert_eq!(hash_set.len(), 3);
+
+        let sorted = ids.into_iter().collect::<BTreeSet<_>>();
+        assert_eq!(
+            sorted.into_iter().collect::<Vec<_>>(),
+            vec![
+                project_id("radicle", org.clone()),
+                project_id("radicle", user),
+                project_id("upstream", org),
+            ]
+        );
+    }
+}
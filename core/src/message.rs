@@ -21,6 +21,7 @@ extern crate alloc;
 use crate::{AccountId, Balance, Bytes128, Id, ProjectDomain, ProjectName};
 use alloc::prelude::v1::Vec;
 use parity_scale_codec::{Decode, Encode};
+use sp_core::H256;
 
 /// Registers an org on the Radicle Registry with the given ID.
 ///
@@ -33,6 +34,8 @@ use parity_scale_codec::{Decode, Encode};
 ///
 /// [crate::state::Orgs1Data::account_id] is generated randomly.
 ///
+/// If `reservation_salt` is given the reservation it reveals is consumed. See [ReserveId].
+///
 /// # State-dependent validations
 ///
 /// An Org with the same ID must not yet exist.
@@ -41,9 +44,15 @@ use parity_scale_codec::{Decode, Encode};
 ///
 /// A user associated with the author must exist.
 ///
+/// If `reservation_salt` is given the author must hold an active reservation with the
+/// commitment [crate::reservation_commitment] of the ID, the author, and the salt.
+///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
 pub struct RegisterOrg {
     pub org_id: Id,
+
+    /// Salt of the reservation of `org_id` made with [ReserveId].
+    pub reservation_salt: Option<H256>,
 }
 
 /// Unregisters an org on the Radicle Registry with the given ID.
@@ -70,15 +79,72 @@ pub struct UnregisterOrg {
 ///
 /// [crate::state::Users1Data::account_id] is generated randomly.
 ///
+/// If `reservation_salt` is given the reservation it reveals is consumed. See [ReserveId].
+///
 /// # State-dependent validations
 ///
 /// A user with the same ID must not yet exist.
 ///
 /// An Org with the same ID must not yet exist.
 ///
+/// If `reservation_salt` is given the author must hold an active reservation with the
+/// commitment [crate::reservation_commitment] of the ID, the author, and the salt.
+///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
 pub struct RegisterUser {
     pub user_id: Id,
+
+    /// Salt of the reservation of `user_id` made with [ReserveId].
+    pub reservation_salt: Option<H256>,
+}
+
+/// Reserves an ID for the author without disclosing it.
+///
+/// The author commits to the ID with `commitment`, the [crate::reservation_commitment] of the
+/// ID, the author, and a secret salt. The ID is revealed when the author registers an org or a
+/// user with it and passes the salt as `reservation_salt`. The reservation is consumed by the
+/// registration.
+///
+/// Registrations that reveal an active reservation of their author take precedence over all
+/// other transactions in the transaction pool. An ID cannot be claimed by another account that
+/// sees the registration in the pool unless a block author orders the transactions of their own
+/// block otherwise.
+///
+/// The reservation expires after a fixed number of blocks.
+///
+/// # State changes
+///
+/// If successful, a new [crate::state::Reservations1Data] holding the block number at which the
+/// reservation expires is added to the state for the author and the commitment.
+///
+/// A deposit is reserved from the author's balance. The deposit is returned when the
+/// reservation is cancelled, consumed, or expires.
+///
+/// # State-dependent validations
+///
+/// The author must not hold an active reservation with the same commitment.
+///
+/// The author must have enough free funds for the deposit.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct ReserveId {
+    pub commitment: H256,
+}
+
+/// Cancels a reservation made with [ReserveId].
+///
+/// # State changes
+///
+/// If successful, the [crate::state::Reservations1Data] for the author and the commitment is
+/// removed from the state and the deposit is returned to the author.
+///
+/// # State-dependent validations
+///
+/// The author must hold an active reservation with the commitment.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct CancelReservation {
+    pub commitment: H256,
 }
 
 /// Unregisters a user on the Radicle Registry with the given ID.
@@ -113,6 +179,8 @@ pub struct UnregisterUser {
 ///
 /// The `user_id` must not already be a member of the org.
 ///
+/// The org must have fewer than `MAX_ORG_MEMBERS` members, a limit defined by the runtime.
+///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
 pub struct RegisterMember {
     // The member to register, unique in the org.
@@ -122,6 +190,28 @@ pub struct RegisterMember {
     pub org_id: Id,
 }
 
+/// Remove the user associated with the author from the members of an org.
+///
+/// # State changes
+///
+/// If successful, the user associated with the author is removed from
+/// [crate::state::Orgs1Data::members] of `org_id`.
+///
+/// # State-dependent validations
+///
+/// The identified org must exist.
+///
+/// A user associated with the author must exist.
+///
+/// The user associated with the author must be a member of the identified org.
+///
+/// The user associated with the author must not be the last member of the org.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct LeaveOrg {
+    pub org_id: Id,
+}
+
 /// Register a project on the Radicle Registry with the given ID.
 ///
 /// # State changes
@@ -129,17 +219,23 @@ pub struct RegisterMember {
 /// If successful, a new [crate::state::Projects1Data] with the given
 /// properties is added to the state.
 ///
+/// If project moderation is enabled in the genesis config of the chain, a new
+/// [crate::state::PendingProjects1Data] is added to the state instead. The project is
+/// registered when it is accepted with [AcceptProject].
 ///
 /// # State-dependent validations
 ///
-/// The involved project domain must exit.
+/// The involved project domain must exit. Otherwise the transaction is not included in a block
+/// and no fee is charged. The transaction pool keeps the transaction until a registration of the
+/// domain is ready so that both can be submitted without waiting for the domain registration to
+/// be included.
 ///
 /// A user associated with the author must exist.
 ///
 /// The user associated with the author must a member of
 /// the involved org, when an org is specified as the project domain.
 ///
-/// A project with the same name must not yet exist in domain.
+/// A project with the same name must not yet exist or be pending in domain.
 ///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
 pub struct RegisterProject {
@@ -153,6 +249,66 @@ pub struct RegisterProject {
     pub metadata: Bytes128,
 }
 
+/// Accept a pending project and register it.
+///
+/// # State changes
+///
+/// If successful, the [crate::state::PendingProjects1Data] is removed from the state and a new
+/// [crate::state::Projects1Data] with its metadata is added to the state.
+///
+/// # State-dependent validations
+///
+/// The message must be dispatched with the root origin, that is through the sudo key.
+///
+/// A pending project with the given name must exist in the domain.
+///
+/// The project domain must still exist.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct AcceptProject {
+    pub project_name: ProjectName,
+    pub project_domain: ProjectDomain,
+}
+
+/// Reject a pending project.
+///
+/// # State changes
+///
+/// If successful, the [crate::state::PendingProjects1Data] is removed from the state.
+///
+/// # State-dependent validations
+///
+/// The message must be dispatched with the root origin, that is through the sudo key.
+///
+/// A pending project with the given name must exist in the domain.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct RejectProject {
+    pub project_name: ProjectName,
+    pub project_domain: ProjectDomain,
+}
+
+/// Unregister a project from the Radicle Registry.
+///
+/// # State changes
+///
+/// If successful, the [crate::state::Projects1Data] is removed from the state and the project
+/// name is removed from the projects of the org or user that is the project domain.
+///
+/// # State-dependent validations
+///
+/// The project must exist in the domain.
+///
+/// If the domain is an org, a user associated with the author must be a member of the org.
+///
+/// If the domain is a user, the author must be the account of that user.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct UnregisterProject {
+    pub project_name: ProjectName,
+    pub project_domain: ProjectDomain,
+}
+
 /// Transfer funds from an org account to an account.
 ///
 /// # State changes
@@ -198,6 +354,49 @@ pub struct Transfer {
     pub amount: Balance,
 }
 
+/// Mint `amount` of funds and credit them to the `recipient` account.
+///
+/// The message is submitted as an unsigned transaction and is only
+/// available on test chains that enable the faucet in their genesis
+/// config.
+///
+/// # State changes
+///
+/// If successful, `amount` is added to the recipient account. If the
+/// recipient account did not exist before, it is created.
+///
+/// # State-dependent validations
+///
+/// The faucet must be enabled in the genesis config of the chain.
+///
+/// `amount` must not exceed the faucet limit and the recipient must
+/// not have received faucet funds in the last faucet period.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct Faucet {
+    pub recipient: AccountId,
+    pub amount: Balance,
+}
+
+/// Transfer funds from the author account to multiple recipients.
+///
+/// # State changes
+///
+/// If successful, each amount is deducted from the author account and added to the account of
+/// its recipient. If one of the transfers fails, none of the transfers is applied.
+///
+/// # State-dependent validations
+///
+/// The author account must have a balance of at least the sum of all amounts.
+///
+/// The number of transfers must not exceed the limit of the runtime.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct MultiTransfer {
+    /// Recipients and the amounts they receive. The transfers are performed in order.
+    pub transfers: Vec<(AccountId, Balance)>,
+}
+
 /// Attempts to update the on-chain runtime with the new given one.
 /// The `code` must be a valid WASM module and adhere to the substrate runtime API.
 ///
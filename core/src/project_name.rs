@@ -21,7 +21,10 @@ use alloc::string::{String, ToString};
 use core::convert::{From, Into, TryFrom};
 use parity_scale_codec as codec;
 
-#[derive(codec::Encode, Clone, Debug, Eq, PartialEq)]
+/// The name of a project.
+///
+/// Project names are ordered lexicographically by their string representation.
+#[derive(codec::Encode, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 #[cfg_attr(feature = "std", serde(try_from = "String"))]
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub struct ProjectName(String);
@@ -18,7 +18,7 @@
 use alloc::vec::Vec;
 use parity_scale_codec::{Decode, Encode};
 
-use crate::{AccountId, Balance, Bytes128, Id, ProjectName};
+use crate::{AccountId, Balance, BlockNumber, Bytes128, Id, ProjectName};
 
 /// Projects are stored as a map with the key derived from a given [crate::ProjectId].
 /// The project ID can be extracted from the storage key.
@@ -26,6 +26,7 @@ use crate::{AccountId, Balance, Bytes128, Id, ProjectName};
 /// # Relevant messages
 ///
 /// * [crate::message::RegisterProject]
+/// * [crate::message::UnregisterProject]
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
 pub enum Projects1Data {
     V1(ProjectV1),
@@ -54,6 +55,68 @@ pub struct ProjectV1 {
     pub metadata: Bytes128,
 }
 
+/// Projects waiting to be accepted or rejected when project moderation is enabled.
+///
+/// Pending projects are stored as a map with the key derived from a given [crate::ProjectId].
+/// The project ID can be extracted from the storage key.
+///
+/// # Relevant messages
+///
+/// * [crate::message::RegisterProject]
+/// * [crate::message::AcceptProject]
+/// * [crate::message::RejectProject]
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub enum PendingProjects1Data {
+    V1(PendingProjectV1),
+}
+
+impl PendingProjects1Data {
+    /// Creates new instance in the most up to date version
+    pub fn new(metadata: Bytes128, account_id: AccountId, submitted_at: BlockNumber) -> Self {
+        Self::V1(PendingProjectV1 {
+            metadata,
+            account_id,
+            submitted_at,
+        })
+    }
+
+    /// Opaque metadata that is controlled by the App.
+    pub fn metadata(&self) -> &Bytes128 {
+        match self {
+            Self::V1(project) => &project.metadata,
+        }
+    }
+
+    /// Account that submitted the project.
+    pub fn account_id(&self) -> AccountId {
+        match self {
+            Self::V1(project) => project.account_id,
+        }
+    }
+
+    /// Number of the block in which the project was submitted.
+    pub fn submitted_at(&self) -> BlockNumber {
+        match self {
+            Self::V1(project) => project.submitted_at,
+        }
+    }
+}
+
+/// # Invariants
+///
+/// * `metadata` is immutable
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct PendingProjectV1 {
+    /// Opaque metadata that is controlled by the DApp.
+    pub metadata: Bytes128,
+
+    /// Account that submitted the project.
+    pub account_id: AccountId,
+
+    /// Number of the block in which the project was submitted.
+    pub submitted_at: BlockNumber,
+}
+
 /// Balance associated with an [crate::AccountId].
 ///
 /// See the [Balances Pallet](https://substrate.dev/rustdocs/master/pallet_balances/index.html) for
@@ -88,18 +151,27 @@ pub type AccountTransactionIndex = u32;
 ///
 /// * [crate::message::RegisterOrg]
 /// * [crate::message::UnregisterOrg]
+/// * [crate::message::RegisterMember]
+/// * [crate::message::LeaveOrg]
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
 pub enum Orgs1Data {
     V1(OrgV1),
+    V2(OrgV2),
 }
 
 impl Orgs1Data {
     /// Creates new instance in the most up to date version
-    pub fn new(account_id: AccountId, members: Vec<Id>, projects: Vec<ProjectName>) -> Self {
-        Self::V1(OrgV1 {
+    pub fn new(
+        account_id: AccountId,
+        members: Vec<Id>,
+        projects: Vec<ProjectName>,
+        registered_at: BlockNumber,
+    ) -> Self {
+        Self::V2(OrgV2 {
             account_id,
             members,
             projects,
+            registered_at,
         })
     }
 
@@ -110,6 +182,7 @@ impl Orgs1Data {
     pub fn account_id(&self) -> AccountId {
         match self {
             Self::V1(org) => org.account_id,
+            Self::V2(org) => org.account_id,
         }
     }
 
@@ -122,6 +195,7 @@ impl Orgs1Data {
     pub fn members(&self) -> &Vec<Id> {
         match self {
             Self::V1(org) => &org.members,
+            Self::V2(org) => &org.members,
         }
     }
 
@@ -130,6 +204,17 @@ impl Orgs1Data {
     pub fn projects(&self) -> &Vec<ProjectName> {
         match self {
             Self::V1(org) => &org.projects,
+            Self::V2(org) => &org.projects,
+        }
+    }
+
+    /// Number of the block in which the org was registered.
+    ///
+    /// Orgs registered before this was recorded report block `0`.
+    pub fn registered_at(&self) -> BlockNumber {
+        match self {
+            Self::V1(_) => 0,
+            Self::V2(org) => org.registered_at,
         }
     }
 
@@ -139,6 +224,17 @@ impl Orgs1Data {
     pub fn add_project(self, project_name: ProjectName) -> Self {
         match self {
             Self::V1(org) => Self::V1(org.add_project(project_name)),
+            Self::V2(org) => Self::V2(org.add_project(project_name)),
+        }
+    }
+
+    /// Remove the given project from the list of [Orgs1Data::projects].
+    /// Return a new Org without the project or the same org if the
+    /// org does not own that project.
+    pub fn remove_project(self, project_name: &ProjectName) -> Self {
+        match self {
+            Self::V1(org) => Self::V1(org.remove_project(project_name)),
+            Self::V2(org) => Self::V2(org.remove_project(project_name)),
         }
     }
 
@@ -148,6 +244,17 @@ impl Orgs1Data {
     pub fn add_member(self, user_id: Id) -> Self {
         match self {
             Self::V1(org) => Self::V1(org.add_member(user_id)),
+            Self::V2(org) => Self::V2(org.add_member(user_id)),
+        }
+    }
+
+    /// Remove the given user from the list of [Orgs1Data::members].
+    /// Return a new Org without the member or the same org if the
+    /// user is not a member.
+    pub fn remove_member(self, user_id: &Id) -> Self {
+        match self {
+            Self::V1(org) => Self::V1(org.remove_member(user_id)),
+            Self::V2(org) => Self::V2(org.remove_member(user_id)),
         }
     }
 }
@@ -188,6 +295,14 @@ impl OrgV1 {
         self
     }
 
+    /// Remove the given project from the list of [OrgV1::projects].
+    /// Return a new Org without the project or the same org if the
+    /// org does not own that project.
+    pub fn remove_project(mut self, project_name: &ProjectName) -> Self {
+        self.projects.retain(|project| project != project_name);
+        self
+    }
+
     /// Add the given user to the list of [OrgV1::members].
     /// Return a new Org with the new member included or the
     /// same org if the org already contains that member.
@@ -197,6 +312,80 @@ impl OrgV1 {
         }
         self
     }
+
+    /// Remove the given user from the list of [OrgV1::members].
+    /// Return a new Org without the member or the same org if the
+    /// user is not a member.
+    pub fn remove_member(mut self, user_id: &Id) -> Self {
+        self.members.retain(|member| member != user_id);
+        self
+    }
+}
+
+/// # Invariants
+///
+/// * `account_id` is immutable
+/// * `projects` is a set of all the projects owned by the Org.
+/// * `registered_at` is immutable
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct OrgV2 {
+    /// Account ID that holds the org funds.
+    ///
+    /// It is randomly generated and, unlike for other accounts,
+    /// there is no private key that controls this account.
+    pub account_id: AccountId,
+
+    /// Set of members of the org. Members are allowed to manage
+    /// the org, its projects, and transfer funds.
+    ///
+    /// It is initialized with the user id associated with the author
+    /// of the [crate::message::RegisterOrg] transaction.
+    pub members: Vec<Id>,
+
+    /// Set of all projects owned by the org. Members are allowed to register
+    /// a project by sending a [crate::message::RegisterProject] transaction.
+    pub projects: Vec<ProjectName>,
+
+    /// Number of the block in which the org was registered.
+    pub registered_at: BlockNumber,
+}
+
+impl OrgV2 {
+    /// Add the given project to the list of [OrgV2::projects].
+    /// Return a new Org with the new project included or the
+    /// same org if the org already contains that project.
+    pub fn add_project(mut self, project_name: ProjectName) -> Self {
+        if !self.projects.contains(&project_name) {
+            self.projects.push(project_name);
+        }
+        self
+    }
+
+    /// Remove the given project from the list of [OrgV2::projects].
+    /// Return a new Org without the project or the same org if the
+    /// org does not own that project.
+    pub fn remove_project(mut self, project_name: &ProjectName) -> Self {
+        self.projects.retain(|project| project != project_name);
+        self
+    }
+
+    /// Add the given user to the list of [OrgV2::members].
+    /// Return a new Org with the new member included or the
+    /// same org if the org already contains that member.
+    pub fn add_member(mut self, user_id: Id) -> Self {
+        if !self.members.contains(&user_id) {
+            self.members.push(user_id);
+        }
+        self
+    }
+
+    /// Remove the given user from the list of [OrgV2::members].
+    /// Return a new Org without the member or the same org if the
+    /// user is not a member.
+    pub fn remove_member(mut self, user_id: &Id) -> Self {
+        self.members.retain(|member| member != user_id);
+        self
+    }
 }
 
 /// Users are stored as a map with the key derived from [crate::Id].
@@ -209,14 +398,20 @@ impl OrgV1 {
 #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
 pub enum Users1Data {
     V1(UserV1),
+    V2(UserV2),
 }
 
 impl Users1Data {
     /// Creates new instance in the most up to date version
-    pub fn new(account_id: AccountId, projects: Vec<ProjectName>) -> Self {
-        Self::V1(UserV1 {
+    pub fn new(
+        account_id: AccountId,
+        projects: Vec<ProjectName>,
+        registered_at: BlockNumber,
+    ) -> Self {
+        Self::V2(UserV2 {
             account_id,
             projects,
+            registered_at,
         })
     }
 
@@ -224,6 +419,7 @@ impl Users1Data {
     pub fn account_id(&self) -> AccountId {
         match self {
             Self::V1(user) => user.account_id,
+            Self::V2(user) => user.account_id,
         }
     }
 
@@ -231,6 +427,17 @@ impl Users1Data {
     pub fn projects(&self) -> &Vec<ProjectName> {
         match self {
             Self::V1(user) => &user.projects,
+            Self::V2(user) => &user.projects,
+        }
+    }
+
+    /// Number of the block in which the user was registered.
+    ///
+    /// Users registered before this was recorded report block `0`.
+    pub fn registered_at(&self) -> BlockNumber {
+        match self {
+            Self::V1(_) => 0,
+            Self::V2(user) => user.registered_at,
         }
     }
 
@@ -240,6 +447,17 @@ impl Users1Data {
     pub fn add_project(self, project_name: ProjectName) -> Self {
         match self {
             Self::V1(user) => Self::V1(user.add_project(project_name)),
+            Self::V2(user) => Self::V2(user.add_project(project_name)),
+        }
+    }
+
+    /// Remove the given project from the list of [Users1Data::projects].
+    /// Return a new User without the project or the same user if the
+    /// user does not own that project.
+    pub fn remove_project(self, project_name: &ProjectName) -> Self {
+        match self {
+            Self::V1(user) => Self::V1(user.remove_project(project_name)),
+            Self::V2(user) => Self::V2(user.remove_project(project_name)),
         }
     }
 }
@@ -267,4 +485,119 @@ impl UserV1 {
         }
         self
     }
+
+    /// Remove the given project from the list of [UserV1::projects].
+    /// Return a new User without the project or the same user if the
+    /// user does not own that project.
+    pub fn remove_project(mut self, project_name: &ProjectName) -> Self {
+        self.projects.retain(|project| project != project_name);
+        self
+    }
+}
+
+/// # Invariants
+///
+/// * `account_id` is immutable
+/// * `projects` is a set of all the projects owned by the User.
+/// * `registered_at` is immutable
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub struct UserV2 {
+    /// Account ID that holds the user funds.
+    pub account_id: AccountId,
+
+    /// Set of all projects owned by the user.
+    pub projects: Vec<ProjectName>,
+
+    /// Number of the block in which the user was registered.
+    pub registered_at: BlockNumber,
+}
+
+impl UserV2 {
+    /// Add the given project to the list of [UserV2::projects].
+    /// Return a new User with the new project included or the
+    /// same user if the user already owns that project.
+    pub fn add_project(mut self, project_name: ProjectName) -> Self {
+        if !self.projects.contains(&project_name) {
+            self.projects.push(project_name);
+        }
+        self
+    }
+
+    /// Remove the given project from the list of [UserV2::projects].
+    /// Return a new User without the project or the same user if the
+    /// user does not own that project.
+    pub fn remove_project(mut self, project_name: &ProjectName) -> Self {
+        self.projects.retain(|project| project != project_name);
+        self
+    }
+}
+
+/// # Storage
+///
+/// Reservations are stored as a double map keyed by the account that holds the reservation and
+/// the commitment [crate::reservation_commitment] of the reserved [crate::Id]. Both can be
+/// extracted from the storage key.
+///
+/// # Relevant messages
+///
+/// * [crate::message::ReserveId]
+/// * [crate::message::CancelReservation]
+/// * [crate::message::RegisterOrg]
+/// * [crate::message::RegisterUser]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub enum Reservations1Data {
+    V1(ReservationV1),
+}
+
+impl Reservations1Data {
+    /// Creates new instance in the most up to date version
+    pub fn new(expires_at: BlockNumber) -> Self {
+        Self::V1(ReservationV1 { expires_at })
+    }
+
+    /// Number of the first block in which the reservation is no longer valid.
+    pub fn expires_at(&self) -> BlockNumber {
+        match self {
+            Self::V1(reservation) => reservation.expires_at,
+        }
+    }
+}
+
+/// # Invariants
+///
+/// * All fields are immutable
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub struct ReservationV1 {
+    /// Number of the first block in which the reservation is no longer valid.
+    pub expires_at: BlockNumber,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn v1_entities_are_registered_at_genesis() {
+        let account_id = AccountId::from_raw([1; 32]);
+        let org = Orgs1Data::V1(OrgV1 {
+            account_id,
+            members: Vec::new(),
+            projects: Vec::new(),
+        });
+        let user = Users1Data::V1(UserV1 {
+            account_id,
+            projects: Vec::new(),
+        });
+        assert_eq!(org.registered_at(), 0);
+        assert_eq!(user.registered_at(), 0);
+    }
+
+    #[test]
+    fn new_entities_keep_registration_block() {
+        let account_id = AccountId::from_raw([1; 32]);
+        let org = Orgs1Data::new(account_id, Vec::new(), Vec::new(), 42);
+        let user = Users1Data::new(account_id, Vec::new(), 42);
+        assert_eq!(org.registered_at(), 42);
+        assert_eq!(user.registered_at(), 42);
+    }
 }
@@ -31,15 +31,30 @@ const LATEST_RUNTIME_WASM: &[u8] = include_bytes!("../../runtime-cache/latest.wa
 ///
 /// If `runtime` is given, it is used as the genesis runtime. Uses dummy PoW that does not eat up
 /// your CPU.
-pub fn dev() -> ChainSpec {
+///
+/// `endowments` are added to the default genesis balances. If an account is already endowed its
+/// balance is replaced.
+pub fn dev(endowments: Vec<(AccountId, Balance)>) -> ChainSpec {
+    let mut balances = dev_balances();
+    for (account_id, balance) in endowments {
+        match balances.iter_mut().find(|(id, _)| *id == account_id) {
+            Some(entry) => entry.1 = balance,
+            None => balances.push((account_id, balance)),
+        }
+    }
+
     ChainParams {
         id: String::from("dev"),
         chain_type: ChainType::Development,
         boot_nodes: vec![],
         pow_alg: PowAlgConfig::Dummy,
         runtime: LATEST_RUNTIME_WASM.to_owned(),
-        balances: dev_balances(),
+        balances,
         sudo_key: account_id("Alice"),
+        deterministic_org_accounts: false,
+        faucet_enabled: true,
+        project_moderation: false,
+        burn_fees: false,
     }
     .into_chain_spec()
 }
@@ -59,6 +74,10 @@ pub fn devnet() -> ChainSpec {
         runtime: LATEST_RUNTIME_WASM.to_owned(),
         balances: dev_balances(),
         sudo_key: account_id("Alice"),
+        deterministic_org_accounts: false,
+        faucet_enabled: true,
+        project_moderation: false,
+        burn_fees: true,
     }
     .into_chain_spec()
 }
@@ -76,6 +95,10 @@ pub fn local_devnet() -> ChainSpec {
         runtime: LATEST_RUNTIME_WASM.to_owned(),
         balances: dev_balances(),
         sudo_key: account_id("Alice"),
+        deterministic_org_accounts: false,
+        faucet_enabled: true,
+        project_moderation: false,
+        burn_fees: false,
     }
     .into_chain_spec()
 }
@@ -100,6 +123,16 @@ struct ChainParams {
     runtime: Vec<u8>,
     balances: Vec<(AccountId, Balance)>,
     sudo_key: AccountId,
+    /// Derive the accounts of newly registered orgs from the org ID instead of choosing them at
+    /// random.
+    deterministic_org_accounts: bool,
+    /// Allow anybody to mint funds with unsigned faucet transactions.
+    faucet_enabled: bool,
+    /// Require the sudo key to accept newly registered projects.
+    project_moderation: bool,
+    /// Burn [radicle_registry_runtime::fees::BURN_SHARE] of every transaction fee. If false the
+    /// block author receives the whole fee.
+    burn_fees: bool,
 }
 
 impl ChainParams {
@@ -112,6 +145,10 @@ impl ChainParams {
             runtime,
             balances,
             sudo_key,
+            deterministic_org_accounts,
+            faucet_enabled,
+            project_moderation,
+            burn_fees,
         } = self;
         let make_genesis_config = move || genesis::GenesisConfig {
             system: Some(genesis::SystemConfig {
@@ -122,6 +159,12 @@ impl ChainParams {
                 balances: balances.clone(),
             }),
             pallet_sudo: Some(genesis::SudoConfig { key: sudo_key }),
+            registry: Some(genesis::RegistryConfig {
+                deterministic_org_accounts,
+                faucet_enabled,
+                project_moderation,
+                burn_fees,
+            }),
         };
         GenericChainSpec::from_genesis(
             &id,
@@ -14,10 +14,11 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 //! Provides [Cli] struct that represents the command line arguments.
-use radicle_registry_runtime::AccountId;
+use radicle_registry_runtime::{AccountId, Balance};
 use sc_cli::{RunCmd, Subcommand, SubstrateCli};
 use sc_network::config::MultiaddrWithPeerId;
 use sc_service::{ChainSpec, Configuration};
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::sync::Arc;
 use structopt::StructOpt;
@@ -83,6 +84,12 @@ pub struct Cli {
     #[structopt(long, value_name = "SS58_ADDRESS", parse(try_from_str = parse_ss58_account_id))]
     mine: Option<AccountId>,
 
+    /// Number of threads to use for mining. Defaults to the number of CPU cores.
+    ///
+    /// Only has an effect if mining is enabled and the chain uses the Blake3 PoW algorithm.
+    #[structopt(long, value_name = "COUNT")]
+    mining_threads: Option<NonZeroUsize>,
+
     /// Bind the prometheus metrics endpoint to 0.0.0.0 on port 9615
     #[structopt(long)]
     prometheus_external: bool,
@@ -102,6 +109,38 @@ pub struct Cli {
     /// Run the dev chain with an in-memory database and mining
     #[structopt(long, conflicts_with = "chain")]
     dev: bool,
+
+    /// Endow an account at genesis with the given balance in μRAD. Can be repeated.
+    ///
+    /// Only valid for the `dev` chain.
+    #[structopt(
+        long,
+        value_name = "SS58_ADDRESS:AMOUNT",
+        number_of_values = 1,
+        parse(try_from_str = parse_dev_endowment)
+    )]
+    dev_endow: Vec<(AccountId, Balance)>,
+
+    /// Maximum number of transactions in the ready queue of the transaction pool.
+    ///
+    /// Transactions that exceed the limit are rejected. The queue of transactions that are not
+    /// ready yet is limited to a tenth of the value. Defaults to 8192.
+    #[structopt(long, value_name = "COUNT")]
+    pool_limit: Option<usize>,
+
+    /// Maximum size in kilobytes of the transactions in the ready queue of the transaction pool.
+    ///
+    /// Transactions that exceed the limit are rejected. The queue of transactions that are not
+    /// ready yet is limited to a tenth of the value. Defaults to 20480.
+    #[structopt(long, value_name = "KBYTES")]
+    pool_kbytes: Option<usize>,
+
+    /// Keep the state of all blocks instead of pruning the state of old blocks.
+    ///
+    /// Required to query the state of historical blocks. The pruning mode of a database cannot be
+    /// changed after it has been created.
+    #[structopt(long)]
+    archive: bool,
 }
 
 impl SubstrateCli for Cli {
@@ -135,11 +174,17 @@ impl SubstrateCli for Cli {
     }
 
     fn load_spec(&self, id: &str) -> Result<Box<dyn ChainSpec>, String> {
+        if !self.dev_endow.is_empty() && (self.spec.is_some() || id != "dev") {
+            return Err(String::from(
+                "--dev-endow can only be used with the dev chain",
+            ));
+        }
+
         if let Some(spec_path) = &self.spec {
             crate::chain_spec::from_spec_file(spec_path.clone())
         } else {
             match id {
-                "dev" => Ok(crate::chain_spec::dev()),
+                "dev" => Ok(crate::chain_spec::dev(self.dev_endow.clone())),
                 "local-devnet" => Ok(crate::chain_spec::local_devnet()),
                 "devnet" => Ok(crate::chain_spec::devnet()),
                 "ffnet" => Ok(crate::chain_spec::ffnet()),
@@ -173,9 +218,19 @@ impl Cli {
                     // We leave this call here so that the type checker can properly infer the type
                     // of this closure.
                     #[allow(unreachable_code)]
-                    service::new_full(self.adjust_config(_config), self.block_author())
+                    service::new_full(
+                        self.adjust_config(_config),
+                        self.block_author(),
+                        self.mining_threads(),
+                    )
+                },
+                |config| {
+                    service::new_full(
+                        self.adjust_config(config),
+                        self.block_author(),
+                        self.mining_threads(),
+                    )
                 },
-                |config| service::new_full(self.adjust_config(config), self.block_author()),
                 radicle_registry_runtime::VERSION,
             ),
         }
@@ -193,6 +248,12 @@ impl Cli {
         }
     }
 
+    fn mining_threads(&self) -> NonZeroUsize {
+        self.mining_threads.unwrap_or_else(|| {
+            NonZeroUsize::new(num_cpus::get()).expect("num_cpus::get() is at least 1")
+        })
+    }
+
     fn create_run_cmd(&self) -> RunCmd {
         // This does not panic if there are no required arguments which we statically know.
         let mut run_cmd = RunCmd::from_iter_safe(vec![] as Vec<String>).unwrap();
@@ -210,6 +271,13 @@ impl Cli {
         run_cmd.unsafe_ws_external = self.unsafe_rpc_external;
         run_cmd.prometheus_external = self.prometheus_external;
         run_cmd.name = self.name.clone();
+        // Substrate derives the limits of the ready and the future queue from these values.
+        if let Some(pool_limit) = self.pool_limit {
+            run_cmd.pool_config.pool_limit = pool_limit;
+        }
+        if let Some(pool_kbytes) = self.pool_kbytes {
+            run_cmd.pool_config.pool_kbytes = pool_kbytes;
+        }
         run_cmd
     }
 
@@ -249,6 +317,10 @@ impl Cli {
             config.network.boot_nodes = vec![];
         }
 
+        if self.archive {
+            config.pruning = sc_service::config::PruningMode::ArchiveAll;
+        }
+
         if self.unsafe_rpc_external {
             config.rpc_cors = None;
         }
@@ -259,3 +331,14 @@ impl Cli {
 fn parse_ss58_account_id(data: &str) -> Result<AccountId, String> {
     sp_core::crypto::Ss58Codec::from_ss58check(data).map_err(|err| format!("{:?}", err))
 }
+
+fn parse_dev_endowment(data: &str) -> Result<(AccountId, Balance), String> {
+    let mut parts = data.splitn(2, ':');
+    let account_id = parse_ss58_account_id(parts.next().unwrap_or_default())?;
+    let balance = parts
+        .next()
+        .ok_or_else(|| String::from("Expected <SS58_ADDRESS>:<AMOUNT>"))?
+        .parse()
+        .map_err(|err| format!("Invalid amount: {}", err))?;
+    Ok((account_id, balance))
+}
@@ -23,6 +23,7 @@ mod cli;
 mod logger;
 mod metrics;
 mod pow;
+mod rpc;
 mod service;
 
 use crate::cli::Cli;
@@ -33,8 +33,9 @@ use sp_api::ProvideRuntimeApi;
 use sp_consensus_pow::Seal;
 use sp_core::{H256, U256};
 use sp_runtime::traits::Header as _;
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
 
 type BlockId = sp_runtime::generic::BlockId<Block>;
 type Result<T> = std::result::Result<T, Error<Block>>;
@@ -55,6 +56,7 @@ const TARGET_WINDOW_TIME_MS: u64 = ADJUST_DIFFICULTY_WINDOW_SIZE * TARGET_BLOCK_
 pub struct Blake3Pow<C> {
     client: C,
     next_nonce: Arc<AtomicU64>,
+    mining_pool: Arc<Mutex<Option<MiningPool>>>,
 }
 
 impl<C> Blake3Pow<C> {
@@ -66,19 +68,29 @@ impl<C> Blake3Pow<C> {
     /// Creates Blake3Pow with the specific seed for generating nonces
     pub fn new_with_seed(client: C, nonce_seed: u64) -> Self {
         let next_nonce = Arc::new(AtomicU64::new(nonce_seed));
-        Blake3Pow { client, next_nonce }
+        Blake3Pow {
+            client,
+            next_nonce,
+            mining_pool: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Spawns `mining_threads` threads that check nonces in parallel when mining. The threads are
+    /// reused for every mining round.
+    ///
+    /// If this is not called a single thread is spawned for the first mining round.
+    pub fn with_mining_threads(self, mining_threads: NonZeroUsize) -> Self {
+        Blake3Pow {
+            mining_pool: Arc::new(Mutex::new(Some(MiningPool::spawn(mining_threads)))),
+            ..self
+        }
     }
 
-    fn nonces_for_mining_round(&self) -> impl Iterator<Item = [u8; 8]> {
-        let first_nonce = self
-            .next_nonce
+    /// Reserves the nonces for a mining round and returns the first one.
+    fn next_mining_round_first_nonce(&self) -> u64 {
+        self.next_nonce
             // fetch_add wraps on overflow
-            .fetch_add(NONCES_PER_MINING_ROUND as u64, Ordering::Relaxed);
-        std::iter::successors(Some(first_nonce), |prev_nonce| {
-            Some(prev_nonce.wrapping_add(1))
-        })
-        .take(NONCES_PER_MINING_ROUND)
-        .map(u64::to_ne_bytes)
+            .fetch_add(NONCES_PER_MINING_ROUND as u64, Ordering::Relaxed)
     }
 }
 
@@ -124,13 +136,17 @@ where
         difficulty: Self::Difficulty,
         _round: u32,
     ) -> Result<Option<Seal>> {
-        let mut verifier = NonceVerifier::new(pre_hash, difficulty);
-        for nonce in self.nonces_for_mining_round() {
-            if verifier.is_nonce_valid(&nonce) {
-                return Ok(Some(nonce.to_vec()));
-            }
-        }
-        Ok(None)
+        let mut mining_pool = self
+            .mining_pool
+            .lock()
+            .expect("Mining pool lock is poisoned");
+        let mining_pool =
+            mining_pool.get_or_insert_with(|| MiningPool::spawn(NonZeroUsize::new(1).unwrap()));
+        let maybe_nonce =
+            mining_pool.find_valid_nonce(*pre_hash, difficulty, NONCES_PER_MINING_ROUND, || {
+                self.next_mining_round_first_nonce()
+            });
+        Ok(maybe_nonce.map(|nonce| nonce.to_vec()))
     }
 }
 
@@ -181,6 +197,105 @@ where
     }
 }
 
+/// Threads that check nonces in parallel for [Blake3Pow::mine].
+///
+/// The threads are spawned once and wait for [MiningJob]s. Every mining round sends one job to
+/// each thread and waits for the results of all threads. The threads stop when the pool is
+/// dropped.
+#[derive(Debug)]
+struct MiningPool {
+    job_senders: Vec<mpsc::Sender<MiningJob>>,
+    result_receiver: mpsc::Receiver<Option<[u8; 8]>>,
+}
+
+impl MiningPool {
+    fn spawn(threads: NonZeroUsize) -> Self {
+        let (result_sender, result_receiver) = mpsc::channel();
+        let job_senders = (0..threads.get())
+            .map(|index| {
+                let (job_sender, job_receiver) = mpsc::channel::<MiningJob>();
+                let result_sender = result_sender.clone();
+                std::thread::Builder::new()
+                    .name(format!("blake3-miner-{}", index))
+                    .spawn(move || {
+                        for job in job_receiver {
+                            if result_sender.send(job.run()).is_err() {
+                                break;
+                            }
+                        }
+                    })
+                    .expect("Failed to spawn mining thread");
+                job_sender
+            })
+            .collect();
+        MiningPool {
+            job_senders,
+            result_receiver,
+        }
+    }
+
+    /// Checks `nonce_count` nonces in every thread and returns the first valid nonce found.
+    ///
+    /// The nonces of each thread start at the value returned by `next_first_nonce`. All threads
+    /// stop as soon as one of them finds a valid nonce.
+    fn find_valid_nonce(
+        &self,
+        pre_hash: Hash,
+        difficulty: Difficulty,
+        nonce_count: usize,
+        mut next_first_nonce: impl FnMut() -> u64,
+    ) -> Option<[u8; 8]> {
+        let found = Arc::new(AtomicBool::new(false));
+        for job_sender in &self.job_senders {
+            let job = MiningJob {
+                pre_hash,
+                difficulty,
+                first_nonce: next_first_nonce(),
+                nonce_count,
+                found: found.clone(),
+            };
+            job_sender.send(job).expect("Mining thread stopped");
+        }
+
+        self.job_senders
+            .iter()
+            .map(|_| self.result_receiver.recv().expect("Mining thread stopped"))
+            .fold(None, Option::or)
+    }
+}
+
+/// Nonces checked by one thread of a [MiningPool] in a mining round.
+#[derive(Debug)]
+struct MiningJob {
+    pre_hash: Hash,
+    difficulty: Difficulty,
+    first_nonce: u64,
+    nonce_count: usize,
+    /// Set by the thread that finds a valid nonce to stop the other threads.
+    found: Arc<AtomicBool>,
+}
+
+impl MiningJob {
+    fn run(self) -> Option<[u8; 8]> {
+        let mut verifier = NonceVerifier::new(&self.pre_hash, self.difficulty);
+        let nonces = std::iter::successors(Some(self.first_nonce), |prev_nonce| {
+            Some(prev_nonce.wrapping_add(1))
+        })
+        .take(self.nonce_count)
+        .map(u64::to_ne_bytes);
+        for nonce in nonces {
+            if self.found.load(Ordering::Relaxed) {
+                return None;
+            }
+            if verifier.is_nonce_valid(&nonce) {
+                self.found.store(true, Ordering::Relaxed);
+                return Some(nonce);
+            }
+        }
+        None
+    }
+}
+
 struct NonceVerifier {
     payload: Vec<u8>,
     threshold: Threshold,
@@ -258,6 +373,39 @@ mod test {
         assert_next_difficulty(67, 10000);
     }
 
+    #[test]
+    fn find_valid_nonce_in_any_batch() {
+        let pre_hash = Hash::repeat_byte(7);
+        let mining_pool = MiningPool::spawn(NonZeroUsize::new(4).unwrap());
+        let mut first_nonces = 0..;
+        let nonce = mining_pool.find_valid_nonce(pre_hash, Difficulty::one(), 1, || {
+            first_nonces.next().unwrap()
+        });
+        assert!(nonce.is_some());
+    }
+
+    #[test]
+    fn find_valid_nonce_none_valid() {
+        let pre_hash = Hash::repeat_byte(7);
+        let mining_pool = MiningPool::spawn(NonZeroUsize::new(4).unwrap());
+        let mut first_nonces = (0..).step_by(10);
+        let nonce = mining_pool.find_valid_nonce(pre_hash, Difficulty::max_value(), 10, || {
+            first_nonces.next().unwrap()
+        });
+        assert_eq!(nonce, None);
+    }
+
+    /// Test that the threads of a mining pool are reused for subsequent mining rounds.
+    #[test]
+    fn find_valid_nonce_reuses_threads() {
+        let pre_hash = Hash::repeat_byte(7);
+        let mining_pool = MiningPool::spawn(NonZeroUsize::new(2).unwrap());
+        for _ in 0..3 {
+            let nonce = mining_pool.find_valid_nonce(pre_hash, Difficulty::one(), 1, || 0);
+            assert_eq!(nonce, Some(0u64.to_ne_bytes()));
+        }
+    }
+
     // assume that the average window difficulty is 100 and the target window time is 100
     fn assert_next_difficulty(expected: u64, time_observed: u64) {
         let adjusted_time_observed = TARGET_WINDOW_TIME_MS * time_observed / 100;
@@ -0,0 +1,71 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC methods that the node provides in addition to the Substrate RPC API.
+
+use jsonrpc_core::{Error as RpcError, IoHandler, Params};
+use sc_client_api::AuxStore;
+use sc_consensus_pow::PowAux;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+use std::sync::Arc;
+
+use crate::blockchain::{Block, Hash};
+use crate::pow::Difficulty;
+
+/// Name of the RPC method that returns the total difficulty of the chain up to and including a
+/// block. Must match the method name used by the client.
+///
+/// The method takes the block hash as its only parameter. It returns `null` if the block is
+/// unknown.
+const TOTAL_DIFFICULTY_METHOD: &str = "pow_totalDifficulty";
+
+/// Create the handler for the custom RPC methods of the node.
+pub fn extensions<C>(client: Arc<C>) -> IoHandler<sc_rpc::Metadata>
+where
+    C: AuxStore + HeaderBackend<Block> + Send + Sync + 'static,
+{
+    let mut io = IoHandler::default();
+    io.add_method(TOTAL_DIFFICULTY_METHOD, move |params: Params| {
+        let (block_hash,): (Hash,) = params.parse()?;
+        let total_difficulty = total_difficulty(&*client, block_hash)?;
+        serde_json::to_value(total_difficulty).map_err(internal_error)
+    });
+    io
+}
+
+/// Read the total difficulty of the chain up to and including the given block that is tracked
+/// by `sc_consensus_pow`. Returns `None` if the block is unknown.
+fn total_difficulty<C>(client: &C, block_hash: Hash) -> Result<Option<Difficulty>, RpcError>
+where
+    C: AuxStore + HeaderBackend<Block>,
+{
+    let header = client
+        .header(BlockId::hash(block_hash))
+        .map_err(internal_error)?;
+    if header.is_none() {
+        return Ok(None);
+    }
+    let pow_aux =
+        PowAux::<Difficulty>::read::<_, Block>(client, &block_hash).map_err(internal_error)?;
+    Ok(Some(pow_aux.total_difficulty))
+}
+
+fn internal_error(error: impl std::fmt::Display) -> RpcError {
+    RpcError {
+        message: error.to_string(),
+        ..RpcError::internal_error()
+    }
+}
@@ -19,6 +19,7 @@
 
 use futures::StreamExt;
 use std::convert::TryFrom;
+use std::num::NonZeroUsize;
 use std::time::Duration;
 
 use sc_client_api::client::BlockchainEvents as _;
@@ -53,6 +54,7 @@ macro_rules! new_full_start {
                     builder.prometheus_registry(),
                 ))
             })?
+            .with_rpc_extensions(|builder| Ok(crate::rpc::extensions(builder.client().clone())))?
             .with_import_queue(
                 |config, client, select_chain, _transaction_pool, spawn_task_handle, _registry| {
                     let (block_import, import_queue) = node_import_queue!(
@@ -140,10 +142,12 @@ macro_rules! node_import_queue_for_pow_alg {
 
 /// Builds a new service for a full client.
 ///
-/// Starts a miner if `opt_block_author` was provided.
+/// Starts a miner if `opt_block_author` was provided. The miner checks nonces with
+/// `mining_threads` threads in parallel.
 pub fn new_full(
     config: Configuration,
     opt_block_author: Option<AccountId>,
+    mining_threads: NonZeroUsize,
 ) -> Result<impl AbstractService, Error> {
     log::info!(
         "Native runtime version: spec={} impl={}",
@@ -184,7 +188,7 @@ pub fn new_full(
             service.prometheus_registry().as_ref(),
         );
 
-        log::info!("Starting block miner");
+        log::info!("Starting block miner with {} threads", mining_threads);
 
         match pow_alg {
             Config::Dummy => start_mine!(
@@ -199,7 +203,7 @@ pub fn new_full(
                 service,
                 proposer,
                 inherent_data_providers,
-                Blake3Pow::new(client)
+                Blake3Pow::new(client).with_mining_threads(mining_threads)
             ),
         }
     } else {
@@ -13,6 +13,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use futures::prelude::*;
 use radicle_registry_client::*;
 use radicle_registry_test_utils::*;
 
@@ -39,3 +40,135 @@ async fn random_account_does_not_exist() {
         "Account was expected to be on chain"
     );
 }
+
+#[async_std::test]
+async fn account_profile() {
+    let (client, _) = Client::new_emulator();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+    let register_project = random_register_project_message(&ProjectDomain::User(user_id.clone()));
+    let project_name = register_project.project_name.clone();
+    let tx_included = submit_ok(&client, &author, register_project).await;
+    assert_result_ok(&tx_included);
+
+    let profile = client.account_profile(author.public()).await.unwrap();
+    assert_eq!(
+        profile.block_hash,
+        client.block_header_best_chain().await.unwrap().hash()
+    );
+    assert_eq!(
+        profile.balance,
+        client.free_balance(&author.public()).await.unwrap()
+    );
+    assert_eq!(
+        profile.nonce,
+        client.account_nonce(&author.public()).await.unwrap()
+    );
+    assert_eq!(profile.user_id, Some(user_id.clone()));
+    assert_eq!(profile.orgs, vec![org_id]);
+    assert_eq!(
+        profile.projects,
+        vec![(project_name, ProjectDomain::User(user_id))]
+    );
+}
+
+#[async_std::test]
+async fn account_profile_without_user() {
+    let (client, _) = Client::new_emulator();
+    let account_id = key_pair_with_funds(&client).await.public();
+
+    let profile = client.account_profile(account_id).await.unwrap();
+    assert_eq!(profile.user_id, None);
+    assert!(profile.orgs.is_empty());
+    assert!(profile.projects.is_empty());
+}
+
+/// Test that [ClientT::subscribe_balance_changes] reports a transfer to the account.
+#[async_std::test]
+async fn subscribe_balance_changes_transfer() {
+    for_each_backend(|client| async move {
+        let author = key_pair_with_funds(&client).await;
+        let recipient = ed25519::Pair::generate().0.public();
+        let mut changes = client.subscribe_balance_changes(recipient).await.unwrap();
+
+        let tx_included = client
+            .sign_and_submit_message(
+                &author,
+                message::Transfer {
+                    recipient,
+                    amount: 1000,
+                },
+                MINIMUM_TX_FEE,
+            )
+            .await
+            .unwrap()
+            .await
+            .unwrap();
+
+        let change = changes.next().await.unwrap().unwrap();
+        assert_eq!(change.block_hash, tx_included.block);
+        assert_eq!(change.previous, 0);
+        assert_eq!(change.current, 1000);
+        assert_eq!(change.transfers, vec![(author.public(), recipient, 1000)]);
+    })
+    .await;
+}
+
+/// Test that [ClientT::get_account] returns the nonce and free balance of an account and the
+/// default for unknown accounts.
+#[async_std::test]
+async fn get_account() {
+    for_each_backend(|client| async move {
+        let author = key_pair_with_funds(&client).await;
+        transfer(&client, &author, ed25519::Pair::generate().0.public(), 1000).await;
+
+        let account = client.get_account(&author.public()).await.unwrap();
+        assert_eq!(account.nonce, 1);
+        assert_eq!(
+            account.free_balance,
+            client.free_balance(&author.public()).await.unwrap()
+        );
+
+        let unknown = client
+            .get_account(&ed25519::Pair::generate().0.public())
+            .await
+            .unwrap();
+        assert_eq!(
+            unknown,
+            Account {
+                nonce: 0,
+                free_balance: 0
+            }
+        );
+    })
+    .await;
+}
+
+/// Test that [ClientT::free_balances] returns the balances in the order of the accounts and zero
+/// for unknown accounts.
+#[async_std::test]
+async fn free_balances() {
+    for_each_backend(|client| async move {
+        let author = key_pair_with_funds(&client).await;
+        let recipient = ed25519::Pair::generate().0.public();
+        let unknown = ed25519::Pair::generate().0.public();
+        transfer(&client, &author, recipient, 1000).await;
+
+        let balances = client
+            .free_balances(&[recipient, unknown, author.public()])
+            .await
+            .unwrap();
+        assert_eq!(
+            balances,
+            vec![
+                (recipient, 1000),
+                (unknown, 0),
+                (
+                    author.public(),
+                    client.free_balance(&author.public()).await.unwrap()
+                ),
+            ]
+        );
+    })
+    .await;
+}
@@ -0,0 +1,227 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern batches of messages submitted with [ClientT::submit_batch].
+use radicle_registry_client::*;
+use radicle_registry_runtime::event;
+use radicle_registry_test_utils::*;
+
+async fn submit_batch_ok(
+    client: &Client,
+    author: &ed25519::Pair,
+    messages: Vec<Box<dyn Message>>,
+    fee: Balance,
+) -> TransactionIncluded {
+    client
+        .submit_batch(author, messages, fee)
+        .await
+        .unwrap()
+        .await
+        .unwrap()
+}
+
+fn batch_error(failed_index: u32, error: RegistryError) -> TransactionError {
+    TransactionError::BatchInterrupted(BatchError {
+        failed_index,
+        dispatch_error: error.into(),
+    })
+}
+
+#[async_std::test]
+async fn batch_register_org_and_project() {
+    let (client, _) = Client::new_emulator();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+
+    let register_org = random_register_org_message();
+    let project_domain = ProjectDomain::Org(register_org.org_id.clone());
+    let register_project = random_register_project_message(&project_domain);
+    let tx_included = submit_batch_ok(
+        &client,
+        &author,
+        vec![
+            Box::new(register_org.clone()),
+            Box::new(register_project.clone()),
+        ],
+        random_balance(),
+    )
+    .await;
+    assert_result_ok(&tx_included);
+
+    let org = client
+        .registry()
+        .orgs()
+        .get(register_org.org_id.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(org.members(), &vec![user_id]);
+    assert_eq!(org.projects(), &vec![register_project.project_name.clone()]);
+    assert!(client
+        .registry()
+        .projects()
+        .get(register_project.project_name, project_domain)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+/// Test that the changes of all messages in a batch are reverted if one message fails and that
+/// the fee is only charged once.
+#[async_std::test]
+async fn batch_failure_reverts_all_messages() {
+    let (client, _) = Client::new_emulator();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+    let bob = ed25519::Pair::generate().0.public();
+
+    let initial_balance = client.free_balance(&author.public()).await.unwrap();
+    let fee = random_balance();
+    let register_org = random_register_org_message();
+    let tx_included = submit_batch_ok(
+        &client,
+        &author,
+        vec![
+            Box::new(register_org.clone()),
+            Box::new(message::Transfer {
+                recipient: bob,
+                amount: 1000,
+            }),
+            Box::new(message::RegisterOrg {
+                org_id: user_id,
+                reservation_salt: None,
+            }),
+        ],
+        fee,
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(batch_error(2, RegistryError::IdAlreadyTaken))
+    );
+
+    assert!(client
+        .registry()
+        .orgs()
+        .get(register_org.org_id)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(client.free_balance(&bob).await.unwrap(), 0);
+    assert_eq!(
+        client.free_balance(&author.public()).await.unwrap(),
+        initial_balance - fee,
+        "The tx fee was not charged properly."
+    );
+}
+
+/// Test that the index of the failed call is reported if the second of three calls fails and
+/// that the changes of the first call are reverted.
+#[async_std::test]
+async fn batch_second_call_fails() {
+    let (client, author) = emulator_with_funded_author().await;
+    let bob = ed25519::Pair::generate().0.public();
+
+    let register_org = random_register_org_message();
+    let tx_included = submit_batch_ok(
+        &client,
+        &author,
+        vec![
+            Box::new(message::Transfer {
+                recipient: bob,
+                amount: 1000,
+            }),
+            Box::new(register_org.clone()),
+            Box::new(message::Transfer {
+                recipient: bob,
+                amount: 1000,
+            }),
+        ],
+        random_balance(),
+    )
+    .await;
+    // The author has no associated user so the org cannot be registered.
+    assert_eq!(
+        tx_included.result,
+        Err(batch_error(1, RegistryError::AuthorHasNoAssociatedUser))
+    );
+    assert_event_present(
+        &client,
+        &tx_included,
+        Event::registry(event::Registry::BatchInterrupted(
+            1,
+            RegistryError::AuthorHasNoAssociatedUser.into(),
+        )),
+    )
+    .await;
+
+    assert_eq!(client.free_balance(&bob).await.unwrap(), 0);
+    assert!(client
+        .registry()
+        .orgs()
+        .get(register_org.org_id)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[async_std::test]
+async fn batch_nested_batch() {
+    let (client, author) = emulator_with_funded_author().await;
+    let bob = ed25519::Pair::generate().0.public();
+
+    let transfer = message::Transfer {
+        recipient: bob,
+        amount: 1000,
+    };
+    let tx_included = submit_batch_ok(
+        &client,
+        &author,
+        vec![
+            Box::new(transfer.clone()),
+            Box::new(message::Batch::new(vec![Box::new(transfer)])),
+        ],
+        random_balance(),
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(batch_error(1, RegistryError::InvalidBatchCall))
+    );
+    assert_eq!(client.free_balance(&bob).await.unwrap(), 0);
+}
+
+#[async_std::test]
+async fn batch_too_many_calls() {
+    let (client, author) = emulator_with_funded_author().await;
+    let bob = ed25519::Pair::generate().0.public();
+
+    let messages = (0..=MAX_BATCH_CALLS)
+        .map(|_| -> Box<dyn Message> {
+            Box::new(message::Transfer {
+                recipient: bob,
+                amount: 1,
+            })
+        })
+        .collect();
+    let tx_included = submit_batch_ok(&client, &author, messages, random_balance()).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::TooManyBatchCalls.into())
+    );
+    assert_eq!(client.free_balance(&bob).await.unwrap(), 0);
+}
@@ -13,8 +13,9 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use futures::prelude::*;
 use radicle_registry_client::*;
-use radicle_registry_runtime::registry::BLOCK_REWARD;
+use radicle_registry_runtime::{fees::BURN_SHARE, genesis::RegistryConfig, registry::BLOCK_REWARD};
 use radicle_registry_test_utils::*;
 use sp_runtime::Permill;
 
@@ -43,3 +44,170 @@ async fn block_rewards_credited() {
     let fee_reward = Permill::from_percent(99) * fee;
     assert_eq!(rewards, fee_reward + BLOCK_REWARD);
 }
+
+/// Assert that the author of a block sealed with [EmulatorControl::seal_block] receives exactly
+/// the block reward.
+#[async_std::test]
+async fn seal_block_credits_author() {
+    let (client, emulator) = Client::new_emulator();
+    let author = ed25519::Pair::generate().0.public();
+    let initial_issuance = client.total_issuance().await.unwrap();
+    let initial_block_number = client.block_header_best_chain().await.unwrap().number;
+
+    let block_hash = emulator.seal_block(author);
+
+    let header = client.block_header(block_hash).await.unwrap().unwrap();
+    assert_eq!(header.number, initial_block_number + 1);
+    assert_eq!(client.free_balance(&author).await.unwrap(), BLOCK_REWARD);
+    assert_eq!(BLOCK_REWARD, rad_to_balance(20));
+    assert_eq!(
+        client.total_issuance().await.unwrap(),
+        initial_issuance + BLOCK_REWARD
+    );
+}
+
+/// Assert that the block reward is minted and the burned share of the transaction fee is removed
+/// from the total issuance.
+#[async_std::test]
+async fn transaction_fee_burned() {
+    let (client, alice) = emulator_with_funded_author().await;
+    let initial_issuance = client.total_issuance().await.unwrap();
+
+    let fee = 3000;
+    submit_ok_with_fee(
+        &client,
+        &alice,
+        message::Transfer {
+            recipient: ed25519::Pair::generate().0.public(),
+            amount: 1000,
+        },
+        fee,
+    )
+    .await;
+
+    // The emulator creates a block for every transaction.
+    assert_eq!(
+        client.total_issuance().await.unwrap(),
+        initial_issuance + BLOCK_REWARD - BURN_SHARE * fee
+    );
+}
+
+/// Assert that [ClientT::subscribe_block_rewards] yields the rewards credited to the block author
+/// for new blocks.
+#[async_std::test]
+async fn subscribe_block_rewards() {
+    let (client, emulator) = Client::new_emulator();
+    let alice = key_pair_with_funds(&client).await;
+    let mut rewards = client.subscribe_block_rewards().await.unwrap();
+
+    emulator.add_blocks(1);
+    let header = client.block_header_best_chain().await.unwrap();
+    let reward = rewards.next().await.unwrap().unwrap();
+    assert_eq!(reward, (header.number, EMULATOR_BLOCK_AUTHOR, BLOCK_REWARD));
+
+    let fee = 3000;
+    submit_ok_with_fee(
+        &client,
+        &alice,
+        message::Transfer {
+            recipient: ed25519::Pair::generate().0.public(),
+            amount: 1000,
+        },
+        fee,
+    )
+    .await;
+    let header = client.block_header_best_chain().await.unwrap();
+    let reward = rewards.next().await.unwrap().unwrap();
+    let fee_reward = Permill::from_percent(99) * fee;
+    assert_eq!(
+        reward,
+        (
+            header.number,
+            EMULATOR_BLOCK_AUTHOR,
+            BLOCK_REWARD + fee_reward
+        )
+    );
+}
+
+/// Assert that [ClientT::reward_estimate] averages the block rewards and the fee rewards over the
+/// last blocks.
+#[async_std::test]
+async fn reward_estimate() {
+    let (client, emulator) = Client::new_emulator();
+    let alice = key_pair_with_funds(&client).await;
+
+    emulator.add_blocks(1);
+    let fee = 3000;
+    submit_ok_with_fee(
+        &client,
+        &alice,
+        message::Transfer {
+            recipient: ed25519::Pair::generate().0.public(),
+            amount: 1000,
+        },
+        fee,
+    )
+    .await;
+
+    let fee_reward = Permill::from_percent(99) * fee;
+    let estimate = client.reward_estimate(2).await.unwrap();
+    assert_eq!(
+        estimate,
+        RewardEstimate {
+            blocks: 2,
+            reward_per_block: BLOCK_REWARD + fee_reward / 2,
+            fees_per_block: fee_reward / 2,
+        }
+    );
+
+    let estimate = client.reward_estimate(0).await.unwrap();
+    assert_eq!(
+        estimate,
+        RewardEstimate {
+            blocks: 0,
+            reward_per_block: BLOCK_REWARD,
+            fees_per_block: 0,
+        }
+    );
+}
+
+/// Assert that the block author receives the whole transaction fee and that no fee is burned if
+/// burning fees is disabled in the genesis config.
+#[async_std::test]
+async fn transaction_fee_not_burned() {
+    let mut genesis_config = emulator_genesis_config();
+    genesis_config.registry = Some(RegistryConfig {
+        deterministic_org_accounts: false,
+        faucet_enabled: false,
+        project_moderation: false,
+        burn_fees: false,
+    });
+    let (client, _) = Client::new_emulator_with_genesis(genesis_config);
+    let alice = key_pair_with_funds(&client).await;
+    let mut rewards = client.subscribe_block_rewards().await.unwrap();
+    let author_balance = client.free_balance(&EMULATOR_BLOCK_AUTHOR).await.unwrap();
+    let initial_issuance = client.total_issuance().await.unwrap();
+
+    let fee = 3000;
+    submit_ok_with_fee(
+        &client,
+        &alice,
+        message::Transfer {
+            recipient: ed25519::Pair::generate().0.public(),
+            amount: 1000,
+        },
+        fee,
+    )
+    .await;
+
+    assert_eq!(
+        client.free_balance(&EMULATOR_BLOCK_AUTHOR).await.unwrap() - author_balance,
+        BLOCK_REWARD + fee
+    );
+    assert_eq!(
+        client.total_issuance().await.unwrap(),
+        initial_issuance + BLOCK_REWARD
+    );
+    let (_, _, reward) = rewards.next().await.unwrap().unwrap();
+    assert_eq!(reward, BLOCK_REWARD + fee);
+}
@@ -0,0 +1,365 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern queries of the chain and its state with [Client].
+use futures::prelude::*;
+use radicle_registry_client::*;
+use radicle_registry_runtime::event::{self, System};
+use radicle_registry_runtime::genesis::{BalancesConfig, GenesisConfig, SudoConfig};
+use radicle_registry_test_utils::*;
+use sp_core::hexdisplay::HexDisplay;
+
+/// Test that [Client::new_from_genesis_json] emulates a chain with the raw genesis state of a
+/// chain spec.
+#[async_std::test]
+async fn new_from_genesis_json() {
+    let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+    let balance = 1 << 40;
+    let genesis_config = GenesisConfig {
+        pallet_balances: Some(BalancesConfig {
+            balances: vec![(alice.public(), balance)],
+        }),
+        pallet_sudo: None,
+        registry: None,
+        system: None,
+    };
+    let storage = sp_runtime::BuildStorage::build_storage(&genesis_config).unwrap();
+    let top: serde_json::Map<String, serde_json::Value> = storage
+        .top
+        .iter()
+        .map(|(key, value)| {
+            (
+                format!("0x{}", HexDisplay::from(key)),
+                format!("0x{}", HexDisplay::from(value)).into(),
+            )
+        })
+        .collect();
+    let chain_spec = serde_json::json!({ "genesis": { "raw": { "top": top } } });
+
+    let path = std::env::temp_dir().join(format!(
+        "radicle-registry-chain-spec-{}.json",
+        rand::random::<u64>()
+    ));
+    std::fs::write(&path, chain_spec.to_string()).unwrap();
+    let client = Client::new_from_genesis_json(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(client.free_balance(&alice.public()).await.unwrap(), balance);
+}
+
+/// Test that a transaction is expected to be included in the next block if no transactions are
+/// pending.
+#[async_std::test]
+async fn estimate_inclusion_blocks_emulator() {
+    let (client, _) = Client::new_emulator();
+    assert_eq!(
+        client
+            .estimate_inclusion_blocks(MINIMUM_TX_FEE, 100)
+            .await
+            .unwrap(),
+        0
+    );
+}
+
+#[async_std::test]
+async fn ensure_chain_name_emulator() {
+    let (client, _) = Client::new_emulator();
+    assert_eq!(client.chain_name().await.unwrap(), "emulator");
+    client.ensure_chain_name("emulator").await.unwrap();
+    match client.ensure_chain_name("ffnet").await {
+        Err(Error::WrongChain { expected, found }) => {
+            assert_eq!(expected, "ffnet");
+            assert_eq!(found, "emulator");
+        }
+        result => panic!("Unexpected result {:?}", result),
+    }
+}
+
+#[async_std::test]
+async fn block_headers_range() {
+    let (client, emulator) = Client::new_emulator();
+    emulator.add_blocks(5);
+
+    let headers = client.block_headers(2, 4).await.unwrap();
+    let block_numbers = headers
+        .iter()
+        .map(|header| header.number)
+        .collect::<Vec<_>>();
+    assert_eq!(block_numbers, vec![2, 3, 4]);
+    assert_eq!(headers[1].parent_hash, headers[0].hash());
+
+    assert!(client.block_headers(4, 2).await.unwrap().is_empty());
+
+    match client.block_headers(4, 6).await {
+        Err(Error::BlockNumberMissing { block_number: 6 }) => {}
+        result => panic!("Unexpected result {:?}", result),
+    }
+
+    match client.block_headers(0, MAX_BLOCK_HEADERS_RANGE).await {
+        Err(Error::RangeTooLarge { .. }) => {}
+        result => panic!("Unexpected result {:?}", result),
+    }
+}
+
+/// Test that [ClientT::block_extrinsics] decodes the inherents and signed extrinsics of a block
+/// together with their events.
+#[async_std::test]
+async fn block_extrinsics_decoded() {
+    for_each_backend(|client| async move {
+        let author = key_pair_with_funds(&client).await;
+        let message = message::Transfer {
+            recipient: ed25519::Pair::generate().0.public(),
+            amount: 1000,
+        };
+        let tx_included = client
+            .sign_and_submit_message(&author, message.clone(), MINIMUM_TX_FEE)
+            .await
+            .unwrap()
+            .await
+            .unwrap();
+
+        let extrinsics = client.block_extrinsics(tx_included.block).await.unwrap();
+        let inherents = extrinsics
+            .iter()
+            .filter(|xt| xt.signer.is_none())
+            .collect::<Vec<_>>();
+        assert!(!inherents.is_empty());
+        for inherent in inherents {
+            assert_eq!(inherent.nonce, None);
+        }
+
+        let transfer_xt = extrinsics
+            .iter()
+            .find(|xt| xt.tx_hash == tx_included.tx_hash)
+            .unwrap();
+        assert_eq!(transfer_xt.signer, Some(author.public()));
+        assert_eq!(transfer_xt.nonce, Some(0));
+        assert_eq!(transfer_xt.call, message.into_runtime_call());
+        assert!(transfer_xt
+            .events
+            .iter()
+            .any(|xt_event| matches!(xt_event, Event::system(System::ExtrinsicSuccess(_)))));
+
+        let block_hash = Hash::repeat_byte(7);
+        match client.block_extrinsics(block_hash).await {
+            Err(Error::BlockMissing { .. }) => {}
+            result => panic!("Unexpected result {:?}", result),
+        }
+    })
+    .await;
+}
+
+/// Test that [ClientT::subscribe_runtime_upgrades] yields nothing while the runtime does not
+/// change.
+#[async_std::test]
+async fn subscribe_runtime_upgrades_unchanged() {
+    let (client, emulator) = Client::new_emulator();
+    let mut upgrades = client.subscribe_runtime_upgrades().await.unwrap();
+    emulator.add_blocks(3);
+    assert!(upgrades.next().now_or_never().is_none());
+}
+
+#[async_std::test]
+async fn genesis_header() {
+    let (client, emulator) = Client::new_emulator();
+    emulator.add_blocks(2);
+
+    let genesis_header = client.genesis_header().await.unwrap();
+    assert_eq!(genesis_header.number, 0);
+    let first_header = client.block_headers(1, 1).await.unwrap().remove(0);
+    assert_eq!(first_header.parent_hash, genesis_header.hash());
+}
+
+/// Test that [ClientT::system_accounts] reports the sudo key and the endowed development
+/// accounts.
+#[async_std::test]
+async fn system_accounts() {
+    let alice = ed25519::Pair::from_string("//Alice", None)
+        .unwrap()
+        .public();
+    let bob = ed25519::Pair::from_string("//Bob", None).unwrap().public();
+    let mut genesis_config = emulator_genesis_config();
+    genesis_config.pallet_sudo = Some(SudoConfig { key: bob });
+    let (client, _) = Client::new_emulator_with_genesis(genesis_config);
+
+    let system_accounts = client.system_accounts().await.unwrap();
+    assert_eq!(
+        system_accounts,
+        SystemAccounts {
+            sudo_key: bob,
+            dev_accounts: vec![alice],
+        }
+    );
+    assert_eq!(client.root_accounts().await.unwrap(), vec![bob]);
+}
+
+/// Test that [EmulatorControl] sets the timestamps of the blocks it creates.
+#[test]
+fn emulator_timestamps() {
+    let (_, emulator) = Client::new_emulator();
+    let start = 1_600_000_000_000;
+    emulator.set_timestamp(start);
+    emulator.add_blocks(1);
+    assert_eq!(emulator.timestamp(), start);
+
+    emulator.advance_blocks(3, 60_000);
+    assert_eq!(emulator.timestamp(), start + 3 * 60_000);
+
+    // Timestamps that are too early are increased by the runtime.
+    emulator.set_timestamp(start);
+    emulator.add_blocks(1);
+    assert!(emulator.timestamp() > start + 3 * 60_000);
+}
+
+#[async_std::test]
+async fn chain_total_work() {
+    let (client, emulator) = Client::new_emulator();
+    emulator.add_blocks(3);
+
+    let tip = client.block_header_best_chain().await.unwrap();
+    let total_work = client.chain_total_work(tip.hash()).await.unwrap();
+    assert_eq!(total_work, U256::from(tip.number));
+
+    match client.chain_total_work(H256::zero()).await {
+        Err(Error::BlockMissing { .. }) => {}
+        result => panic!("Unexpected result {:?}", result),
+    }
+}
+
+/// Test that [ClientT::subscribe_events] yields the events of every new block.
+#[async_std::test]
+async fn subscribe_events() {
+    let (client, emulator) = Client::new_emulator();
+    let mut events = client.subscribe_events().await.unwrap();
+
+    let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+    let bob = ed25519::Pair::from_string("//Bob", None).unwrap().public();
+    let tx_included = client
+        .sign_and_submit_message(
+            &alice,
+            message::Transfer {
+                recipient: bob,
+                amount: 1000,
+            },
+            MINIMUM_TX_FEE,
+        )
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+
+    let (block_hash, block_events) = events.next().await.unwrap().unwrap();
+    assert_eq!(block_hash, tx_included.block);
+    assert!(
+        block_events.contains(&Event::pallet_balances(event::Balances::Transfer(
+            alice.public(),
+            bob,
+            1000
+        )))
+    );
+
+    emulator.add_blocks(1);
+    let (block_hash, _) = events.next().await.unwrap().unwrap();
+    assert_eq!(
+        block_hash,
+        client.block_header_best_chain().await.unwrap().hash()
+    );
+}
+
+/// Test that [ClientT::get_transaction] finds an included transaction in a recent block.
+#[async_std::test]
+async fn get_transaction() {
+    let (client, emulator) = Client::new_emulator();
+    let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+    let bob = ed25519::Pair::from_string("//Bob", None).unwrap().public();
+    let transaction = Transaction::new_signed(
+        &alice,
+        message::Transfer {
+            recipient: bob,
+            amount: 1000,
+        },
+        TransactionExtra {
+            nonce: client.account_nonce(&alice.public()).await.unwrap(),
+            genesis_hash: client.genesis_hash(),
+            fee: MINIMUM_TX_FEE,
+            runtime_transaction_version: radicle_registry_runtime::VERSION.transaction_version,
+            longevity: None,
+            era: None,
+        },
+    );
+    let tx_hash = transaction.clone().hash();
+    assert!(client.get_transaction(tx_hash).await.unwrap().is_none());
+
+    let tx_included = client
+        .submit_transaction(transaction)
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+    emulator.add_blocks(3);
+
+    let record = client.get_transaction(tx_hash).await.unwrap().unwrap();
+    assert_eq!(record.block, tx_included.block);
+    assert_eq!(record.extrinsic.tx_hash, tx_hash);
+    assert_eq!(record.extrinsic.signer, Some(alice.public()));
+    assert_eq!(record.result, Ok(()));
+    assert!(record
+        .extrinsic
+        .events
+        .contains(&Event::pallet_balances(event::Balances::Transfer(
+            alice.public(),
+            bob,
+            1000
+        ))));
+}
+
+/// Test that [Client::at] reads the state of the chain tip on the emulator and fails for
+/// other blocks.
+#[async_std::test]
+async fn client_at() {
+    let (client, emulator) = Client::new_emulator();
+    let alice = ed25519::Pair::from_string("//Alice", None)
+        .unwrap()
+        .public();
+    let genesis_hash = client.genesis_hash();
+    emulator.add_blocks(1);
+    let tip_hash = client.block_header_best_chain().await.unwrap().hash();
+
+    assert_eq!(
+        client.at(tip_hash).free_balance(&alice).await.unwrap(),
+        client.free_balance(&alice).await.unwrap()
+    );
+    assert!(matches!(
+        client.at(genesis_hash).free_balance(&alice).await,
+        Err(Error::Unsupported { .. })
+    ));
+    assert!(matches!(
+        client.at(genesis_hash).registry().orgs().list().await,
+        Err(Error::Unsupported { .. })
+    ));
+}
+
+/// Test that [ClientT::best_block_number] follows the blocks created by the emulator.
+#[async_std::test]
+async fn best_block_number() {
+    let (client, emulator) = Client::new_emulator();
+    assert_eq!(client.best_block_number().await.unwrap(), 0);
+    emulator.add_blocks(3);
+    assert_eq!(client.best_block_number().await.unwrap(), 3);
+}
@@ -0,0 +1,106 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern the faucet for test chains.
+use radicle_registry_client::*;
+use radicle_registry_runtime::{genesis::RegistryConfig, registry::FAUCET_MAX_AMOUNT};
+
+fn faucet_client() -> Client {
+    let mut genesis_config = emulator_genesis_config();
+    genesis_config.registry = Some(RegistryConfig {
+        deterministic_org_accounts: false,
+        faucet_enabled: true,
+        project_moderation: false,
+        burn_fees: true,
+    });
+    let (client, _) = Client::new_emulator_with_genesis(genesis_config);
+    client
+}
+
+#[async_std::test]
+async fn faucet_mints_funds() {
+    let client = faucet_client();
+    let recipient = ed25519::Pair::generate().0.public();
+    let total_issuance = client.total_issuance().await.unwrap();
+
+    let tx_included = client
+        .faucet(recipient, FAUCET_MAX_AMOUNT)
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(tx_included.result, Ok(()));
+
+    assert_eq!(
+        client.free_balance(&recipient).await.unwrap(),
+        FAUCET_MAX_AMOUNT
+    );
+    // The block reward is minted as well.
+    assert!(client.total_issuance().await.unwrap() >= total_issuance + FAUCET_MAX_AMOUNT);
+}
+
+#[async_std::test]
+async fn faucet_rate_limit() {
+    let client = faucet_client();
+    let recipient = ed25519::Pair::generate().0.public();
+
+    client.faucet(recipient, 1000).await.unwrap().await.unwrap();
+    match client.faucet(recipient, 1000).await {
+        Err(Error::InvalidTransaction) => {}
+        Err(other) => panic!("Unexpected error {:?}", other),
+        Ok(_) => panic!("Second faucet transaction must be rejected"),
+    }
+    assert_eq!(client.free_balance(&recipient).await.unwrap(), 1000);
+
+    // Other recipients are not affected.
+    let other_recipient = ed25519::Pair::generate().0.public();
+    client
+        .faucet(other_recipient, 1000)
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+}
+
+#[async_std::test]
+async fn faucet_amount_too_large() {
+    let client = faucet_client();
+    let recipient = ed25519::Pair::generate().0.public();
+
+    match client.faucet(recipient, FAUCET_MAX_AMOUNT + 1).await {
+        Err(Error::InvalidTransaction) => {}
+        Err(other) => panic!("Unexpected error {:?}", other),
+        Ok(_) => panic!("Faucet transaction must be rejected"),
+    }
+    assert!(!client.account_exists(&recipient).await.unwrap());
+}
+
+/// Test that the faucet is disabled by default.
+#[async_std::test]
+async fn faucet_disabled() {
+    let (client, _) = Client::new_emulator();
+    let recipient = ed25519::Pair::generate().0.public();
+
+    match client.faucet(recipient, 1000).await {
+        Err(Error::InvalidTransaction) => {}
+        Err(other) => panic!("Unexpected error {:?}", other),
+        Ok(_) => panic!("Faucet transaction must be rejected"),
+    }
+    assert!(!client.account_exists(&recipient).await.unwrap());
+}
@@ -0,0 +1,315 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern ID reservations.
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+fn salt() -> H256 {
+    H256::repeat_byte(0x22)
+}
+
+fn reserve_id_message(author: &ed25519::Pair, id: &Id) -> message::ReserveId {
+    message::ReserveId {
+        commitment: reservation_commitment(id, &author.public(), &salt()),
+    }
+}
+
+fn cancel_reservation_message(author: &ed25519::Pair, id: &Id) -> message::CancelReservation {
+    message::CancelReservation {
+        commitment: reservation_commitment(id, &author.public(), &salt()),
+    }
+}
+
+fn register_user_message(user_id: &Id, reservation_salt: Option<H256>) -> message::RegisterUser {
+    message::RegisterUser {
+        user_id: user_id.clone(),
+        reservation_salt,
+    }
+}
+
+#[async_std::test]
+async fn reserve_and_register_user() {
+    let (client, author) = emulator_with_funded_author().await;
+    let user_id = random_id();
+
+    let tx_included = submit_ok(&client, &author, reserve_id_message(&author, &user_id)).await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        register_user_message(&user_id, Some(salt())),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert_eq!(
+        client.get_id_status(&user_id).await.unwrap(),
+        IdStatus::Taken
+    );
+}
+
+/// Test that the deposit for a reservation is reserved from the author's balance and returned
+/// when the reservation is consumed.
+#[async_std::test]
+async fn reservation_deposit() {
+    let (client, author) = emulator_with_funded_author().await;
+    let user_id = random_id();
+    let initial_total_balance = client.total_balance(&author.public()).await.unwrap();
+
+    let fee = 5;
+    submit_ok_with_fee(&client, &author, reserve_id_message(&author, &user_id), fee).await;
+    assert_eq!(
+        client.reserved_balance(&author.public()).await.unwrap(),
+        ID_RESERVATION_DEPOSIT
+    );
+    assert_eq!(
+        client.free_balance(&author.public()).await.unwrap(),
+        initial_total_balance - fee - ID_RESERVATION_DEPOSIT
+    );
+    assert_eq!(
+        client.total_balance(&author.public()).await.unwrap(),
+        initial_total_balance - fee
+    );
+
+    submit_ok_with_fee(
+        &client,
+        &author,
+        register_user_message(&user_id, Some(salt())),
+        fee,
+    )
+    .await;
+    assert_eq!(client.reserved_balance(&author.public()).await.unwrap(), 0);
+    assert_eq!(
+        client.free_balance(&author.public()).await.unwrap(),
+        initial_total_balance - 2 * fee - REGISTRATION_FEE
+    );
+}
+
+#[async_std::test]
+async fn reserve_and_register_org() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let org_id = random_id();
+
+    submit_ok(&client, &author, reserve_id_message(&author, &org_id)).await;
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::RegisterOrg {
+            org_id: org_id.clone(),
+            reservation_salt: Some(salt()),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert!(client
+        .registry()
+        .orgs()
+        .get(org_id)
+        .await
+        .unwrap()
+        .is_some());
+    assert_eq!(client.reserved_balance(&author.public()).await.unwrap(), 0);
+}
+
+/// Test that a registration with a salt that does not reveal a reservation of the author fails.
+#[async_std::test]
+async fn register_with_wrong_salt() {
+    let (client, author) = emulator_with_funded_author().await;
+    let user_id = random_id();
+
+    submit_ok(&client, &author, reserve_id_message(&author, &user_id)).await;
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        register_user_message(&user_id, Some(H256::repeat_byte(0x33))),
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentReservation.into())
+    );
+    assert_eq!(
+        client.get_id_status(&user_id).await.unwrap(),
+        IdStatus::Available
+    );
+}
+
+/// Test that another account that copies the commitment of a reservation cannot reveal it, even
+/// after learning the ID and the salt.
+#[async_std::test]
+async fn copied_commitment_cannot_be_revealed() {
+    let (client, holder) = emulator_with_funded_author().await;
+    let other = key_pair_with_funds(&client).await;
+    let id = random_id();
+
+    let reserve_id = reserve_id_message(&holder, &id);
+    submit_ok(&client, &holder, reserve_id.clone()).await;
+    let tx_included = submit_ok(&client, &other, reserve_id).await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let tx_included = submit_ok(&client, &other, register_user_message(&id, Some(salt()))).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentReservation.into())
+    );
+
+    let tx_included = submit_ok(&client, &holder, register_user_message(&id, Some(salt()))).await;
+    assert_eq!(tx_included.result, Ok(()));
+}
+
+#[async_std::test]
+async fn duplicate_reservation() {
+    let (client, author) = emulator_with_funded_author().await;
+    let id = random_id();
+
+    submit_ok(&client, &author, reserve_id_message(&author, &id)).await;
+    let tx_included = submit_ok(&client, &author, reserve_id_message(&author, &id)).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::DuplicateReservation.into())
+    );
+    assert_eq!(
+        client.reserved_balance(&author.public()).await.unwrap(),
+        ID_RESERVATION_DEPOSIT
+    );
+}
+
+/// Test that a reservation expires after [ID_RESERVATION_PERIOD] blocks.
+#[async_std::test]
+async fn reservation_expires() {
+    let (client, emulator) = Client::new_emulator();
+    let holder = key_pair_with_funds(&client).await;
+    let id = random_id();
+
+    submit_ok(&client, &holder, reserve_id_message(&holder, &id)).await;
+    emulator.add_blocks(ID_RESERVATION_PERIOD);
+    assert_eq!(client.reserved_balance(&holder.public()).await.unwrap(), 0);
+
+    let tx_included = submit_ok(&client, &holder, register_user_message(&id, Some(salt()))).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentReservation.into())
+    );
+}
+
+/// Test that a reservation that is cancelled and made again does not expire at the expiry block
+/// of the cancelled reservation.
+#[async_std::test]
+async fn reservation_renewed_after_cancel() {
+    let (client, emulator) = Client::new_emulator();
+    let holder = key_pair_with_funds(&client).await;
+    let id = random_id();
+
+    submit_ok(&client, &holder, reserve_id_message(&holder, &id)).await;
+    emulator.add_blocks(1);
+    submit_ok(&client, &holder, cancel_reservation_message(&holder, &id)).await;
+    submit_ok(&client, &holder, reserve_id_message(&holder, &id)).await;
+
+    // The first reservation would have expired by now.
+    emulator.add_blocks(ID_RESERVATION_PERIOD - 2);
+    assert_eq!(
+        client.reserved_balance(&holder.public()).await.unwrap(),
+        ID_RESERVATION_DEPOSIT
+    );
+
+    emulator.add_blocks(2);
+    assert_eq!(client.reserved_balance(&holder.public()).await.unwrap(), 0);
+}
+
+/// Test that revealing a reservation for a taken ID fails and keeps the reservation.
+#[async_std::test]
+async fn reveal_reservation_of_taken_id() {
+    let (client, _) = Client::new_emulator();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+
+    submit_ok(&client, &author, reserve_id_message(&author, &user_id)).await;
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::RegisterOrg {
+            org_id: user_id,
+            reservation_salt: Some(salt()),
+        },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::IdAlreadyTaken.into())
+    );
+    assert_eq!(
+        client.reserved_balance(&author.public()).await.unwrap(),
+        ID_RESERVATION_DEPOSIT
+    );
+}
+
+/// Test that the account that reserved an ID can cancel the reservation and that it cannot be
+/// revealed afterwards.
+#[async_std::test]
+async fn cancel_reservation() {
+    let (client, holder) = emulator_with_funded_author().await;
+    let id = random_id();
+
+    submit_ok(&client, &holder, reserve_id_message(&holder, &id)).await;
+    let tx_included = submit_ok(&client, &holder, cancel_reservation_message(&holder, &id)).await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert_eq!(client.reserved_balance(&holder.public()).await.unwrap(), 0);
+
+    let tx_included = submit_ok(&client, &holder, register_user_message(&id, Some(salt()))).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentReservation.into())
+    );
+}
+
+/// Test that another account cannot cancel a reservation by copying its commitment.
+#[async_std::test]
+async fn cancel_reservation_of_other_account() {
+    let (client, holder) = emulator_with_funded_author().await;
+    let other = key_pair_with_funds(&client).await;
+    let id = random_id();
+
+    submit_ok(&client, &holder, reserve_id_message(&holder, &id)).await;
+    let tx_included = submit_ok(&client, &other, cancel_reservation_message(&holder, &id)).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentReservation.into())
+    );
+    assert_eq!(
+        client.reserved_balance(&holder.public()).await.unwrap(),
+        ID_RESERVATION_DEPOSIT
+    );
+}
+
+#[async_std::test]
+async fn cancel_inexistent_reservation() {
+    let (client, author) = emulator_with_funded_author().await;
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        cancel_reservation_message(&author, &random_id()),
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentReservation.into())
+    );
+}
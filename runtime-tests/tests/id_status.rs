@@ -87,3 +87,24 @@ async fn test_retired_by_user() {
     let status = client.get_id_status(&user_id).await.unwrap();
     assert_eq!(status, IdStatus::Retired);
 }
+
+/// Test that [ClientT::is_id_available] is only `true` for available IDs.
+#[async_std::test]
+async fn test_is_id_available() {
+    let (client, _) = Client::new_emulator();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+
+    assert!(client.is_id_available(random_id()).await.unwrap());
+    assert!(!client.is_id_available(user_id).await.unwrap());
+
+    let (org_id, _) = register_random_org(&client, &author).await;
+    submit_ok(
+        &client,
+        &author,
+        message::UnregisterOrg {
+            org_id: org_id.clone(),
+        },
+    )
+    .await;
+    assert!(!client.is_id_available(org_id).await.unwrap());
+}
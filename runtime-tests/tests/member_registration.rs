@@ -32,7 +32,9 @@ async fn register_member() {
 
     // The org needs funds to submit transactions.
     let org = client
-        .get_org(register_org.org_id.clone())
+        .registry()
+        .orgs()
+        .get(register_org.org_id.clone())
         .await
         .unwrap()
         .unwrap();
@@ -45,11 +47,13 @@ async fn register_member() {
         user_id: member_user_id,
     };
     let tx_included = submit_ok_with_fee(&client, &author, message.clone(), random_fee).await;
-    assert_eq!(tx_included.result, Ok(()));
+    assert_result_ok(&tx_included);
 
     // Fetch the org again
     let re_org = client
-        .get_org(message.clone().org_id)
+        .registry()
+        .orgs()
+        .get(message.clone().org_id)
         .await
         .unwrap()
         .unwrap();
@@ -105,6 +109,7 @@ async fn register_member_with_bad_actor() {
     let org_id = random_id();
     let register_org = message::RegisterOrg {
         org_id: org_id.clone(),
+        reservation_salt: None,
     };
     submit_ok(&client, &good_actor, register_org.clone()).await;
 
@@ -132,7 +137,9 @@ async fn register_member_with_bad_actor() {
 
     // Re-fetch the org and check that the bad actor was not added as a member
     let re_org = client
-        .get_org(register_member.clone().org_id)
+        .registry()
+        .orgs()
+        .get(register_member.clone().org_id)
         .await
         .unwrap()
         .unwrap();
@@ -149,12 +156,15 @@ async fn register_duplicate_member() {
     let org_id = random_id();
     let register_org = message::RegisterOrg {
         org_id: org_id.clone(),
+        reservation_salt: None,
     };
     submit_ok(&client, &author, register_org.clone()).await;
 
     // The org needs funds to submit transactions.
     let org = client
-        .get_org(register_org.org_id.clone())
+        .registry()
+        .orgs()
+        .get(register_org.org_id.clone())
         .await
         .unwrap()
         .unwrap();
@@ -174,7 +184,9 @@ async fn register_duplicate_member() {
 
     // Re-fetch the org
     let re_org = client
-        .get_org(register_member.clone().org_id)
+        .registry()
+        .orgs()
+        .get(register_member.clone().org_id)
         .await
         .unwrap()
         .unwrap();
@@ -199,12 +211,15 @@ async fn register_nonexistent_user() {
     let org_id = random_id();
     let register_org = message::RegisterOrg {
         org_id: org_id.clone(),
+        reservation_salt: None,
     };
     submit_ok(&client, &author, register_org.clone()).await;
 
     // The org needs funds to submit transactions.
     let org = client
-        .get_org(register_org.org_id.clone())
+        .registry()
+        .orgs()
+        .get(register_org.org_id.clone())
         .await
         .unwrap()
         .unwrap();
@@ -224,7 +239,9 @@ async fn register_nonexistent_user() {
 
     // Re-fetch the org
     let re_org = client
-        .get_org(register_member.clone().org_id)
+        .registry()
+        .orgs()
+        .get(register_member.clone().org_id)
         .await
         .unwrap()
         .unwrap();
@@ -239,3 +256,190 @@ async fn register_nonexistent_user() {
     // Check that no new member was added
     assert_eq!(re_org.members(), &vec![author_id]);
 }
+
+/// Test that an org can have up to [MAX_ORG_MEMBERS] members and registering another member
+/// fails.
+#[async_std::test]
+async fn register_member_limit() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+    // The org pays for every member registration.
+    transfer(&client, &author, org.account_id(), 20_000).await;
+
+    // The author is the first member of the org.
+    for _ in 1..MAX_ORG_MEMBERS {
+        let (_, user_id) = key_pair_with_associated_user(&client).await;
+        let message = message::RegisterMember {
+            org_id: org_id.clone(),
+            user_id,
+        };
+        let tx_included = submit_ok(&client, &author, message).await;
+        assert_eq!(tx_included.result, Ok(()));
+    }
+
+    let org = client
+        .registry()
+        .orgs()
+        .get(org_id.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(org.members().len(), MAX_ORG_MEMBERS as usize);
+
+    let (_, user_id) = key_pair_with_associated_user(&client).await;
+    let message = message::RegisterMember {
+        org_id: org_id.clone(),
+        user_id: user_id.clone(),
+    };
+    let tx_included = submit_ok(&client, &author, message).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::MemberLimitReached.into())
+    );
+
+    let org = client.registry().orgs().get(org_id).await.unwrap().unwrap();
+    assert!(!org.members().contains(&user_id));
+}
+
+#[async_std::test]
+async fn leave_org() {
+    let (client, _) = Client::new_emulator();
+    let (author, author_id) = key_pair_with_associated_user(&client).await;
+    let (member, member_id) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+
+    let register_member = message::RegisterMember {
+        org_id: org_id.clone(),
+        user_id: member_id,
+    };
+    submit_ok(&client, &author, register_member).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &member,
+        message::LeaveOrg {
+            org_id: org_id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let org = client.registry().orgs().get(org_id).await.unwrap().unwrap();
+    assert_eq!(org.members(), &vec![author_id]);
+}
+
+#[async_std::test]
+async fn leave_org_as_last_member() {
+    let (client, _) = Client::new_emulator();
+    let (author, author_id) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::LeaveOrg {
+            org_id: org_id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Err(RegistryError::LastOrgMember.into()));
+
+    let org = client.registry().orgs().get(org_id).await.unwrap().unwrap();
+    assert_eq!(org.members(), &vec![author_id]);
+}
+
+#[async_std::test]
+async fn leave_org_as_non_member() {
+    let (client, _) = Client::new_emulator();
+    let (author, author_id) = key_pair_with_associated_user(&client).await;
+    let (other, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &other,
+        message::LeaveOrg {
+            org_id: org_id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InsufficientSenderPermissions.into())
+    );
+
+    let org = client.registry().orgs().get(org_id).await.unwrap().unwrap();
+    assert_eq!(org.members(), &vec![author_id]);
+}
+
+/// Test that `Client::registry().orgs().members()` pages through the sorted members of an org and
+/// reflects members leaving the org.
+#[async_std::test]
+async fn list_org_members() {
+    let (client, _) = Client::new_emulator();
+    let (author, author_id) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+
+    let mut member_ids = vec![author_id];
+    let mut leaving_member = None;
+    for _ in 0..3 {
+        let (member, member_id) = key_pair_with_associated_user(&client).await;
+        let message = message::RegisterMember {
+            org_id: org_id.clone(),
+            user_id: member_id.clone(),
+        };
+        submit_ok(&client, &author, message).await;
+        member_ids.push(member_id.clone());
+        leaving_member = Some((member, member_id));
+    }
+    member_ids.sort();
+
+    let all_members = client
+        .registry()
+        .orgs()
+        .members(org_id.clone(), 0, usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(all_members, member_ids);
+
+    let first_page = client
+        .registry()
+        .orgs()
+        .members(org_id.clone(), 0, 2)
+        .await
+        .unwrap();
+    let second_page = client
+        .registry()
+        .orgs()
+        .members(org_id.clone(), 2, 2)
+        .await
+        .unwrap();
+    let past_the_end = client
+        .registry()
+        .orgs()
+        .members(org_id.clone(), 4, 2)
+        .await
+        .unwrap();
+    assert_eq!(first_page, member_ids[0..2].to_vec());
+    assert_eq!(second_page, member_ids[2..4].to_vec());
+    assert_eq!(past_the_end, Vec::<Id>::new());
+
+    let (member, member_id) = leaving_member.unwrap();
+    submit_ok(
+        &client,
+        &member,
+        message::LeaveOrg {
+            org_id: org_id.clone(),
+        },
+    )
+    .await;
+    let all_members = client
+        .registry()
+        .orgs()
+        .members(org_id, 0, usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(all_members.len(), 3);
+    assert!(!all_members.contains(&member_id));
+}
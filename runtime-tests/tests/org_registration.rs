@@ -18,6 +18,8 @@
 /// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
 ///
 /// The tests in this module concern orgs registration.
+use std::time::Duration;
+
 use radicle_registry_client::*;
 use radicle_registry_test_utils::*;
 
@@ -31,21 +33,36 @@ async fn register_org() {
     let register_org_message = random_register_org_message();
     let tx_included =
         submit_ok_with_fee(&client, &author, register_org_message.clone(), random_fee).await;
-    assert_eq!(tx_included.result, Ok(()));
+    assert_result_ok(&tx_included);
 
     assert!(
         org_exists(&client, register_org_message.org_id.clone()).await,
         "Org not found in orgs list"
     );
+    assert!(client
+        .registry()
+        .orgs()
+        .exists(register_org_message.org_id.clone())
+        .await
+        .unwrap());
 
     let org = client
-        .get_org(register_org_message.org_id.clone())
+        .registry()
+        .orgs()
+        .get(register_org_message.org_id.clone())
         .await
         .unwrap()
         .unwrap();
     assert_eq!(org.members(), &vec![user_id]);
     assert!(org.projects().is_empty());
 
+    let block_header = client
+        .block_header(tx_included.block)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(org.registered_at(), block_header.number);
+
     assert_eq!(
         client.free_balance(&author.public()).await.unwrap(),
         initial_balance - random_fee - REGISTRATION_FEE,
@@ -53,6 +70,73 @@ async fn register_org() {
     );
 }
 
+/// Test that [Client::ensure_org_registered] registers an org only once.
+#[async_std::test]
+async fn ensure_org_registered() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let org_id = random_id();
+
+    let created = client
+        .ensure_org_registered(&author, org_id.clone(), random_balance())
+        .await
+        .unwrap();
+    assert!(created);
+    assert!(org_exists(&client, org_id.clone()).await);
+
+    let created = client
+        .ensure_org_registered(&author, org_id, random_balance())
+        .await
+        .unwrap();
+    assert!(!created);
+}
+
+/// Test that [Client::ensure_org_registered] fails if the ID is taken by a user.
+#[async_std::test]
+async fn ensure_org_registered_with_id_taken_by_user() {
+    let (client, _) = Client::new_emulator();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+
+    let result = client
+        .ensure_org_registered(&author, user_id, random_balance())
+        .await;
+    match result {
+        Err(Error::RegistrationFailed(TransactionError::RegistryError(
+            RegistryError::IdAlreadyTaken,
+        ))) => {}
+        other => panic!("Unexpected result {:?}", other),
+    }
+}
+
+/// Test that org accounts are derived from the org ID if the chain is configured to do so.
+#[async_std::test]
+async fn register_org_deterministic_account() {
+    let mut genesis_config = emulator_genesis_config();
+    genesis_config.registry = Some(radicle_registry_runtime::genesis::RegistryConfig {
+        deterministic_org_accounts: true,
+        faucet_enabled: false,
+        project_moderation: false,
+        burn_fees: true,
+    });
+    let (client, _) = Client::new_emulator_with_genesis(genesis_config);
+    assert!(client.deterministic_org_accounts().await.unwrap());
+
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+    assert_eq!(org.account_id(), Client::derived_org_account(&org_id));
+}
+
+/// Test that org accounts are random by default.
+#[async_std::test]
+async fn register_org_random_account() {
+    let (client, _) = Client::new_emulator();
+    assert!(!client.deterministic_org_accounts().await.unwrap());
+
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+    assert_ne!(org.account_id(), Client::derived_org_account(&org_id));
+}
+
 /// Verify that it fails to register a user if the author has insufficient funds to
 /// pay for the registration fee.
 #[async_std::test]
@@ -110,8 +194,7 @@ async fn register_user_with_insufficient_funds_for_registration_fee() {
 /// have a registered user associated to its account id.
 #[async_std::test]
 async fn register_org_no_user() {
-    let (client, _) = Client::new_emulator();
-    let alice = key_pair_with_funds(&client).await;
+    let (client, alice) = emulator_with_funded_author().await;
 
     let initial_balance = client.free_balance(&alice.public()).await.unwrap();
     let random_fee = random_balance();
@@ -138,39 +221,47 @@ async fn register_org_no_user() {
 /// Test that an org can not be registered with an id already taken by another org.
 #[async_std::test]
 async fn register_with_id_taken_by_org() {
-    let (client, _) = Client::new_emulator();
-    let (author, _) = key_pair_with_associated_user(&client).await;
-
-    let register_org_message = random_register_org_message();
-    let tx_included_once = submit_ok(&client, &author, register_org_message.clone()).await;
-    assert_eq!(tx_included_once.result, Ok(()));
-
-    let tx_included_twice = submit_ok(&client, &author, register_org_message.clone()).await;
-    assert_eq!(
-        tx_included_twice.result,
-        Err(RegistryError::IdAlreadyTaken.into())
-    );
+    for_each_backend(|client| async move {
+        let (author, _) = key_pair_with_associated_user(&client).await;
+
+        let register_org_message = random_register_org_message();
+        let tx_included_once = submit_ok(&client, &author, register_org_message.clone()).await;
+        assert_eq!(tx_included_once.result, Ok(()));
+
+        let tx_included_twice = submit_ok(&client, &author, register_org_message.clone()).await;
+        assert_eq!(
+            tx_included_twice.result,
+            Err(RegistryError::IdAlreadyTaken.into())
+        );
+    })
+    .await;
 }
 
 /// Test that an org can not be registered with an id already taken by a user.
 #[async_std::test]
 async fn register_with_taken_user_id() {
-    let (client, _) = Client::new_emulator();
-    let author = key_pair_with_funds(&client).await;
-    let id = random_id();
-
-    let register_user_message = message::RegisterUser {
-        user_id: id.clone(),
-    };
-    let tx_included_user = submit_ok(&client, &author, register_user_message.clone()).await;
-    assert_eq!(tx_included_user.result, Ok(()));
-
-    let register_org_message = message::RegisterOrg { org_id: id };
-    let tx_included_org = submit_ok(&client, &author, register_org_message.clone()).await;
-    assert_eq!(
-        tx_included_org.result,
-        Err(RegistryError::IdAlreadyTaken.into())
-    );
+    for_each_backend(|client| async move {
+        let author = key_pair_with_funds(&client).await;
+        let id = random_id();
+
+        let register_user_message = message::RegisterUser {
+            user_id: id.clone(),
+            reservation_salt: None,
+        };
+        let tx_included_user = submit_ok(&client, &author, register_user_message.clone()).await;
+        assert_eq!(tx_included_user.result, Ok(()));
+
+        let register_org_message = message::RegisterOrg {
+            org_id: id,
+            reservation_salt: None,
+        };
+        let tx_included_org = submit_ok(&client, &author, register_org_message.clone()).await;
+        assert_eq!(
+            tx_included_org.result,
+            Err(RegistryError::IdAlreadyTaken.into())
+        );
+    })
+    .await;
 }
 
 #[async_std::test]
@@ -208,7 +299,10 @@ async fn register_with_id_of_unregistered_user() {
     assert_eq!(tx_unregister_applied.result, Ok(()));
 
     // Try to register an Org with the unregistered user id
-    let register_org_message = message::RegisterOrg { org_id: user_id };
+    let register_org_message = message::RegisterOrg {
+        org_id: user_id,
+        reservation_salt: None,
+    };
     let tx_included = submit_ok(&client, &author, register_org_message.clone()).await;
     assert_eq!(tx_included.result, Err(RegistryError::IdRetired.into()));
 }
@@ -309,7 +403,13 @@ async fn unregister_org_with_projects() {
         "Org not found in orgs list"
     );
 
-    let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+    let org = client
+        .registry()
+        .orgs()
+        .get(org_id.clone())
+        .await
+        .unwrap()
+        .unwrap();
 
     assert_eq!(org.projects().len(), 1);
 
@@ -329,9 +429,76 @@ async fn unregister_org_with_projects() {
     );
 }
 
+#[async_std::test]
+async fn list_orgs_is_sorted() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    for _ in 0..5 {
+        register_random_org(&client, &author).await;
+    }
+
+    let org_ids = client.registry().orgs().list().await.unwrap();
+    let mut sorted_org_ids = org_ids.clone();
+    sorted_org_ids.sort();
+
+    assert_eq!(org_ids.len(), 5);
+    assert_eq!(org_ids, sorted_org_ids);
+    assert_eq!(client.registry().orgs().list().await.unwrap(), org_ids);
+}
+
+#[async_std::test]
+async fn org_count() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    assert_eq!(client.org_count().await.unwrap(), 0);
+
+    for _ in 0..3 {
+        register_random_org(&client, &author).await;
+    }
+
+    assert_eq!(client.org_count().await.unwrap(), 3);
+    assert_eq!(client.registry().orgs().list().await.unwrap().len(), 3);
+}
+
+/// Test that [Client::wait_for_org] returns an org that is registered while waiting.
+#[async_std::test]
+async fn wait_for_org() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let message = random_register_org_message();
+
+    let (org, tx_included) = futures::join!(
+        client.wait_for_org(message.org_id.clone(), Duration::from_secs(10)),
+        submit_ok(&client, &author, message.clone()),
+    );
+    assert_result_ok(&tx_included);
+    let block_header = client
+        .block_header(tx_included.block)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(org.unwrap().registered_at(), block_header.number);
+}
+
+/// Test that [Client::wait_for_org] fails if the org is not registered in time.
+#[async_std::test]
+async fn wait_for_org_timeout() {
+    let (client, _) = Client::new_emulator();
+
+    let result = client
+        .wait_for_org(random_id(), Duration::from_millis(100))
+        .await;
+    match result {
+        Err(Error::Timeout { .. }) => (),
+        _ => panic!("Expected Error::Timeout, got {:?}", result),
+    }
+}
+
 async fn org_exists(client: &Client, org_id: Id) -> bool {
     client
-        .list_orgs()
+        .registry()
+        .orgs()
+        .list()
         .await
         .unwrap()
         .iter()
@@ -0,0 +1,54 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern the `populate_chain` test helper.
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+#[async_std::test]
+async fn populate_chain_creates_entities() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let spec = PopulateSpec {
+        orgs: 3,
+        users: 4,
+        projects: 10,
+    };
+
+    let populated = populate_chain(&client, &author, spec).await;
+
+    assert_eq!(populated.orgs.len(), spec.orgs);
+    assert_eq!(populated.users.len(), spec.users);
+    assert_eq!(populated.projects.len(), spec.projects);
+
+    let orgs = client.registry().orgs().list().await.unwrap();
+    assert!(populated.orgs.iter().all(|org_id| orgs.contains(org_id)));
+
+    let users = client.registry().users().list().await.unwrap();
+    assert!(populated
+        .users
+        .iter()
+        .all(|(_, user_id)| users.contains(user_id)));
+
+    let projects = client.registry().projects().list().await.unwrap();
+    assert!(populated
+        .projects
+        .iter()
+        .all(|project_id| projects.contains(project_id)));
+}
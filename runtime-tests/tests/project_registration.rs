@@ -19,6 +19,8 @@
 ///
 /// The tests in this module concern project registration.
 use radicle_registry_client::*;
+use radicle_registry_runtime::event;
+use radicle_registry_runtime::genesis::{RegistryConfig, SudoConfig};
 use radicle_registry_test_utils::*;
 
 // Verify that a project can be registered under a user and an org.
@@ -26,17 +28,28 @@ use radicle_registry_test_utils::*;
 // under those two different domains.
 #[async_std::test]
 async fn register_project() {
-    let (client, _) = Client::new_emulator();
-    let author = key_pair_with_funds(&client).await;
+    let (client, author) = emulator_with_funded_author().await;
 
     for domain in generate_project_domains(&client, &author).await {
         let initial_balance = match &domain {
             ProjectDomain::Org(org_id) => {
-                let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+                let org = client
+                    .registry()
+                    .orgs()
+                    .get(org_id.clone())
+                    .await
+                    .unwrap()
+                    .unwrap();
                 client.free_balance(&org.account_id()).await.unwrap()
             }
             ProjectDomain::User(user_id) => {
-                let user = client.get_user(user_id.clone()).await.unwrap().unwrap();
+                let user = client
+                    .registry()
+                    .users()
+                    .get(user_id.clone())
+                    .await
+                    .unwrap()
+                    .unwrap();
                 client.free_balance(&user.account_id()).await.unwrap()
             }
         };
@@ -48,14 +61,24 @@ async fn register_project() {
         assert_eq!(tx_included.result, Ok(()));
 
         let project = client
-            .get_project(message.project_name.clone(), message.project_domain.clone())
+            .registry()
+            .projects()
+            .get(message.project_name.clone(), message.project_domain.clone())
             .await
             .unwrap()
             .unwrap();
         assert_eq!(project.metadata().clone(), message.metadata.clone());
+        assert!(client
+            .registry()
+            .projects()
+            .exists(message.project_name.clone(), message.project_domain.clone())
+            .await
+            .unwrap());
 
         let has_project = client
-            .list_projects()
+            .registry()
+            .projects()
+            .list()
             .await
             .unwrap()
             .iter()
@@ -64,11 +87,23 @@ async fn register_project() {
 
         let (projects, account_id) = match &domain {
             ProjectDomain::Org(org_id) => {
-                let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+                let org = client
+                    .registry()
+                    .orgs()
+                    .get(org_id.clone())
+                    .await
+                    .unwrap()
+                    .unwrap();
                 (org.projects().clone(), org.account_id())
             }
             ProjectDomain::User(user_id) => {
-                let user = client.get_user(user_id.clone()).await.unwrap().unwrap();
+                let user = client
+                    .registry()
+                    .users()
+                    .get(user_id.clone())
+                    .await
+                    .unwrap()
+                    .unwrap();
                 (user.projects().clone(), user.account_id())
             }
         };
@@ -82,7 +117,8 @@ async fn register_project() {
     }
 }
 
-// Verify that a project can not be registered under a domain that does not exist.
+// Verify that a project registration under a domain that does not exist is rejected as an
+// invalid transaction and that the author is not charged a fee.
 #[async_std::test]
 async fn register_project_under_inexistent_domain() {
     let (client, _) = Client::new_emulator();
@@ -92,22 +128,29 @@ async fn register_project_under_inexistent_domain() {
         ProjectDomain::Org(random_id()),
         ProjectDomain::User(random_id()),
     ] {
+        let initial_balance = client.free_balance(&author.public()).await.unwrap();
         let message = random_register_project_message(&domain);
-        let tx_included = submit_ok(&client, &author, message.clone()).await;
+        let result = client
+            .sign_and_submit_message(&author, message, random_balance())
+            .await;
 
-        let expected_error = match domain {
-            ProjectDomain::Org(_) => RegistryError::InexistentOrg,
-            ProjectDomain::User(_) => RegistryError::InexistentUser,
-        };
-        assert_eq!(tx_included.result, Err(expected_error.into()));
+        match result {
+            Err(Error::InvalidTransaction) => (),
+            Err(error) => panic!("Unexpected error {}", error),
+            Ok(_) => panic!("Transaction was not rejected"),
+        }
+        assert_eq!(
+            client.free_balance(&author.public()).await.unwrap(),
+            initial_balance,
+            "The author was charged for a rejected transaction."
+        );
     }
 }
 
 // Verify that a same project can not be re-registered under the same user or org.
 #[async_std::test]
 async fn re_register_project_same_domain_entity() {
-    let (client, _) = Client::new_emulator();
-    let author = key_pair_with_funds(&client).await;
+    let (client, author) = emulator_with_funded_author().await;
 
     for domain in generate_project_domains(&client, &author).await {
         let message = random_register_project_message(&domain);
@@ -131,7 +174,9 @@ async fn re_register_project_same_domain_entity() {
         );
 
         let project = client
-            .get_project(message.project_name, message.project_domain)
+            .registry()
+            .projects()
+            .get(message.project_name, message.project_domain)
             .await
             .unwrap()
             .unwrap();
@@ -141,11 +186,23 @@ async fn re_register_project_same_domain_entity() {
 
         let projects_list = match &domain {
             ProjectDomain::Org(org_id) => {
-                let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+                let org = client
+                    .registry()
+                    .orgs()
+                    .get(org_id.clone())
+                    .await
+                    .unwrap()
+                    .unwrap();
                 org.projects().clone()
             }
             ProjectDomain::User(user_id) => {
-                let user = client.get_user(user_id.clone()).await.unwrap().unwrap();
+                let user = client
+                    .registry()
+                    .users()
+                    .get(user_id.clone())
+                    .await
+                    .unwrap()
+                    .unwrap();
                 user.projects().clone()
             }
         };
@@ -210,8 +267,7 @@ async fn register_same_project_name_under_different_users() {
 // Verify that a bad author can not register projects under other users and orgs.
 #[async_std::test]
 async fn register_project_with_bad_actor() {
-    let (client, _) = Client::new_emulator();
-    let author = key_pair_with_funds(&client).await;
+    let (client, author) = emulator_with_funded_author().await;
     let (bad_actor, _) = key_pair_with_associated_user(&client).await;
 
     for domain in generate_project_domains(&client, &author).await {
@@ -235,9 +291,290 @@ async fn register_project_with_bad_actor() {
         );
 
         assert!(client
-            .get_project(register_project.project_name, domain)
+            .registry()
+            .projects()
+            .get(register_project.project_name, domain)
             .await
             .unwrap()
             .is_none());
     }
 }
+
+// Verify that a project can be unregistered from a user and an org.
+#[async_std::test]
+async fn unregister_project() {
+    let (client, author) = emulator_with_funded_author().await;
+
+    for domain in generate_project_domains(&client, &author).await {
+        let (project_name, _) = create_project(&client, &author, &domain).await;
+
+        let tx_included = submit_ok(
+            &client,
+            &author,
+            message::UnregisterProject {
+                project_name: project_name.clone(),
+                project_domain: domain.clone(),
+            },
+        )
+        .await;
+        assert_eq!(tx_included.result, Ok(()));
+        assert_event_present(
+            &client,
+            &tx_included,
+            Event::registry(event::Registry::ProjectUnregistered(
+                project_name.clone(),
+                domain.clone(),
+            )),
+        )
+        .await;
+
+        assert!(client
+            .registry()
+            .projects()
+            .get(project_name.clone(), domain.clone())
+            .await
+            .unwrap()
+            .is_none());
+        let projects = match &domain {
+            ProjectDomain::Org(org_id) => {
+                let org = client
+                    .registry()
+                    .orgs()
+                    .get(org_id.clone())
+                    .await
+                    .unwrap()
+                    .unwrap();
+                org.projects().clone()
+            }
+            ProjectDomain::User(user_id) => {
+                let user = client
+                    .registry()
+                    .users()
+                    .get(user_id.clone())
+                    .await
+                    .unwrap()
+                    .unwrap();
+                user.projects().clone()
+            }
+        };
+        assert!(!projects.contains(&project_name));
+    }
+}
+
+// Verify that a bad author can not unregister projects of other users and orgs.
+#[async_std::test]
+async fn unregister_project_with_bad_actor() {
+    let (client, author) = emulator_with_funded_author().await;
+    let (bad_actor, _) = key_pair_with_associated_user(&client).await;
+
+    for domain in generate_project_domains(&client, &author).await {
+        let (project_name, _) = create_project(&client, &author, &domain).await;
+
+        let tx_included = submit_ok(
+            &client,
+            &bad_actor,
+            message::UnregisterProject {
+                project_name: project_name.clone(),
+                project_domain: domain.clone(),
+            },
+        )
+        .await;
+        assert_eq!(
+            tx_included.result,
+            Err(RegistryError::InsufficientSenderPermissions.into())
+        );
+
+        assert!(client
+            .registry()
+            .projects()
+            .get(project_name, domain)
+            .await
+            .unwrap()
+            .is_some());
+    }
+}
+
+#[async_std::test]
+async fn unregister_inexistent_project() {
+    let (client, _) = Client::new_emulator();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::UnregisterProject {
+            project_name: random_project_name(),
+            project_domain: ProjectDomain::User(user_id),
+        },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentProjectId.into())
+    );
+}
+
+fn project_moderation_client() -> Client {
+    let mut genesis_config = emulator_genesis_config();
+    genesis_config.pallet_sudo = Some(SudoConfig {
+        key: root_key_pair().public(),
+    });
+    genesis_config.registry = Some(RegistryConfig {
+        deterministic_org_accounts: false,
+        faucet_enabled: false,
+        project_moderation: true,
+        burn_fees: true,
+    });
+    let (client, _) = Client::new_emulator_with_genesis(genesis_config);
+    client
+}
+
+// Verify that with project moderation a registered project is pending until it is accepted.
+#[async_std::test]
+async fn accept_pending_project() {
+    let client = project_moderation_client();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+    let domain = ProjectDomain::User(user_id.clone());
+
+    let message = random_register_project_message(&domain);
+    let project_id = (message.project_name.clone(), domain.clone());
+    let tx_included = submit_ok(&client, &author, message.clone()).await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let pending_project = client
+        .get_pending_project(message.project_name.clone(), domain.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(pending_project.metadata(), &message.metadata);
+    assert_eq!(pending_project.account_id(), author.public());
+    let block_header = client
+        .block_header(tx_included.block)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(pending_project.submitted_at(), block_header.number);
+    assert_eq!(
+        client.list_pending_projects().await.unwrap(),
+        vec![project_id.clone()]
+    );
+    assert!(client
+        .registry()
+        .projects()
+        .get(message.project_name.clone(), domain.clone())
+        .await
+        .unwrap()
+        .is_none());
+
+    // A project that is pending can not be registered again.
+    let tx_included = submit_ok(&client, &author, message.clone()).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::DuplicateProjectId.into())
+    );
+
+    let tx_included = client
+        .sudo(
+            &root_key_pair(),
+            message::AcceptProject {
+                project_name: message.project_name.clone(),
+                project_domain: domain.clone(),
+            },
+            random_balance(),
+        )
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(tx_included.result, Ok(()));
+
+    assert!(client.list_pending_projects().await.unwrap().is_empty());
+    let project = client
+        .registry()
+        .projects()
+        .get(message.project_name.clone(), domain)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(project.metadata(), &message.metadata);
+    let user = client
+        .registry()
+        .users()
+        .get(user_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(user.projects().contains(&message.project_name));
+}
+
+// Verify that a rejected project is removed from the pending projects and not registered.
+#[async_std::test]
+async fn reject_pending_project() {
+    let client = project_moderation_client();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+    let domain = ProjectDomain::User(user_id);
+
+    let message = random_register_project_message(&domain);
+    submit_ok(&client, &author, message.clone()).await;
+
+    let reject_project = message::RejectProject {
+        project_name: message.project_name.clone(),
+        project_domain: domain.clone(),
+    };
+    let tx_included = client
+        .sudo(&root_key_pair(), reject_project.clone(), random_balance())
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(tx_included.result, Ok(()));
+
+    assert!(client.list_pending_projects().await.unwrap().is_empty());
+    assert!(client
+        .registry()
+        .projects()
+        .get(message.project_name, domain)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Rejecting the project again fails.
+    let tx_included = client
+        .sudo(&root_key_pair(), reject_project, random_balance())
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentPendingProject.into())
+    );
+}
+
+// Verify that only the root origin can accept pending projects.
+#[async_std::test]
+async fn accept_pending_project_not_root() {
+    let client = project_moderation_client();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+    let domain = ProjectDomain::User(user_id);
+
+    let message = random_register_project_message(&domain);
+    submit_ok(&client, &author, message.clone()).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::AcceptProject {
+            project_name: message.project_name.clone(),
+            project_domain: domain.clone(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Err(DispatchError::BadOrigin.into()));
+
+    assert!(client
+        .get_pending_project(message.project_name, domain)
+        .await
+        .unwrap()
+        .is_some());
+}
@@ -0,0 +1,83 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern replaying the events of the chain.
+use futures::prelude::*;
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+#[async_std::test]
+async fn replay_from_genesis() {
+    let (client, emulator) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::RegisterUser {
+            user_id: random_id(),
+            reservation_salt: None,
+        },
+    )
+    .await;
+    emulator.add_blocks(2);
+    let best_block_number = client.block_header_best_chain().await.unwrap().number;
+
+    let replayed: Vec<(BlockNumber, Vec<Event>)> = client
+        .replay_from_genesis()
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    let block_numbers: Vec<BlockNumber> = replayed.iter().map(|(number, _)| *number).collect();
+    assert_eq!(block_numbers, (0..=best_block_number).collect::<Vec<_>>());
+
+    let tx_block_number = client
+        .block_header(tx_included.block)
+        .await
+        .unwrap()
+        .unwrap()
+        .number;
+    let (_, tx_block_events) = &replayed[tx_block_number as usize];
+    assert!(tx_block_events.iter().any(|event| matches!(
+        event,
+        Event::system(radicle_registry_runtime::event::System::ExtrinsicSuccess(_))
+    )));
+}
+
+#[async_std::test]
+async fn replay_from() {
+    let (client, emulator) = Client::new_emulator();
+    emulator.add_blocks(5);
+    let best_block_number = client.block_header_best_chain().await.unwrap().number;
+
+    let block_numbers: Vec<BlockNumber> = client
+        .replay_from(best_block_number - 1)
+        .await
+        .unwrap()
+        .map_ok(|(number, _)| number)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        block_numbers,
+        vec![best_block_number - 1, best_block_number]
+    );
+}
@@ -0,0 +1,277 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern signing and submitting transactions with [Client].
+use futures::prelude::*;
+use radicle_registry_client::*;
+use radicle_registry_runtime::{AvailableBlockRatio, BlockHashCount, MaximumBlockLength};
+use radicle_registry_test_utils::*;
+
+/// Test that [ClientT::submit_nowait] returns the hash of the submitted transaction.
+#[async_std::test]
+async fn submit_nowait_returns_tx_hash() {
+    let (client, _) = Client::new_emulator();
+    let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+    let bob = ed25519::Pair::from_string("//Bob", None).unwrap().public();
+    let message = message::Transfer {
+        recipient: bob,
+        amount: 1000,
+    };
+    let tx_hash = client
+        .submit_nowait(&alice, message, MINIMUM_TX_FEE)
+        .await
+        .unwrap();
+
+    let best_block = client.block_header_best_chain().await.unwrap().hash();
+    let extrinsics = client.block_extrinsics(best_block).await.unwrap();
+    assert_eq!(extrinsics.last().unwrap().tx_hash, tx_hash);
+    assert_eq!(client.account_nonce(&alice.public()).await.unwrap(), 1);
+}
+
+/// Test that [ClientT::next_nonces] reserves the nonces in the nonce cache of the client.
+#[async_std::test]
+async fn next_nonces() {
+    let (client, _) = Client::new_emulator();
+    let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+    assert_eq!(
+        client.next_nonces(&alice.public(), 3).await.unwrap(),
+        vec![0, 1, 2]
+    );
+    assert_eq!(
+        client.next_nonces(&alice.public(), 2).await.unwrap(),
+        vec![3, 4]
+    );
+    assert!(client
+        .next_nonces(&alice.public(), 0)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+/// Test that [ClientT::submit_tracked] reports the transaction entering the pool, a block, and
+/// its inclusion.
+#[async_std::test]
+async fn submit_tracked_reports_progress() {
+    let (client, _) = Client::new_emulator();
+    let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+    let transaction = Transaction::new_signed(
+        &alice,
+        message::Transfer {
+            recipient: ed25519::Pair::generate().0.public(),
+            amount: 1000,
+        },
+        TransactionExtra {
+            nonce: client.account_nonce(&alice.public()).await.unwrap(),
+            genesis_hash: client.genesis_hash(),
+            fee: MINIMUM_TX_FEE,
+            runtime_transaction_version: radicle_registry_runtime::VERSION.transaction_version,
+            longevity: None,
+            era: None,
+        },
+    );
+    let tx_hash = transaction.clone().hash();
+
+    let states: Vec<TxState> = client
+        .submit_tracked(transaction)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    match states.as_slice() {
+        [TxState::InPool, TxState::InBlock(block_hash), TxState::Included(tx_included)] => {
+            assert_eq!(tx_included.block, *block_hash);
+            assert_eq!(tx_included.tx_hash, tx_hash);
+            assert_eq!(tx_included.result, Ok(()));
+        }
+        states => panic!("Unexpected transaction states {:?}", states),
+    }
+}
+
+/// Test that a transaction that exceeds the block length available to normal transactions is
+/// rejected with [Error::BlockResourcesExhausted].
+#[async_std::test]
+async fn submit_exhausts_block_resources() {
+    let (client, _) = Client::new_emulator();
+    let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+    let block_length = AvailableBlockRatio::get() * MaximumBlockLength::get();
+    let message = message::UpdateRuntime {
+        code: vec![0u8; block_length as usize],
+    };
+
+    match client.submit_nowait(&alice, message, MINIMUM_TX_FEE).await {
+        Err(Error::BlockResourcesExhausted) => {}
+        result => panic!("Unexpected result {:?}", result),
+    }
+}
+
+/// Submit a transfer from Alice that is mortal with the given era block.
+async fn submit_mortal_transfer(
+    client: &Client,
+    era: (BlockNumber, BlockHash),
+) -> Result<TransactionIncluded, Error> {
+    let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+    let bob = ed25519::Pair::from_string("//Bob", None).unwrap().public();
+    let transaction = Transaction::new_signed(
+        &alice,
+        message::Transfer {
+            recipient: bob,
+            amount: 1000,
+        },
+        TransactionExtra {
+            nonce: client.account_nonce(&alice.public()).await.unwrap(),
+            genesis_hash: client.genesis_hash(),
+            fee: MINIMUM_TX_FEE,
+            runtime_transaction_version: radicle_registry_runtime::VERSION.transaction_version,
+            longevity: None,
+            era: Some(era),
+        },
+    );
+    client.submit_transaction(transaction).await?.await
+}
+
+#[async_std::test]
+async fn mortal_transaction() {
+    let (client, emulator) = Client::new_emulator();
+    emulator.add_blocks(1);
+    let header = client.block_header_best_chain().await.unwrap();
+
+    let tx_included = submit_mortal_transfer(&client, (header.number, header.hash()))
+        .await
+        .unwrap();
+    assert_eq!(tx_included.result, Ok(()));
+}
+
+/// Test that a mortal transaction is rejected once its era block is older than
+/// [BlockHashCount] blocks.
+#[async_std::test]
+async fn mortal_transaction_ancient_era_block() {
+    let (client, emulator) = Client::new_emulator();
+    emulator.add_blocks(1);
+    let header = client.block_header_best_chain().await.unwrap();
+    emulator.add_blocks(BlockHashCount::get() + 1);
+
+    let result = submit_mortal_transfer(&client, (header.number, header.hash())).await;
+    assert!(matches!(result, Err(Error::AncientEraBlock)));
+}
+
+/// Test that [ClientT::sign_and_submit_message] signs with consecutive nonces and fetches
+/// the nonce again if a transaction was submitted without the client's nonce cache.
+#[async_std::test]
+async fn sign_and_submit_message_nonce_cache() {
+    for_each_backend(|client| async move {
+        let author = key_pair_with_funds(&client).await;
+        let transfer = || message::Transfer {
+            recipient: ed25519::Pair::generate().0.public(),
+            amount: 1000,
+        };
+
+        for _ in 0..3 {
+            let tx_included = client
+                .sign_and_submit_message(&author, transfer(), MINIMUM_TX_FEE)
+                .await
+                .unwrap()
+                .await
+                .unwrap();
+            assert_eq!(tx_included.result, Ok(()));
+        }
+        assert_eq!(client.account_nonce(&author.public()).await.unwrap(), 3);
+
+        // Uses nonce 3 and makes the cached nonce stale.
+        let transaction = Transaction::new_signed(
+            &author,
+            transfer(),
+            TransactionExtra {
+                nonce: 3,
+                genesis_hash: client.genesis_hash(),
+                fee: MINIMUM_TX_FEE,
+                runtime_transaction_version: radicle_registry_runtime::VERSION.transaction_version,
+                longevity: None,
+                era: None,
+            },
+        );
+        client
+            .submit_transaction(transaction)
+            .await
+            .unwrap()
+            .await
+            .unwrap();
+
+        let tx_included = client
+            .sign_and_submit_message(&author, transfer(), MINIMUM_TX_FEE)
+            .await
+            .unwrap()
+            .await
+            .unwrap();
+        assert_eq!(tx_included.result, Ok(()));
+        assert_eq!(client.account_nonce(&author.public()).await.unwrap(), 5);
+    })
+    .await;
+}
+
+/// Test that the confirmation future of [ClientT::sign_and_submit_message_confirmed] resolves
+/// once the given number of blocks is built on top of the block that includes the transaction.
+#[async_std::test]
+async fn sign_and_submit_message_confirmed() {
+    let (client, emulator) = Client::new_emulator();
+    let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+    let (tx_included, mut tx_confirmed) = client
+        .sign_and_submit_message_confirmed(
+            &alice,
+            message::Transfer {
+                recipient: ed25519::Pair::generate().0.public(),
+                amount: 1000,
+            },
+            MINIMUM_TX_FEE,
+            3,
+        )
+        .await
+        .unwrap();
+
+    let tx_included = tx_included.await.unwrap();
+    assert_eq!(tx_included.result, Ok(()));
+
+    emulator.add_blocks(2);
+    assert!(futures::poll!(&mut tx_confirmed).is_pending());
+    emulator.add_blocks(1);
+    tx_confirmed.await.unwrap();
+}
+
+/// Test that the confirmation future of [ClientT::sign_and_submit_message_confirmed] resolves
+/// without awaiting the inclusion future.
+#[async_std::test]
+async fn sign_and_submit_message_confirmed_without_inclusion() {
+    for_each_backend(|client| async move {
+        let author = key_pair_with_funds(&client).await;
+        let (_, tx_confirmed) = client
+            .sign_and_submit_message_confirmed(
+                &author,
+                message::Transfer {
+                    recipient: ed25519::Pair::generate().0.public(),
+                    amount: 1000,
+                },
+                MINIMUM_TX_FEE,
+                0,
+            )
+            .await
+            .unwrap();
+        tx_confirmed.await.unwrap();
+    })
+    .await;
+}
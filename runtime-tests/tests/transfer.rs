@@ -19,12 +19,12 @@
 ///
 /// The tests in this module concern transferring funds.
 use radicle_registry_client::*;
+use radicle_registry_runtime::event;
 use radicle_registry_test_utils::*;
 
 #[async_std::test]
 async fn transfer_fail() {
-    let (client, _) = Client::new_emulator();
-    let alice = key_pair_with_funds(&client).await;
+    let (client, alice) = emulator_with_funded_author().await;
     let bob = key_pair_with_funds(&client).await.public();
 
     let balance_alice = client.free_balance(&alice.public()).await.unwrap();
@@ -40,12 +40,34 @@ async fn transfer_fail() {
     assert!(tx_included.result.is_err());
 }
 
+/// Test that a transfer that would leave the sender below the existential deposit fails with
+/// [TransactionError::WouldReapAccount].
+#[async_std::test]
+async fn transfer_would_reap_account() {
+    let (client, alice) = emulator_with_funded_author().await;
+    let bob = ed25519::Pair::generate().0.public();
+
+    let fee = random_balance();
+    let balance_alice = client.free_balance(&alice.public()).await.unwrap();
+    let tx_included = submit_ok_with_fee(
+        &client,
+        &alice,
+        message::Transfer {
+            recipient: bob,
+            amount: balance_alice - fee,
+        },
+        fee,
+    )
+    .await;
+    assert_eq!(tx_included.result, Err(TransactionError::WouldReapAccount));
+    assert_eq!(client.free_balance(&bob).await.unwrap(), 0);
+}
+
 // Test that we can transfer any amount within a reasonable range.
 // Affected by the [crate::ExistentialDeposit] parameter.
 #[async_std::test]
 async fn transfer_any_amount() {
-    let (client, _) = Client::new_emulator();
-    let donator = key_pair_with_funds(&client).await;
+    let (client, donator) = emulator_with_funded_author().await;
     let receipient = ed25519::Pair::generate().0.public();
 
     for amount in (1..10000).step_by(500) {
@@ -58,12 +80,27 @@ async fn transfer_any_amount() {
             },
         )
         .await;
-        assert_eq!(
-            tx_included.result,
-            Ok(()),
-            "Failed to transfer {} μRAD",
-            amount
-        );
+        assert_result_ok(&tx_included);
+        assert_event_present(
+            &client,
+            &tx_included,
+            Event::pallet_balances(event::Balances::Transfer(
+                donator.public(),
+                receipient,
+                amount,
+            )),
+        )
+        .await;
+        assert_event_present(
+            &client,
+            &tx_included,
+            Event::registry(event::Registry::Transferred(
+                donator.public(),
+                receipient,
+                amount,
+            )),
+        )
+        .await;
     }
 }
 
@@ -106,7 +143,7 @@ async fn org_account_transfer() {
     let initial_balance_org = client.free_balance(&org.account_id()).await.unwrap();
     let org_transfer_fee = random_balance();
     let org_transfer_amount = 1000;
-    submit_ok_with_fee(
+    let tx_included = submit_ok_with_fee(
         &client,
         &author,
         message::TransferFromOrg {
@@ -117,6 +154,17 @@ async fn org_account_transfer() {
         org_transfer_fee,
     )
     .await;
+    assert_result_ok(&tx_included);
+    assert_event_present(
+        &client,
+        &tx_included,
+        Event::registry(event::Registry::Transferred(
+            org.account_id(),
+            bob,
+            org_transfer_amount,
+        )),
+    )
+    .await;
     assert_eq!(client.free_balance(&bob).await.unwrap(), 1000);
     assert_eq!(
         client.free_balance(&org.account_id()).await.unwrap(),
@@ -160,3 +208,118 @@ async fn org_account_transfer_non_member() {
         "The tx fee was not charged properly."
     );
 }
+
+#[async_std::test]
+/// Test that [Client::fee_payer_for] returns the account that is charged the fee.
+async fn fee_payer_for() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+    let non_member = key_pair_with_funds(&client).await;
+
+    let transfer_from_org = message::TransferFromOrg {
+        org_id,
+        recipient: author.public(),
+        amount: 10,
+    };
+    assert_eq!(
+        client
+            .fee_payer_for(&author.public(), &transfer_from_org)
+            .await
+            .unwrap(),
+        org.account_id()
+    );
+    assert_eq!(
+        client
+            .fee_payer_for(&non_member.public(), &transfer_from_org)
+            .await
+            .unwrap(),
+        non_member.public()
+    );
+
+    let transfer = message::Transfer {
+        recipient: non_member.public(),
+        amount: 10,
+    };
+    assert_eq!(
+        client
+            .fee_payer_for(&author.public(), &transfer)
+            .await
+            .unwrap(),
+        author.public()
+    );
+
+    let org_balance = client.free_balance(&org.account_id()).await.unwrap();
+    let fee = random_balance();
+    submit_ok_with_fee(&client, &author, transfer_from_org, fee).await;
+    assert_eq!(
+        client.free_balance(&org.account_id()).await.unwrap(),
+        org_balance - 10 - fee
+    );
+}
+
+#[async_std::test]
+async fn multi_transfer() {
+    let (client, author) = emulator_with_funded_author().await;
+    let bob = ed25519::Pair::generate().0.public();
+    let carol = ed25519::Pair::generate().0.public();
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::MultiTransfer {
+            transfers: vec![(bob, 1000), (carol, 2000), (bob, 500)],
+        },
+    )
+    .await;
+    assert_result_ok(&tx_included);
+    assert_eq!(client.free_balance(&bob).await.unwrap(), 1500);
+    assert_eq!(client.free_balance(&carol).await.unwrap(), 2000);
+    assert_event_present(
+        &client,
+        &tx_included,
+        Event::registry(event::Registry::MultiTransferred(author.public(), 3, 3500)),
+    )
+    .await;
+}
+
+#[async_std::test]
+/// Test that no funds are transferred if the sender cannot pay for all transfers.
+async fn multi_transfer_insufficient_funds() {
+    let (client, author) = emulator_with_funded_author().await;
+    let bob = ed25519::Pair::generate().0.public();
+    let carol = ed25519::Pair::generate().0.public();
+
+    let balance_author = client.free_balance(&author.public()).await.unwrap();
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::MultiTransfer {
+            transfers: vec![(bob, 1000), (carol, balance_author)],
+        },
+    )
+    .await;
+    assert!(tx_included.result.is_err());
+    assert_eq!(client.free_balance(&bob).await.unwrap(), 0);
+    assert_eq!(client.free_balance(&carol).await.unwrap(), 0);
+}
+
+#[async_std::test]
+async fn multi_transfer_too_many_recipients() {
+    let (client, author) = emulator_with_funded_author().await;
+    let bob = ed25519::Pair::generate().0.public();
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::MultiTransfer {
+            transfers: vec![(bob, 1); MAX_MULTI_TRANSFER_RECIPIENTS as usize + 1],
+        },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::TooManyRecipients.into())
+    );
+    assert_eq!(client.free_balance(&bob).await.unwrap(), 0);
+}
@@ -23,15 +23,14 @@ use radicle_registry_test_utils::*;
 
 #[async_std::test]
 async fn register_user() {
-    let (client, _) = Client::new_emulator();
-    let alice = key_pair_with_funds(&client).await;
+    let (client, alice) = emulator_with_funded_author().await;
     let initial_balance = client.free_balance(&alice.public()).await.unwrap();
 
     let register_user_message = random_register_user_message();
     let random_fee = random_balance();
     let tx_included =
         submit_ok_with_fee(&client, &alice, register_user_message.clone(), random_fee).await;
-    assert_eq!(tx_included.result, Ok(()));
+    assert_result_ok(&tx_included);
 
     assert!(
         user_exists(&client, register_user_message.user_id.clone()).await,
@@ -45,15 +44,91 @@ async fn register_user() {
     );
 
     let user = client
-        .get_user(register_user_message.user_id.clone())
+        .registry()
+        .users()
+        .get(register_user_message.user_id.clone())
         .await
         .unwrap()
         .unwrap();
     assert!(user.projects().is_empty());
+    assert_eq!(
+        client.user_of_account(alice.public()).await.unwrap(),
+        Some(register_user_message.user_id.clone())
+    );
+
+    let block_header = client
+        .block_header(tx_included.block)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.registered_at(), block_header.number);
+}
+
+#[async_std::test]
+async fn get_users() {
+    let (client, _) = Client::new_emulator();
+    let (_, alice_id) = key_pair_with_associated_user(&client).await;
+    let (_, bob_id) = key_pair_with_associated_user(&client).await;
+
+    let users = client
+        .get_users(vec![alice_id.clone(), random_id(), bob_id.clone()])
+        .await
+        .unwrap();
+
+    assert_eq!(users.len(), 3);
+    assert_eq!(
+        users[0],
+        client.registry().users().get(alice_id).await.unwrap()
+    );
+    assert_eq!(users[1], None);
+    assert_eq!(
+        users[2],
+        client.registry().users().get(bob_id).await.unwrap()
+    );
+    assert!(users[0].is_some());
 }
 
 /// Verify that it fails to register a user if the author has insufficient funds to
 /// pay for the registration fee.
+/// Test that [Client::ensure_user_registered] registers a user only once.
+#[async_std::test]
+async fn ensure_user_registered() {
+    let (client, author) = emulator_with_funded_author().await;
+    let user_id = random_id();
+
+    let created = client
+        .ensure_user_registered(&author, user_id.clone(), random_balance())
+        .await
+        .unwrap();
+    assert!(created);
+    assert!(user_exists(&client, user_id.clone()).await);
+
+    let created = client
+        .ensure_user_registered(&author, user_id, random_balance())
+        .await
+        .unwrap();
+    assert!(!created);
+}
+
+/// Test that [Client::ensure_user_registered] fails if the user is associated with a different
+/// account.
+#[async_std::test]
+async fn ensure_user_registered_with_other_account() {
+    let (client, _) = Client::new_emulator();
+    let (_, user_id) = key_pair_with_associated_user(&client).await;
+    let author = key_pair_with_funds(&client).await;
+
+    let result = client
+        .ensure_user_registered(&author, user_id, random_balance())
+        .await;
+    match result {
+        Err(Error::RegistrationFailed(TransactionError::RegistryError(
+            RegistryError::IdAlreadyTaken,
+        ))) => {}
+        other => panic!("Unexpected result {:?}", other),
+    }
+}
+
 #[async_std::test]
 async fn register_user_with_insufficient_funds_for_registration_fee() {
     let (client, _) = Client::new_emulator();
@@ -98,45 +173,54 @@ async fn register_user_with_insufficient_funds_for_registration_fee() {
 /// Test that a user can not be registered with an id already taken by another user.
 #[async_std::test]
 async fn register_with_id_taken_by_user() {
-    let (client, _) = Client::new_emulator();
-    let author_x = key_pair_with_funds(&client).await;
-
-    let register_user_message = random_register_user_message();
-    let tx_included_once = submit_ok(&client, &author_x, register_user_message.clone()).await;
-    assert!(tx_included_once.result.is_ok());
-
-    let author_y = key_pair_with_funds(&client).await;
-    let tx_included_twice = submit_ok(&client, &author_y, register_user_message.clone()).await;
-    assert_eq!(
-        tx_included_twice.result,
-        Err(RegistryError::IdAlreadyTaken.into())
-    )
+    for_each_backend(|client| async move {
+        let author_x = key_pair_with_funds(&client).await;
+
+        let register_user_message = random_register_user_message();
+        let tx_included_once = submit_ok(&client, &author_x, register_user_message.clone()).await;
+        assert!(tx_included_once.result.is_ok());
+
+        let author_y = key_pair_with_funds(&client).await;
+        let tx_included_twice = submit_ok(&client, &author_y, register_user_message.clone()).await;
+        assert_eq!(
+            tx_included_twice.result,
+            Err(RegistryError::IdAlreadyTaken.into())
+        )
+    })
+    .await;
 }
 
 /// Test that a user can not be registered with an id already taken by an org.
 #[async_std::test]
 async fn register_with_id_taken_by_org() {
-    let (client, _) = Client::new_emulator();
-    let (author_x, _) = key_pair_with_associated_user(&client).await;
-    let id = random_id();
-
-    let register_org_message = message::RegisterOrg { org_id: id.clone() };
-    let tx_included_org = submit_ok(&client, &author_x, register_org_message.clone()).await;
-    assert_eq!(tx_included_org.result, Ok(()));
-
-    let author_y = key_pair_with_funds(&client).await;
-    let register_user_message = message::RegisterUser { user_id: id };
-    let tx_included_user = submit_ok(&client, &author_y, register_user_message.clone()).await;
-    assert_eq!(
-        tx_included_user.result,
-        Err(RegistryError::IdAlreadyTaken.into())
-    );
+    for_each_backend(|client| async move {
+        let (author_x, _) = key_pair_with_associated_user(&client).await;
+        let id = random_id();
+
+        let register_org_message = message::RegisterOrg {
+            org_id: id.clone(),
+            reservation_salt: None,
+        };
+        let tx_included_org = submit_ok(&client, &author_x, register_org_message.clone()).await;
+        assert_eq!(tx_included_org.result, Ok(()));
+
+        let author_y = key_pair_with_funds(&client).await;
+        let register_user_message = message::RegisterUser {
+            user_id: id,
+            reservation_salt: None,
+        };
+        let tx_included_user = submit_ok(&client, &author_y, register_user_message.clone()).await;
+        assert_eq!(
+            tx_included_user.result,
+            Err(RegistryError::IdAlreadyTaken.into())
+        );
+    })
+    .await;
 }
 
 #[async_std::test]
 async fn register_user_with_already_associated_account() {
-    let (client, _) = Client::new_emulator();
-    let alice = key_pair_with_funds(&client).await;
+    let (client, alice) = emulator_with_funded_author().await;
     let register_first_user_message = random_register_user_message();
 
     let tx_included_first = submit_ok(&client, &alice, register_first_user_message.clone()).await;
@@ -167,6 +251,7 @@ async fn register_with_id_of_unregistered_user() {
     // Try to re-register User with the unregistered id
     let register_user_message = message::RegisterUser {
         user_id: user_id.clone(),
+        reservation_salt: None,
     };
     let tx_included = submit_ok(&client, &author, register_user_message.clone()).await;
     assert_eq!(tx_included.result, Err(RegistryError::IdRetired.into()));
@@ -199,6 +284,7 @@ async fn register_with_id_of_unregistered_org() {
     // Try to register a user with the unregistered org id
     let register_user_message = message::RegisterUser {
         user_id: register_org_message.org_id.clone(),
+        reservation_salt: None,
     };
     let tx_included = submit_ok(&client, &author, register_user_message.clone()).await;
     assert_eq!(tx_included.result, Err(RegistryError::IdRetired.into()));
@@ -206,8 +292,7 @@ async fn register_with_id_of_unregistered_org() {
 
 #[async_std::test]
 async fn unregister_user() {
-    let (client, _) = Client::new_emulator();
-    let alice = key_pair_with_funds(&client).await;
+    let (client, alice) = emulator_with_funded_author().await;
 
     // Registration.
     let register_user_message = random_register_user_message();
@@ -232,6 +317,7 @@ async fn unregister_user() {
         !user_exists(&client, register_user_message.user_id.clone()).await,
         "The user was not expected to exist"
     );
+    assert_eq!(client.user_of_account(alice.public()).await.unwrap(), None);
     assert_eq!(
         client.free_balance(&alice.public()).await.unwrap(),
         initial_balance - random_fee,
@@ -247,7 +333,13 @@ async fn unregister_user_member_of_an_org() {
     // Have user registering an org, which sets the associated user as its single member.
     let register_org = random_register_org_message();
     submit_ok(&client, &author, register_org.clone()).await;
-    let org = client.get_org(register_org.org_id).await.unwrap().unwrap();
+    let org = client
+        .registry()
+        .orgs()
+        .get(register_org.org_id)
+        .await
+        .unwrap()
+        .unwrap();
     assert_eq!(org.members(), &vec![user_id.clone()]);
 
     // Unregistration.
@@ -287,7 +379,13 @@ async fn unregister_user_with_projects() {
 
     // Create a project under the user
     create_project(&client, &author, &domain).await;
-    let user = client.get_user(user_id.clone()).await.unwrap().unwrap();
+    let user = client
+        .registry()
+        .users()
+        .get(user_id.clone())
+        .await
+        .unwrap()
+        .unwrap();
     assert_eq!(user.projects().len(), 1);
 
     // Unregister the user
@@ -331,8 +429,7 @@ async fn unregister_user_with_invalid_sender() {
 
 #[async_std::test]
 async fn unregister_user_with_no_associated_user() {
-    let (client, _) = Client::new_emulator();
-    let alice = key_pair_with_funds(&client).await;
+    let (client, alice) = emulator_with_funded_author().await;
     let initial_balance = client.free_balance(&alice.public()).await.unwrap();
     let unregister_user_message = message::UnregisterUser {
         user_id: random_id(),
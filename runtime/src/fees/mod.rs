@@ -17,16 +17,18 @@
 
 use crate::{AccountId, Balance, Call};
 
+use core::convert::TryFrom;
 use frame_support::dispatch::DispatchInfo;
 use parity_scale_codec::{Decode, Encode};
 use sp_runtime::traits::SignedExtension;
 use sp_runtime::transaction_validity::{
-    InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+    InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError,
+    ValidTransaction,
 };
 
 mod payment;
 
-pub use payment::{pay_registration_fee, pay_tx_fee};
+pub use payment::{burn_share, pay_registration_fee, pay_tx_fee, payer_org, BURN_SHARE};
 
 /// The minimum acceptable tx fee
 pub const MINIMUM_TX_FEE: Balance = 1;
@@ -68,7 +70,86 @@ impl SignedExtension for PayTxFee {
         pay_tx_fee(author, self.fee, call).map_err(|_| error)?;
 
         let mut valid_tx = ValidTransaction::default();
-        valid_tx.priority = self.fee as u64;
+        valid_tx.priority = priority(self.fee);
         Ok(valid_tx)
     }
 }
+
+/// Transaction priority for the given fee.
+///
+/// Fees that do not fit into the priority type get the maximum priority instead of wrapping
+/// around.
+fn priority(fee: Balance) -> TransactionPriority {
+    TransactionPriority::try_from(fee).unwrap_or(TransactionPriority::max_value())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{genesis::GenesisConfig, runtime::Balances};
+
+    use frame_support::traits::Currency;
+    use sp_core::{crypto::Pair as _, ed25519};
+    use sp_runtime::BuildStorage;
+
+    /// Run `f` with Alice’s account funded with `balance`.
+    fn with_funded_alice(balance: Balance, f: impl FnOnce(AccountId)) {
+        let genesis_config = GenesisConfig {
+            pallet_balances: None,
+            pallet_sudo: None,
+            registry: None,
+            system: None,
+        };
+        let mut test_ext = sp_io::TestExternalities::new(genesis_config.build_storage().unwrap());
+        test_ext.execute_with(move || {
+            let alice = ed25519::Pair::from_string("//Alice", None)
+                .unwrap()
+                .public();
+            let _imbalance = Balances::deposit_creating(&alice, balance);
+            f(alice)
+        });
+    }
+
+    fn validate(author: &AccountId, fee: Balance) -> TransactionValidity {
+        let call = Call::Registry(crate::registry::Call::transfer(
+            radicle_registry_core::message::Transfer {
+                recipient: *author,
+                amount: 1,
+            },
+        ));
+        PayTxFee { fee }.validate(author, &call, &DispatchInfo::default(), 0)
+    }
+
+    /// Test that the transaction pool prefers the transaction with the higher fee.
+    #[test]
+    fn higher_fee_higher_priority() {
+        with_funded_alice(10_000, |alice| {
+            let low = validate(&alice, 10).unwrap();
+            let high = validate(&alice, 1000).unwrap();
+            assert_eq!(low.priority, 10);
+            assert_eq!(high.priority, 1000);
+            assert!(high.priority > low.priority);
+        });
+    }
+
+    #[test]
+    fn priority_saturates() {
+        let fee = Balance::from(TransactionPriority::max_value()) + 2;
+        with_funded_alice(fee * 2, |alice| {
+            let valid_tx = validate(&alice, fee).unwrap();
+            assert_eq!(valid_tx.priority, TransactionPriority::max_value());
+        });
+    }
+
+    #[test]
+    fn fee_below_minimum_invalid() {
+        with_funded_alice(10_000, |alice| {
+            assert_eq!(
+                validate(&alice, MINIMUM_TX_FEE - 1),
+                Err(TransactionValidityError::Invalid(
+                    InvalidTransaction::Payment
+                ))
+            );
+        });
+    }
+}
@@ -26,7 +26,20 @@ use sp_runtime::Permill;
 type NegativeImbalance = <crate::runtime::Balances as Currency<AccountId>>::NegativeImbalance;
 
 /// Share of a transaction fee that is burned rather than credited to the block author.
-const BURN_SHARE: Permill = Permill::from_percent(1);
+///
+/// No fees are burned if [store::BurnFees] is disabled in the genesis config.
+pub const BURN_SHARE: Permill = Permill::from_percent(1);
+
+/// The share of transaction fees that is burned on this chain.
+///
+/// Returns [BURN_SHARE] if [store::BurnFees] is set and zero otherwise.
+pub fn burn_share() -> Permill {
+    if store::BurnFees::get() {
+        BURN_SHARE
+    } else {
+        Permill::zero()
+    }
+}
 
 pub fn pay_tx_fee(author: &AccountId, fee: Balance, call: &Call) -> Result<(), DispatchError> {
     let payer = payer_account(*author, call);
@@ -35,7 +48,7 @@ pub fn pay_tx_fee(author: &AccountId, fee: Balance, call: &Call) -> Result<(), D
         &payer,
         WithdrawReason::TransactionPayment | WithdrawReason::Tip,
     )?;
-    let (burn, reward) = withdrawn_fee.split(BURN_SHARE * fee);
+    let (burn, reward) = withdrawn_fee.split(burn_share() * fee);
     drop(burn);
 
     // The block author is only available when this function is run as part of the block execution.
@@ -71,33 +84,58 @@ fn withdraw(
 /// Authorize calls that involve another paying entity than the tx author.
 /// The tx author pays for all unauthorized calls.
 fn payer_account(author: AccountId, call: &Call) -> AccountId {
+    match payer_org(call) {
+        Some(org_id) => org_payer_account(author, org_id),
+        None => author,
+    }
+}
+
+/// Find the org whose account pays for the given runtime call.
+///
+/// The org only pays if the author of the call is a member of the org.
+/// Returns `None` if the author pays for the call in any case.
+pub fn payer_org(call: &Call) -> Option<&Id> {
     match call {
         Call::Registry(registry_call) => match registry_call {
             // Transactions payed by the org
             call::Registry::register_project(m) => match &m.project_domain {
-                ProjectDomain::Org(org_id) => org_payer_account(author, org_id),
-                ProjectDomain::User(_user_id) => author,
+                ProjectDomain::Org(org_id) => Some(org_id),
+                ProjectDomain::User(_user_id) => None,
+            },
+            call::Registry::unregister_project(m) => match &m.project_domain {
+                ProjectDomain::Org(org_id) => Some(org_id),
+                ProjectDomain::User(_user_id) => None,
             },
-            call::Registry::transfer_from_org(m) => org_payer_account(author, &m.org_id),
-            call::Registry::register_member(m) => org_payer_account(author, &m.org_id),
+            call::Registry::transfer_from_org(m) => Some(&m.org_id),
+            call::Registry::register_member(m) => Some(&m.org_id),
 
             // Transactions paid by the author
             call::Registry::register_org(_)
             | call::Registry::unregister_org(_)
             | call::Registry::transfer(_)
+            | call::Registry::multi_transfer(_)
             | call::Registry::register_user(_)
-            | call::Registry::unregister_user(_) => author,
+            | call::Registry::unregister_user(_)
+            | call::Registry::reserve_id(_)
+            | call::Registry::cancel_reservation(_)
+            | call::Registry::leave_org(_) => None,
+
+            // The author pays once for all calls in a batch
+            call::Registry::batch(_) => None,
+
+            // Transactions dispatched by the sudo key
+            call::Registry::accept_project(_) | call::Registry::reject_project(_) => None,
 
-            // Inherents
-            call::Registry::set_block_author(_) => {
-                panic!("Inherent calls are not allowed for signed extrinsics")
+            // Unsigned calls
+            call::Registry::set_block_author(_) | call::Registry::faucet(_) => {
+                panic!("Unsigned calls are not allowed for signed extrinsics")
             }
 
             crate::registry::Call::__PhantomItem(_, _) => {
                 unreachable!("__PhantomItem should never be used.")
             }
         },
-        _ => author,
+        _ => None,
     }
 }
 
@@ -132,6 +170,7 @@ mod test {
         let genesis_config = GenesisConfig {
             pallet_balances: None,
             pallet_sudo: None,
+            registry: None,
             system: None,
         };
 
@@ -148,6 +187,7 @@ mod test {
 
             let call = call::Registry::register_user(message::RegisterUser {
                 user_id: Id::try_from("alice").unwrap(),
+                reservation_salt: None,
             })
             .into();
             let fee = 1000;
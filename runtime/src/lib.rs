@@ -39,18 +39,18 @@ pub use sp_version::RuntimeVersion;
 pub use radicle_registry_core::*;
 pub use runtime::api as runtime_api;
 pub use runtime::api::{api, RuntimeApi};
-pub use runtime::{Call, Event, Origin, Runtime};
+pub use runtime::{
+    AvailableBlockRatio, BlockHashCount, Call, Event, MaximumBlockLength, Origin, Runtime,
+};
 
 pub mod fees;
+pub mod longevity;
 pub mod registry;
 mod runtime;
 pub mod timestamp_in_digest;
 
 pub use registry::DecodeKey;
 
-/// An index to a block.
-pub type BlockNumber = u32;
-
 /// Alias to 512-bit hash when used in the context of a transaction signature on the chain.
 pub type Signature = ed25519::Signature;
 
@@ -73,6 +73,7 @@ pub type SignedExtra = (
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
     crate::fees::PayTxFee,
+    crate::longevity::TxLongevity,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -81,7 +82,7 @@ pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<AccountId, Call, Signa
 /// A timestamp: milliseconds since the unix epoch.
 type Moment = u64;
 
-pub const SPEC_VERSION: u32 = 19;
+pub const SPEC_VERSION: u32 = 42;
 
 /// This runtime version.
 pub const VERSION: RuntimeVersion = RuntimeVersion {
@@ -121,6 +122,9 @@ pub fn native_version() -> sp_version::NativeVersion {
 pub mod store {
     pub use crate::registry::store::*;
     pub type Account = frame_system::Account<crate::Runtime>;
+    pub type SudoKey = pallet_sudo::Key<crate::Runtime>;
+    pub type Timestamp = pallet_timestamp::Now<crate::Runtime>;
+    pub type TotalIssuance = pallet_balances::TotalIssuance<crate::Runtime>;
     #[doc(inline)]
     pub use crate::registry::DecodeKey;
 }
@@ -129,6 +133,9 @@ pub mod event {
     pub use crate::runtime::Event;
     pub type Record = frame_system::EventRecord<crate::runtime::Event, crate::Hash>;
     pub type System = frame_system::Event<crate::Runtime>;
+    pub type Sudo = pallet_sudo::Event<crate::Runtime>;
+    pub type Balances = pallet_balances::Event<crate::Runtime>;
+    pub type Registry = crate::registry::Event;
 
     /// Return the index of the transaction in the block that dispatched the event.
     ///
@@ -142,6 +149,10 @@ pub mod event {
     }
 }
 
+pub mod error {
+    pub type Balances = pallet_balances::Error<crate::Runtime>;
+}
+
 pub mod call {
     pub type Registry = crate::registry::Call<crate::Runtime>;
     pub type System = frame_system::Call<crate::Runtime>;
@@ -150,5 +161,7 @@ pub mod call {
 
 #[cfg(feature = "std")]
 pub mod genesis {
-    pub use crate::runtime::{BalancesConfig, GenesisConfig, SudoConfig, SystemConfig};
+    pub use crate::runtime::{
+        BalancesConfig, GenesisConfig, RegistryConfig, SudoConfig, SystemConfig,
+    };
 }
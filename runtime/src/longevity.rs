@@ -0,0 +1,104 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Bound the time a transaction stays in the transaction pool with the [TxLongevity]
+//! [SignedExtension].
+
+use crate::{AccountId, BlockNumber, Call};
+
+use frame_support::dispatch::DispatchInfo;
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::traits::SignedExtension;
+use sp_runtime::transaction_validity::{
+    InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+};
+
+/// A longevity of zero blocks would make the transaction invalid before it can be included.
+const ZERO_LONGEVITY_ERROR: InvalidTransaction = InvalidTransaction::Custom(20);
+
+/// Number of blocks after which a transaction that has not been included is dropped from the
+/// transaction pool.
+///
+/// If `longevity` is `None` the transaction stays in the pool until it is included or the node
+/// drops it. Transactions with a longevity of zero are invalid.
+#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq)]
+pub struct TxLongevity {
+    pub longevity: Option<BlockNumber>,
+}
+
+impl SignedExtension for TxLongevity {
+    const IDENTIFIER: &'static str = "TxLongevity";
+
+    type AccountId = AccountId;
+    type Call = Call;
+    type AdditionalSigned = ();
+    type Pre = ();
+
+    fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        _author: &Self::AccountId,
+        _call: &Self::Call,
+        _info: &DispatchInfo,
+        _len: usize,
+    ) -> TransactionValidity {
+        let mut valid_tx = ValidTransaction::default();
+        if let Some(longevity) = self.longevity {
+            if longevity == 0 {
+                return Err(ZERO_LONGEVITY_ERROR.into());
+            }
+            valid_tx.longevity = u64::from(longevity);
+        }
+        Ok(valid_tx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use sp_core::{crypto::Pair as _, ed25519};
+
+    fn validate(longevity: Option<BlockNumber>) -> TransactionValidity {
+        let author = ed25519::Pair::from_string("//Alice", None).unwrap();
+        let call = Call::Registry(crate::registry::Call::transfer(
+            radicle_registry_core::message::Transfer {
+                recipient: author.public(),
+                amount: 1,
+            },
+        ));
+        TxLongevity { longevity }.validate(&author.public(), &call, &DispatchInfo::default(), 0)
+    }
+
+    #[test]
+    fn longevity_is_set() {
+        assert_eq!(validate(Some(64)).unwrap().longevity, 64);
+    }
+
+    #[test]
+    fn zero_longevity_is_invalid() {
+        assert_eq!(validate(Some(0)), Err(ZERO_LONGEVITY_ERROR.into()));
+    }
+
+    #[test]
+    fn longevity_defaults_to_unbounded() {
+        assert_eq!(
+            validate(None).unwrap().longevity,
+            ValidTransaction::default().longevity
+        );
+    }
+}
@@ -17,22 +17,30 @@ use alloc::vec;
 use alloc::vec::Vec;
 
 use frame_support::{
-    decl_module, decl_storage,
+    decl_event, decl_module, decl_storage,
     dispatch::DispatchResult,
-    storage::{IterableStorageMap, StorageMap, StorageValue as _},
-    traits::{Currency, ExistenceRequirement, Randomness as _},
-    weights::Pays,
+    storage::{
+        with_transaction, IterableStorageDoubleMap, IterableStorageMap, StorageDoubleMap,
+        StorageMap, StorageValue as _, TransactionOutcome,
+    },
+    traits::{
+        Currency, ExistenceRequirement, Get as _, Randomness as _, ReservableCurrency,
+        UnfilteredDispatchable as _,
+    },
+    weights::{DispatchClass, Pays},
 };
-use frame_system::{ensure_none, ensure_signed};
+use frame_system::{ensure_none, ensure_root, ensure_signed};
+use parity_scale_codec::Encode as _;
 use sp_core::crypto::UncheckedFrom;
 
 use radicle_registry_core::*;
 
 use crate::{fees, AccountId, Hash};
 
+mod faucet;
 mod inherents;
 
-pub use inherents::AuthoringInherentData;
+pub use inherents::{check_block_author_inherent, AuthoringInherentData};
 
 pub trait Trait
 where
@@ -48,6 +56,7 @@ where
     Self: frame_system::Trait<
         BaseCallFilter = (),
         AccountId = AccountId,
+        BlockNumber = BlockNumber,
         Origin = crate::Origin,
         Call = crate::Call,
         Hash = Hash,
@@ -62,6 +71,32 @@ where
 /// Funds that are credited to the block author for every block.
 pub const BLOCK_REWARD: Balance = rad_to_balance(20);
 
+/// Number of blocks an ID reserved with [message::ReserveId] stays reserved.
+pub const ID_RESERVATION_PERIOD: BlockNumber = 100;
+
+/// Funds of the author that are reserved while an ID reserved with [message::ReserveId] stays
+/// reserved.
+pub const ID_RESERVATION_DEPOSIT: Balance = 10;
+
+/// Version of the storage layout. [store::StorageVersion] is set to this value by the storage
+/// migration in [Module::on_runtime_upgrade] and in the genesis block.
+pub const STORAGE_VERSION: u32 = 1;
+
+/// Maximum number of members an org can have.
+pub const MAX_ORG_MEMBERS: u32 = 100;
+
+/// Maximum amount of funds a single [message::Faucet] transaction can mint.
+pub const FAUCET_MAX_AMOUNT: Balance = rad_to_balance(100);
+
+/// Number of blocks a recipient has to wait between two [message::Faucet] transactions.
+pub const FAUCET_PERIOD: BlockNumber = 10;
+
+/// The maximum number of transfers in a [message::MultiTransfer].
+pub const MAX_MULTI_TRANSFER_RECIPIENTS: u32 = 100;
+
+/// The maximum number of calls in a [Call::batch].
+pub const MAX_BATCH_CALLS: u32 = 100;
+
 pub mod store {
     use super::*;
 
@@ -79,6 +114,30 @@ pub mod store {
             // [Call::set_block_author] and not persisted.
             pub BlockAuthor: Option<AccountId>;
 
+            // If true, the account of a newly registered org is derived from the org ID with
+            // [derived_org_account]. Otherwise the account is chosen at random. Set in the
+            // genesis config and never changed.
+            pub DeterministicOrgAccounts config(deterministic_org_accounts): bool;
+
+            // If true, anybody can mint funds with the unsigned [Call::faucet]. Set in the genesis
+            // config of test chains and never changed.
+            pub FaucetEnabled config(faucet_enabled): bool;
+
+            // If true, [fees::BURN_SHARE] of every transaction fee is burned. Otherwise the block
+            // author receives the whole fee. Set in the genesis config and never changed.
+            pub BurnFees config(burn_fees): bool = true;
+
+            // If true, [Call::register_project] adds projects to [PendingProjects1] and the sudo
+            // key accepts or rejects them. Set in the genesis config and never changed.
+            pub ProjectModeration config(project_moderation): bool;
+
+            // The version of the storage layout. The storage is migrated on runtime upgrade if the
+            // version is less than [STORAGE_VERSION].
+            pub StorageVersion build(|_| STORAGE_VERSION): u32;
+
+            // The block number of the last [Call::faucet] transaction for each recipient.
+            pub FaucetDrips1: map hasher(blake2_128_concat) AccountId => Option<BlockNumber>;
+
             // The below map indexes all retired user and org ids.
             // We use the blake2_128_concat hasher so that the Id
             // can be extracted from the key.
@@ -89,19 +148,70 @@ pub mod store {
             // can be extracted from the key.
             pub Orgs1: map hasher(blake2_128_concat) Id => Option<state::Orgs1Data>;
 
+            // Index of the members of each org, keyed by the org ID and the user ID. Mirrors the
+            // members of [Orgs1] so that members can be listed without reading the whole org.
+            // We use the blake2_128_concat hasher so that both IDs can be extracted from the key.
+            pub OrgMembers1: double_map hasher(blake2_128_concat) Id, hasher(blake2_128_concat) Id => ();
+
             // The storage for Users, indexed by Id.
             // We use the blake2_128_concat hasher so that the Id can be extraced from the key.
             pub Users1: map hasher(blake2_128_concat) Id => Option<state::Users1Data>;
 
+            // The ID of the user associated with an account. Reverse index of the account IDs
+            // in [Users1].
+            pub AccountUsers1: map hasher(blake2_128_concat) AccountId => Option<Id>;
+
             // We use the blake2_128_concat hasher so that the ProjectId can be extracted from the
             // key.
             pub Projects1: map hasher(blake2_128_concat) ProjectId => Option<state::Projects1Data>;
+
+            // Projects waiting to be accepted if [ProjectModeration] is enabled.
+            // We use the blake2_128_concat hasher so that the ProjectId can be extracted from the
+            // key.
+            pub PendingProjects1: map hasher(blake2_128_concat) ProjectId => Option<state::PendingProjects1Data>;
+
+            // The storage for ID reservations, keyed by the account that holds the reservation
+            // and the commitment to the reserved ID. Expired reservations are removed when a
+            // block is initialized.
+            // We use the blake2_128_concat hasher so that both keys can be extracted from the key.
+            pub Reservations1: double_map hasher(blake2_128_concat) AccountId, hasher(blake2_128_concat) Hash => Option<state::Reservations1Data>;
+
+            // The keys of the reservations in [Reservations1] indexed by the block number at which
+            // they expire. The entry for a block is removed when the block is initialized. A key
+            // stays in the index if its reservation is cancelled or consumed before it expires.
+            pub ReservationExpiries1: map hasher(twox_64_concat) BlockNumber => Vec<(AccountId, Hash)>;
         }
     }
 }
 
 pub use store::Store;
 
+#[cfg(feature = "std")]
+pub use store::GenesisConfig;
+
+decl_event!(
+    pub enum Event {
+        /// The sender transferred funds to multiple recipients with [message::MultiTransfer].
+        ///
+        /// Holds the sender, the number of transfers and the total amount transferred.
+        MultiTransferred(AccountId, u32, Balance),
+
+        /// Funds were transferred with [message::Transfer] or [message::TransferFromOrg].
+        ///
+        /// Holds the account the funds were transferred from, the recipient and the amount. For
+        /// [message::TransferFromOrg] the funds are transferred from the account of the org.
+        Transferred(AccountId, AccountId, Balance),
+
+        /// A project was unregistered with [message::UnregisterProject].
+        ProjectUnregistered(ProjectName, ProjectDomain),
+
+        /// A call of a [Call::batch] failed and the changes of all calls were reverted.
+        ///
+        /// Holds the index of the failed call in the batch and its error.
+        BatchInterrupted(u32, DispatchError),
+    }
+);
+
 decl_module! {
     pub struct Module<T: Trait> for enum Call where
         origin: T::Origin,
@@ -117,7 +227,8 @@ decl_module! {
             let sender = ensure_signed(origin)?;
 
             let project_id = (message.project_name.clone(), message.project_domain.clone());
-            if store::Projects1::get(project_id.clone()).is_some() {
+            if store::Projects1::contains_key(project_id.clone())
+                || store::PendingProjects1::contains_key(project_id.clone()) {
                 return Err(RegistryError::DuplicateProjectId.into());
             };
 
@@ -127,21 +238,53 @@ decl_module! {
                     if !org_has_member_with_account(&org, sender) {
                         return Err(RegistryError::InsufficientSenderPermissions.into());
                     }
-                    store::Orgs1::insert(org_id, org.add_project(message.project_name.clone()));
                 },
                 ProjectDomain::User(user_id) => {
                     let user = store::Users1::get(user_id).ok_or(RegistryError::InexistentUser)?;
                     if user.account_id() != sender {
                         return Err(RegistryError::InsufficientSenderPermissions.into());
                     }
-                    store::Users1::insert(user_id, user.add_project(message.project_name.clone()));
                 },
             };
 
-            let new_project = state::Projects1Data::new(
-                message.metadata
-            );
-            store::Projects1::insert(project_id, new_project);
+            if store::ProjectModeration::get() {
+                let pending_project = state::PendingProjects1Data::new(
+                    message.metadata,
+                    sender,
+                    frame_system::Module::<T>::block_number(),
+                );
+                store::PendingProjects1::insert(project_id, pending_project);
+            } else {
+                insert_project(message.project_name, message.project_domain, message.metadata)?;
+            }
+            Ok(())
+        }
+
+        #[weight = (0, Pays::No)]
+        pub fn accept_project(origin, message: message::AcceptProject) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let project_id = (message.project_name.clone(), message.project_domain.clone());
+            let pending_project = store::PendingProjects1::get(project_id.clone())
+                .ok_or(RegistryError::InexistentPendingProject)?;
+            insert_project(
+                message.project_name,
+                message.project_domain,
+                pending_project.metadata().clone(),
+            )?;
+            store::PendingProjects1::remove(project_id);
+            Ok(())
+        }
+
+        #[weight = (0, Pays::No)]
+        pub fn reject_project(origin, message: message::RejectProject) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let project_id = (message.project_name, message.project_domain);
+            if !store::PendingProjects1::contains_key(project_id.clone()) {
+                return Err(RegistryError::InexistentPendingProject.into());
+            }
+            store::PendingProjects1::remove(project_id);
             Ok(())
         }
 
@@ -162,25 +305,62 @@ decl_module! {
                 return Err(RegistryError::AlreadyAMember.into());
             }
 
+            if org.members().len() >= MAX_ORG_MEMBERS as usize {
+                return Err(RegistryError::MemberLimitReached.into());
+            }
+
             let org_with_member = org.add_member(message.user_id.clone());
+            store::OrgMembers1::insert(message.org_id.clone(), message.user_id, ());
             store::Orgs1::insert(message.org_id, org_with_member);
             Ok(())
         }
 
+        #[weight = (0, Pays::No)]
+        pub fn leave_org(origin, message: message::LeaveOrg) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let org = store::Orgs1::get(message.org_id.clone()).ok_or(RegistryError::InexistentOrg)?;
+            let user_id = get_user_id_with_account(sender).ok_or(RegistryError::AuthorHasNoAssociatedUser)?;
+            if !org.members().contains(&user_id) {
+                return Err(RegistryError::InsufficientSenderPermissions.into());
+            }
+            if org.members().len() == 1 {
+                return Err(RegistryError::LastOrgMember.into());
+            }
+
+            store::OrgMembers1::remove(message.org_id.clone(), &user_id);
+            store::Orgs1::insert(message.org_id, org.remove_member(&user_id));
+            Ok(())
+        }
+
         #[weight = (0, Pays::No)]
         pub fn register_org(origin, message: message::RegisterOrg) -> DispatchResult {
             let sender = ensure_signed(origin)?;
 
             ensure_id_is_available(&message.org_id)?;
+            let commitment = revealed_reservation(&message.org_id, sender, message.reservation_salt)?;
             let user_id = get_user_id_with_account(sender).ok_or(RegistryError::AuthorHasNoAssociatedUser)?;
             fees::pay_registration_fee(&sender)?;
-            let random_account_id = AccountId::unchecked_from(
-                pallet_randomness_collective_flip::Module::<T>::random(
-                    b"org-account-id",
+            let org_account_id = if store::DeterministicOrgAccounts::get() {
+                derived_org_account(&message.org_id)
+            } else {
+                AccountId::unchecked_from(
+                    pallet_randomness_collective_flip::Module::<T>::random(
+                        b"org-account-id",
+                    )
                 )
+            };
+            let new_org = state::Orgs1Data::new(
+                org_account_id,
+                vec![user_id.clone()],
+                Vec::new(),
+                frame_system::Module::<T>::block_number(),
             );
-            let new_org = state::Orgs1Data::new(random_account_id, vec![user_id],  Vec::new());
             store::Orgs1::insert(message.org_id.clone(), new_org);
+            store::OrgMembers1::insert(message.org_id.clone(), user_id, ());
+            if let Some(commitment) = commitment {
+                remove_reservation(sender, commitment);
+            }
             store::RetiredIds1::insert(message.org_id, ());
             Ok(())
         }
@@ -198,6 +378,7 @@ decl_module! {
                 None => Err(RegistryError::InexistentOrg.into()),
                 Some(org) => {
                     if can_be_unregistered(org, sender) {
+                        store::OrgMembers1::remove_prefix(message.org_id.clone());
                         store::Orgs1::remove(message.org_id);
                         Ok(())
                     }
@@ -213,8 +394,9 @@ decl_module! {
             let sender = ensure_signed(origin)?;
 
             ensure_id_is_available(&message.user_id)?;
+            let commitment = revealed_reservation(&message.user_id, sender, message.reservation_salt)?;
 
-            if get_user_with_account(sender).is_some() {
+            if store::AccountUsers1::contains_key(sender) {
                 return Err(RegistryError::UserAccountAssociated.into())
             }
 
@@ -222,12 +404,44 @@ decl_module! {
             let new_user = state::Users1Data::new(
                 sender,
                 Vec::new(),
+                frame_system::Module::<T>::block_number(),
             );
             store::Users1::insert(message.user_id.clone(), new_user);
+            store::AccountUsers1::insert(sender, message.user_id.clone());
+            if let Some(commitment) = commitment {
+                remove_reservation(sender, commitment);
+            }
             store::RetiredIds1::insert(message.user_id, ());
             Ok(())
         }
 
+        #[weight = (0, Pays::No)]
+        pub fn reserve_id(origin, message: message::ReserveId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            if active_reservation(sender, message.commitment).is_some() {
+                return Err(RegistryError::DuplicateReservation.into());
+            }
+
+            <crate::runtime::Balances as ReservableCurrency<_>>::reserve(&sender, ID_RESERVATION_DEPOSIT)?;
+            let expires_at = frame_system::Module::<T>::block_number() + ID_RESERVATION_PERIOD;
+            let reservation = state::Reservations1Data::new(expires_at);
+            store::ReservationExpiries1::mutate(expires_at, |keys| keys.push((sender, message.commitment)));
+            store::Reservations1::insert(sender, message.commitment, reservation);
+            Ok(())
+        }
+
+        #[weight = (0, Pays::No)]
+        pub fn cancel_reservation(origin, message: message::CancelReservation) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            active_reservation(sender, message.commitment)
+                .ok_or(RegistryError::InexistentReservation)?;
+
+            remove_reservation(sender, message.commitment);
+            Ok(())
+        }
+
         #[weight = (0, Pays::No)]
         pub fn unregister_user(origin, message: message::UnregisterUser) -> DispatchResult {
 
@@ -242,6 +456,7 @@ decl_module! {
             }
 
             store::Users1::remove(user_id);
+            store::AccountUsers1::remove(sender);
             Ok(())
         }
 
@@ -251,17 +466,18 @@ decl_module! {
             let org = store::Orgs1::get(message.org_id)
                 .ok_or(RegistryError::InexistentOrg)?;
 
-            if org_has_member_with_account(&org, sender) {
-                <crate::runtime::Balances as Currency<_>>::transfer(
-                    &org.account_id(),
-                    &message.recipient,
-                    message.amount,
-                    ExistenceRequirement::KeepAlive
-                )
-            }
-            else {
-                Err(RegistryError::InsufficientSenderPermissions.into())
+            if !org_has_member_with_account(&org, sender) {
+                return Err(RegistryError::InsufficientSenderPermissions.into());
             }
+
+            <crate::runtime::Balances as Currency<_>>::transfer(
+                &org.account_id(),
+                &message.recipient,
+                message.amount,
+                ExistenceRequirement::KeepAlive
+            )?;
+            deposit_event(Event::Transferred(org.account_id(), message.recipient, message.amount));
+            Ok(())
         }
 
         #[weight = (0, Pays::No)]
@@ -273,10 +489,26 @@ decl_module! {
                 &message.recipient,
                 message.amount,
                 ExistenceRequirement::KeepAlive
-            )
+            )?;
+            deposit_event(Event::Transferred(sender, message.recipient, message.amount));
+            Ok(())
         }
 
-        #[weight = (0, Pays::No)]
+        // Reads and writes the recipient account and the last drip of the recipient.
+        #[weight = (T::DbWeight::get().reads_writes(2, 2), Pays::No)]
+        pub fn faucet(origin, message: message::Faucet) -> DispatchResult {
+            ensure_none(origin)?;
+            // The faucet parameters are checked by the `ValidateUnsigned` implementation before
+            // the call is dispatched.
+            let imbalance = crate::runtime::Balances::deposit_creating(&message.recipient, message.amount);
+            drop(imbalance);
+            store::FaucetDrips1::insert(message.recipient, frame_system::Module::<T>::block_number());
+            Ok(())
+        }
+
+        // The inherent must be included in every block so it is dispatched with the
+        // `Mandatory` class which is not limited by the block weight.
+        #[weight = (T::DbWeight::get().reads_writes(1, 1), DispatchClass::Mandatory, Pays::No)]
         fn set_block_author(origin, author: AccountId) -> DispatchResult {
             assert!(ensure_none(origin).is_ok(), "set_block_author call is only valid as an inherent");
             assert!(store::BlockAuthor::get().is_none(), "set_block_author can only be called once");
@@ -284,7 +516,161 @@ decl_module! {
             Ok(())
         }
 
+        // Reads and writes the sender account and the account of every recipient.
+        #[weight = (
+            T::DbWeight::get().reads_writes(
+                1 + message.transfers.len() as u64,
+                1 + message.transfers.len() as u64,
+            ),
+            Pays::No,
+        )]
+        pub fn multi_transfer(origin, message: message::MultiTransfer) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            if message.transfers.len() > MAX_MULTI_TRANSFER_RECIPIENTS as usize {
+                return Err(RegistryError::TooManyRecipients.into());
+            }
+
+            let total = transfers_total(&message.transfers)?;
+            // A transfer may fail after earlier ones have been applied. All transfers are
+            // reverted in that case.
+            with_transaction(|| match apply_transfers(&sender, &message.transfers) {
+                Ok(()) => TransactionOutcome::Commit(Ok(())),
+                Err(error) => TransactionOutcome::Rollback(Err(error)),
+            })?;
+
+            deposit_event(Event::MultiTransferred(sender, message.transfers.len() as u32, total));
+            Ok(())
+        }
+
+        /// Dispatch the registry `calls` in order with the origin of the batch.
+        ///
+        /// The calls are applied atomically. If one call fails, the storage changes of all calls
+        /// in the batch are reverted, [Event::BatchInterrupted] is deposited with the index of
+        /// the failed call, and the batch fails with the error of that call. The transaction fee
+        /// is paid once by the author of the batch.
+        #[weight = (0, Pays::No)]
+        pub fn batch(origin, calls: Vec<crate::Call>) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            if calls.len() > MAX_BATCH_CALLS as usize {
+                return Err(RegistryError::TooManyBatchCalls.into());
+            }
+
+            let result = with_transaction(|| match dispatch_batch(sender, calls) {
+                Ok(()) => TransactionOutcome::Commit(Ok(())),
+                Err(error) => TransactionOutcome::Rollback(Err(error)),
+            });
+            // The event is deposited after the rollback so that it is not reverted.
+            result.map_err(|(failed_index, error)| {
+                deposit_event(Event::BatchInterrupted(failed_index, error));
+                error
+            })
+        }
+
+        #[weight = (0, Pays::No)]
+        pub fn unregister_project(origin, message: message::UnregisterProject) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let project_id = (message.project_name.clone(), message.project_domain.clone());
+            if !store::Projects1::contains_key(project_id.clone()) {
+                return Err(RegistryError::InexistentProjectId.into());
+            }
+
+            match &message.project_domain {
+                ProjectDomain::Org(org_id) => {
+                    let org = store::Orgs1::get(org_id).ok_or(RegistryError::InexistentOrg)?;
+                    if !org_has_member_with_account(&org, sender) {
+                        return Err(RegistryError::InsufficientSenderPermissions.into());
+                    }
+                    store::Orgs1::insert(org_id, org.remove_project(&message.project_name));
+                },
+                ProjectDomain::User(user_id) => {
+                    let user = store::Users1::get(user_id).ok_or(RegistryError::InexistentUser)?;
+                    if user.account_id() != sender {
+                        return Err(RegistryError::InsufficientSenderPermissions.into());
+                    }
+                    store::Users1::insert(user_id, user.remove_project(&message.project_name));
+                },
+            };
+
+            store::Projects1::remove(project_id);
+            deposit_event(Event::ProjectUnregistered(message.project_name, message.project_domain));
+            Ok(())
+        }
+
+        fn on_initialize(block_number: T::BlockNumber) -> frame_support::weights::Weight {
+            // Remove the reservations that expire in this block. Only the reservations indexed
+            // for this block are read so the cost does not grow with the number of reservations.
+            let expiring_keys = store::ReservationExpiries1::take(block_number);
+            let mut expired_count = 0;
+            for (account_id, commitment) in expiring_keys.iter() {
+                let expired = store::Reservations1::get(account_id, commitment)
+                    .map_or(false, |reservation| reservation.expires_at() == block_number);
+                if expired {
+                    remove_reservation(*account_id, *commitment);
+                    expired_count += 1;
+                }
+            }
+            let key_count = expiring_keys.len() as u64;
+            T::DbWeight::get().reads_writes(1 + key_count, 1 + 2 * expired_count)
+        }
+
+        fn on_runtime_upgrade() -> frame_support::weights::Weight {
+            if store::StorageVersion::get() >= STORAGE_VERSION {
+                return T::DbWeight::get().reads(1);
+            }
+
+            // Index the users registered before [store::AccountUsers1], the org members added
+            // before [store::OrgMembers1], and the reservations made before
+            // [store::ReservationExpiries1] were introduced.
+            let mut user_count = 0;
+            for (user_id, user) in store::Users1::iter() {
+                store::AccountUsers1::insert(user.account_id(), user_id);
+                user_count += 1;
+            }
+            let mut org_count = 0;
+            let mut member_count = 0;
+            for (org_id, org) in store::Orgs1::iter() {
+                for member in org.members() {
+                    store::OrgMembers1::insert(org_id.clone(), member.clone(), ());
+                    member_count += 1;
+                }
+                org_count += 1;
+            }
+
+            // The index entries of past blocks are never taken again so reservations that have
+            // already expired are removed instead of indexed. The block number is still the
+            // number of the parent block.
+            let block_number = frame_system::Module::<T>::block_number();
+            let mut reservation_count = 0;
+            let mut expired_keys = Vec::new();
+            for (account_id, commitment, reservation) in store::Reservations1::iter() {
+                if reservation.expires_at() <= block_number {
+                    expired_keys.push((account_id, commitment));
+                } else {
+                    store::ReservationExpiries1::mutate(reservation.expires_at(), |keys| {
+                        let key = (account_id, commitment);
+                        if !keys.contains(&key) {
+                            keys.push(key);
+                        }
+                    });
+                }
+                reservation_count += 1;
+            }
+            let expired_count = expired_keys.len() as u64;
+            for (account_id, commitment) in expired_keys {
+                remove_reservation(account_id, commitment);
+            }
+
+            store::StorageVersion::put(STORAGE_VERSION);
+            T::DbWeight::get().reads_writes(
+                2 + user_count + org_count + 2 * reservation_count,
+                1 + user_count + member_count + reservation_count + expired_count,
+            )
+        }
+
         fn on_finalize() {
+            // Blocks without the `set_block_author` inherent are rejected on import by
+            // `check_block_author_inherent`.
             let block_author = store::BlockAuthor::take().expect("Block author must be set by an extrinsic");
             let imbalance = crate::runtime::Balances::deposit_creating(&block_author, BLOCK_REWARD);
             drop(imbalance);
@@ -293,6 +679,70 @@ decl_module! {
     }
 }
 
+/// Return the total amount of the `transfers` of a [message::MultiTransfer].
+fn transfers_total(transfers: &[(AccountId, Balance)]) -> Result<Balance, DispatchError> {
+    transfers
+        .iter()
+        .try_fold(0 as Balance, |total, (_, amount)| {
+            total.checked_add(*amount)
+        })
+        .ok_or_else(|| pallet_balances::Error::<crate::Runtime>::Overflow.into())
+}
+
+/// Transfer the amounts of `transfers` from `sender` to their recipients in order and stop at the
+/// first transfer that fails.
+///
+/// Transfers that were applied before the failed one are not reverted by this function. The
+/// caller must run it in a storage transaction that is rolled back on error. [Call::multi_transfer]
+/// does so to make [message::MultiTransfer] atomic.
+fn apply_transfers(sender: &AccountId, transfers: &[(AccountId, Balance)]) -> DispatchResult {
+    for (recipient, amount) in transfers {
+        <crate::runtime::Balances as Currency<_>>::transfer(
+            sender,
+            recipient,
+            *amount,
+            ExistenceRequirement::KeepAlive,
+        )?;
+    }
+    Ok(())
+}
+
+/// Dispatch the calls of a [Call::batch] with the signed origin of `sender` and stop at the first
+/// call that fails. Returns the index of the failed call and its error.
+///
+/// Only signed registry calls can be batched. Nested batches are rejected.
+fn dispatch_batch(sender: AccountId, calls: Vec<crate::Call>) -> Result<(), (u32, DispatchError)> {
+    for (index, call) in (0..).zip(calls) {
+        dispatch_batch_call(sender, call).map_err(|error| (index, error))?;
+    }
+    Ok(())
+}
+
+/// Dispatch a single call of a [Call::batch] with the origin of `sender`.
+fn dispatch_batch_call(sender: AccountId, call: crate::Call) -> DispatchResult {
+    let registry_call = match call {
+        crate::Call::Registry(Call::batch(_))
+        | crate::Call::Registry(Call::set_block_author(_))
+        | crate::Call::Registry(Call::faucet(_)) => {
+            return Err(RegistryError::InvalidBatchCall.into())
+        }
+        crate::Call::Registry(registry_call) => registry_call,
+        _ => return Err(RegistryError::InvalidBatchCall.into()),
+    };
+    let origin = frame_system::RawOrigin::Signed(sender).into();
+    registry_call
+        .dispatch_bypass_filter(origin)
+        .map_err(|error| error.error)
+}
+
+/// Deposit an event of the registry module.
+fn deposit_event(event: Event) {
+    frame_system::Module::<crate::Runtime>::deposit_event(crate::Event::from(event));
+}
+
+/// Check that `id` can be claimed.
+///
+/// The ID must not be taken or retired.
 fn ensure_id_is_available(id: &Id) -> Result<(), RegistryError> {
     if store::Users1::contains_key(id) || store::Orgs1::contains_key(id) {
         Err(RegistryError::IdAlreadyTaken)
@@ -303,15 +753,110 @@ fn ensure_id_is_available(id: &Id) -> Result<(), RegistryError> {
     }
 }
 
+/// Add the project to the projects of its domain and store it in [store::Projects1].
+///
+/// Fails if the domain does not exist.
+fn insert_project(
+    project_name: ProjectName,
+    project_domain: ProjectDomain,
+    metadata: Bytes128,
+) -> Result<(), RegistryError> {
+    match &project_domain {
+        ProjectDomain::Org(org_id) => {
+            let org = store::Orgs1::get(org_id).ok_or(RegistryError::InexistentOrg)?;
+            store::Orgs1::insert(org_id, org.add_project(project_name.clone()));
+        }
+        ProjectDomain::User(user_id) => {
+            let user = store::Users1::get(user_id).ok_or(RegistryError::InexistentUser)?;
+            store::Users1::insert(user_id, user.add_project(project_name.clone()));
+        }
+    };
+    store::Projects1::insert(
+        (project_name, project_domain),
+        state::Projects1Data::new(metadata),
+    );
+    Ok(())
+}
+
+/// Returns true if the org or user identified by `domain` exists.
+pub fn project_domain_exists(domain: &ProjectDomain) -> bool {
+    match domain {
+        ProjectDomain::Org(org_id) => store::Orgs1::contains_key(org_id),
+        ProjectDomain::User(user_id) => store::Users1::contains_key(user_id),
+    }
+}
+
+/// Derive the account of the org with the given ID from the Blake2 256 hash of the SCALE encoded
+/// ID.
+///
+/// This account is assigned to newly registered orgs if [store::DeterministicOrgAccounts] is set.
+pub fn derived_org_account(org_id: &Id) -> AccountId {
+    AccountId::unchecked_from(sp_io::hashing::blake2_256(&org_id.encode()))
+}
+
+/// Get the reservation of `account_id` with `commitment` if it has not expired yet.
+fn active_reservation(account_id: AccountId, commitment: Hash) -> Option<state::Reservations1Data> {
+    let block_number = frame_system::Module::<crate::Runtime>::block_number();
+    store::Reservations1::get(account_id, commitment)
+        .filter(|reservation| reservation.expires_at() > block_number)
+}
+
+/// Return the commitment of the reservation that `account_id` reveals by registering `id` with
+/// `salt`.
+///
+/// Fails if `salt` is given and `account_id` holds no active reservation with the commitment.
+fn revealed_reservation(
+    id: &Id,
+    account_id: AccountId,
+    salt: Option<Hash>,
+) -> Result<Option<Hash>, RegistryError> {
+    match salt {
+        None => Ok(None),
+        Some(salt) => {
+            let commitment = reservation_commitment(id, &account_id, &salt);
+            active_reservation(account_id, commitment)
+                .ok_or(RegistryError::InexistentReservation)?;
+            Ok(Some(commitment))
+        }
+    }
+}
+
+/// Returns true if `call` registers an org or a user and reveals an active reservation of
+/// `author` for the ID.
+pub fn reveals_reservation(author: AccountId, call: &crate::Call) -> bool {
+    let (id, salt) = match call {
+        crate::Call::Registry(Call::register_org(message)) => {
+            (&message.org_id, message.reservation_salt)
+        }
+        crate::Call::Registry(Call::register_user(message)) => {
+            (&message.user_id, message.reservation_salt)
+        }
+        _ => return false,
+    };
+    revealed_reservation(id, author, salt)
+        .map(|commitment| commitment.is_some())
+        .unwrap_or(false)
+}
+
+/// Remove the reservation of `account_id` with `commitment` if there is one and return the
+/// deposit to the account.
+fn remove_reservation(account_id: AccountId, commitment: Hash) {
+    if store::Reservations1::take(account_id, commitment).is_some() {
+        <crate::runtime::Balances as ReservableCurrency<_>>::unreserve(
+            &account_id,
+            ID_RESERVATION_DEPOSIT,
+        );
+    }
+}
+
 fn get_user_id_with_account(account_id: AccountId) -> Option<Id> {
     get_user_with_account(account_id).map(|(id, _)| id)
 }
 
-// TODO(xla): This is a naive first version of the check to see if an account is
-// already associated to a user. While fine for small dataset this needs to be reworked
-// in the future.
+/// Return the user associated with `account_id` using the [store::AccountUsers1] index.
 pub fn get_user_with_account(account_id: AccountId) -> Option<(Id, state::Users1Data)> {
-    store::Users1::iter().find(|(_, user)| user.account_id() == account_id)
+    let user_id = store::AccountUsers1::get(account_id)?;
+    store::Users1::get(&user_id).map(|user| (user_id, user))
 }
 
 pub fn find_org(predicate: impl Fn(&state::Orgs1Data) -> bool) -> Option<state::Orgs1Data> {
@@ -359,6 +904,22 @@ impl DecodeKey for store::Projects1 {
     }
 }
 
+impl DecodeKey for store::PendingProjects1 {
+    type Key = ProjectId;
+
+    fn decode_key(key: &[u8]) -> Result<ProjectId, parity_scale_codec::Error> {
+        decode_blake_two128_concat_key(key)
+    }
+}
+
+impl DecodeKey for store::Reservations1 {
+    type Key = (AccountId, Hash);
+
+    fn decode_key(key: &[u8]) -> Result<(AccountId, Hash), parity_scale_codec::Error> {
+        decode_blake_two128_concat_double_key(key)
+    }
+}
+
 impl DecodeKey for store::Users1 {
     type Key = Id;
 
@@ -367,6 +928,14 @@ impl DecodeKey for store::Users1 {
     }
 }
 
+impl DecodeKey for store::OrgMembers1 {
+    type Key = (Id, Id);
+
+    fn decode_key(key: &[u8]) -> Result<(Id, Id), parity_scale_codec::Error> {
+        decode_blake_two128_concat_double_key(key)
+    }
+}
+
 /// Decode a blake_two128_concat hashed key to the inferred type K.
 ///
 /// The key consists of the concatenation of the module prefix hash (16 bytes),
@@ -381,10 +950,35 @@ pub fn decode_blake_two128_concat_key<K: parity_scale_codec::Decode>(
     K::decode(&mut id_bytes)
 }
 
+/// Decode a double map key where both keys are blake_two128_concat hashed to the inferred
+/// types K1 and K2.
+///
+/// The key consists of the concatenation of the module prefix hash (16 bytes), the storage
+/// prefix hash (16 bytes), the first key hash (16 bytes), the first raw key, the second key hash
+/// (16 bytes), and finally the second raw key. See
+/// [frame_support::storage::generator::StorageDoubleMap::storage_double_map_final_key].
+pub fn decode_blake_two128_concat_double_key<
+    K1: parity_scale_codec::Decode,
+    K2: parity_scale_codec::Decode,
+>(
+    key: &[u8],
+) -> Result<(K1, K2), parity_scale_codec::Error> {
+    let final_storage_key_prefix_length = 48;
+    let key2_hash_length = 16;
+    let mut key_bytes = &key[final_storage_key_prefix_length..];
+    let key1 = K1::decode(&mut key_bytes)?;
+    if key_bytes.len() < key2_hash_length {
+        return Err("Storage key is too short to contain a second key".into());
+    }
+    let mut key2_bytes = &key_bytes[key2_hash_length..];
+    let key2 = K2::decode(&mut key2_bytes)?;
+    Ok((key1, key2))
+}
+
 #[cfg(test)]
 mod test {
     use core::convert::TryFrom;
-    use frame_support::storage::generator::StorageMap;
+    use frame_support::storage::generator::{StorageDoubleMap, StorageMap};
 
     use super::*;
 
@@ -419,4 +1013,16 @@ mod test {
         let decoded_key = store::Users1::decode_key(&hashed_key).unwrap();
         assert_eq!(decoded_key, user_id);
     }
+
+    /// Test that store::OrgMembers1::decode_key after
+    /// store::OrgMembers1::storage_double_map_final_key is identical to the original input ids.
+    #[test]
+    fn org_members_decode_key_identity() {
+        let org_id = Id::try_from("monadic").unwrap();
+        let user_id = Id::try_from("cloudhead").unwrap();
+        let hashed_key =
+            store::OrgMembers1::storage_double_map_final_key(org_id.clone(), user_id.clone());
+        let decoded_key = store::OrgMembers1::decode_key(&hashed_key).unwrap();
+        assert_eq!(decoded_key, (org_id, user_id));
+    }
 }
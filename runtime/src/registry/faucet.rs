@@ -0,0 +1,102 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Implements [ValidateUnsigned] for the registry module to guard the unsigned [Call::faucet].
+
+use alloc::vec;
+
+use frame_support::storage::{StorageMap as _, StorageValue as _};
+use parity_scale_codec::Encode as _;
+use sp_runtime::{
+    traits::ValidateUnsigned,
+    transaction_validity::{
+        InvalidTransaction, TransactionSource, TransactionValidity, TransactionValidityError,
+        ValidTransaction,
+    },
+};
+
+use radicle_registry_core::{message, AccountId};
+
+use super::{store, Call, Module, Trait, FAUCET_MAX_AMOUNT, FAUCET_PERIOD};
+use crate::Hash;
+
+/// The faucet is not enabled in the genesis config of the chain.
+const FAUCET_DISABLED_ERROR: InvalidTransaction = InvalidTransaction::Custom(10);
+/// The requested amount exceeds [FAUCET_MAX_AMOUNT].
+const FAUCET_AMOUNT_ERROR: InvalidTransaction = InvalidTransaction::Custom(11);
+/// The recipient already received funds within the last [FAUCET_PERIOD] blocks.
+const FAUCET_RATE_LIMIT_ERROR: InvalidTransaction = InvalidTransaction::Custom(12);
+
+impl<T: Trait> ValidateUnsigned for Module<T>
+where
+    T: frame_system::Trait<
+        AccountId = AccountId,
+        BaseCallFilter = (),
+        Origin = crate::Origin,
+        Call = crate::Call,
+        Hash = Hash,
+        OnNewAccount = (),
+    >,
+    <T as frame_system::Trait>::Event: From<frame_system::RawEvent<AccountId>>,
+    <T as frame_system::Trait>::OnKilledAccount:
+        frame_support::traits::OnKilledAccount<T::AccountId>,
+{
+    type Call = Call<T>;
+
+    fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
+        match call {
+            // The block author inherent is validated by `check_inherent`.
+            Call::set_block_author(_) => Ok(()),
+            Call::faucet(message) => check_faucet(message).map_err(Into::into),
+            _ => Err(InvalidTransaction::Call.into()),
+        }
+    }
+
+    fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+        match call {
+            Call::faucet(message) => {
+                check_faucet(message)?;
+                Ok(ValidTransaction {
+                    // Only one faucet transaction per recipient can be in the pool.
+                    provides: vec![(b"faucet", message.recipient).encode()],
+                    longevity: u64::from(FAUCET_PERIOD),
+                    ..Default::default()
+                })
+            }
+            _ => Err(InvalidTransaction::Call.into()),
+        }
+    }
+}
+
+/// Check that the faucet is enabled, the amount is within the limit and the recipient has not
+/// received faucet funds in the last [FAUCET_PERIOD] blocks.
+fn check_faucet(message: &message::Faucet) -> Result<(), InvalidTransaction> {
+    if !store::FaucetEnabled::get() {
+        return Err(FAUCET_DISABLED_ERROR);
+    }
+
+    if message.amount > FAUCET_MAX_AMOUNT {
+        return Err(FAUCET_AMOUNT_ERROR);
+    }
+
+    let block_number = frame_system::Module::<crate::Runtime>::block_number();
+    if let Some(last_drip) = store::FaucetDrips1::get(message.recipient) {
+        if last_drip + FAUCET_PERIOD > block_number {
+            return Err(FAUCET_RATE_LIMIT_ERROR);
+        }
+    }
+
+    Ok(())
+}
@@ -17,7 +17,7 @@
 
 use frame_support::traits::GetCallName as _;
 use parity_scale_codec::{Decode, Encode};
-use sp_inherents::{InherentIdentifier, IsFatalError, ProvideInherent};
+use sp_inherents::{CheckInherentsResult, InherentIdentifier, IsFatalError, ProvideInherent};
 use sp_runtime::RuntimeString;
 
 use radicle_registry_core::AccountId;
@@ -59,6 +59,8 @@ pub enum CheckInherentError {
     /// The call is forbidden for an inherent. `name` is the name of the call as returned by
     /// [frame_support::traits::GetCallName::get_call_name].
     ForbiddenCall { name: RuntimeString },
+    /// The block does not contain the [Call::set_block_author] inherent.
+    BlockAuthorMissing,
 }
 
 impl IsFatalError for CheckInherentError {
@@ -74,10 +76,36 @@ impl std::fmt::Display for CheckInherentError {
             CheckInherentError::ForbiddenCall { name } => {
                 write!(f, "Call {} is forbidden for inherents", name)
             }
+            CheckInherentError::BlockAuthorMissing => {
+                write!(f, "Block does not contain the set_block_author inherent")
+            }
         }
     }
 }
 
+/// Add [CheckInherentError::BlockAuthorMissing] to `result` if `extrinsics` do not contain the
+/// unsigned [Call::set_block_author] inherent.
+///
+/// [Module] requires the block author to be set when the block is finalized. Checking the
+/// inherents of a block on import rejects such a block before it is executed.
+pub fn check_block_author_inherent(
+    extrinsics: &[crate::UncheckedExtrinsic],
+    result: &mut CheckInherentsResult,
+) {
+    let has_block_author = extrinsics.iter().any(|xt| {
+        xt.signature.is_none()
+            && matches!(
+                xt.function,
+                crate::Call::Registry(Call::set_block_author(_))
+            )
+    });
+    if !has_block_author {
+        // `put_error` only fails if an error for the registry inherent was already recorded. The
+        // block is rejected in that case, too.
+        let _ = result.put_error(INHERENT_IDENTIFIER, &CheckInherentError::BlockAuthorMissing);
+    }
+}
+
 impl<T: Trait> ProvideInherent for Module<T>
 where
     T: frame_system::Trait<
@@ -111,6 +139,9 @@ where
     ) -> Result<(), Self::Error> {
         match call {
             Call::set_block_author(_) => Ok(()),
+            // Unsigned faucet transactions that precede the first signed extrinsic are checked
+            // as inherents. They are validated by the `ValidateUnsigned` implementation.
+            Call::faucet(_) => Ok(()),
             _ => Err(CheckInherentError::ForbiddenCall {
                 name: RuntimeString::from(call.get_call_name()),
             }),
@@ -14,12 +14,17 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 //! Implements Substrate runtime APIs and provide a function based interface for the runtime APIs.
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use frame_support::{ensure, fail, traits::Randomness};
+use parity_scale_codec::Encode as _;
+use radicle_registry_core::ProjectDomain;
 use sp_core::OpaqueMetadata;
 use sp_runtime::traits::Block as BlockT;
 use sp_runtime::{
-    transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidity},
+    transaction_validity::{
+        InvalidTransaction, TransactionPriority, TransactionSource, TransactionTag,
+        TransactionValidity,
+    },
     ApplyExtrinsicResult,
 };
 use sp_version::RuntimeVersion;
@@ -52,23 +57,67 @@ pub fn inherent_extrinsics(data: sp_inherents::InherentData) -> Vec<UncheckedExt
 /// See [sp_block_builder::BlockBuilder::apply_extrinsic].
 pub fn apply_extrinsic(extrinsic: UncheckedExtrinsic) -> ApplyExtrinsicResult {
     validate_extrinsic_call(&extrinsic)?;
+    ensure_project_domain_exists(&extrinsic.function)?;
     Executive::apply_extrinsic(extrinsic)
 }
 
+/// See [sp_transaction_pool::runtime_api::TaggedTransactionQueue::validate_transaction].
+///
+/// Registrations that reveal an active ID reservation of their author get the highest priority
+/// and the priority of all other transactions is capped below it. A revealed ID can thus not be
+/// claimed by another account that copies it from the transaction pool, unless the author of
+/// the block orders the transactions differently.
+///
+/// Project registrations wait in the pool for the registration of their domain. See
+/// [project_domain_tags].
+pub fn validate_transaction(
+    source: TransactionSource,
+    tx: UncheckedExtrinsic,
+) -> TransactionValidity {
+    validate_extrinsic_call(&tx)?;
+    let (requires, provides) = project_domain_tags(&tx.function);
+    let reveals_reservation = tx.signature.as_ref().map_or(false, |(author, _, _)| {
+        registry::reveals_reservation(*author, &tx.function)
+    });
+    let mut validity = Executive::validate_transaction(source, tx)?;
+    validity.priority = if reveals_reservation {
+        TransactionPriority::max_value()
+    } else {
+        validity.priority.min(TransactionPriority::max_value() - 1)
+    };
+    validity.requires.extend(requires);
+    validity.provides.extend(provides);
+    Ok(validity)
+}
+
 /// See [sp_block_builder::BlockBuilder::finalize_block].
 pub fn finalize_block() -> Header {
     Executive::finalize_block()
 }
 
+/// See [sp_block_builder::BlockBuilder::check_inherents].
+///
+/// In addition to the checks of the modules we reject blocks that do not set the block author.
+/// See [registry::check_block_author_inherent].
+pub fn check_inherents(
+    block: Block,
+    data: sp_inherents::InherentData,
+) -> sp_inherents::CheckInherentsResult {
+    let mut result = data.check_extrinsics(&block);
+    registry::check_block_author_inherent(&block.extrinsics, &mut result);
+    result
+}
+
 const SIGNED_INHERENT_CALL_ERROR: InvalidTransaction = InvalidTransaction::Custom(1);
 const FOBIDDEN_CALL_ERROR: InvalidTransaction = InvalidTransaction::Custom(2);
 const UNSGINED_CALL_ERROR: InvalidTransaction = InvalidTransaction::Custom(3);
+const INEXISTENT_PROJECT_DOMAIN_ERROR: InvalidTransaction = InvalidTransaction::Custom(4);
 
 /// Validate that the call of the extrinsic is allowed.
 ///
 /// * We forbid calls reserved for inherents when the extrinsic is not signed.
 /// * We forbid any calls to the [super::Balances] or [super::System] module.
-/// * We ensure that the extrinsic is signed for non-inherent calls.
+/// * We ensure that the extrinsic is signed for non-inherent calls except for the faucet.
 ///
 fn validate_extrinsic_call(xt: &UncheckedExtrinsic) -> Result<(), InvalidTransaction> {
     match xt.function {
@@ -77,6 +126,12 @@ fn validate_extrinsic_call(xt: &UncheckedExtrinsic) -> Result<(), InvalidTransac
             ensure!(xt.signature.is_none(), SIGNED_INHERENT_CALL_ERROR)
         }
 
+        // The faucet is only allowed as an unsigned extrinsic and is validated by
+        // [registry::Module] as `ValidateUnsigned`.
+        Call::Registry(registry::Call::faucet(_)) => {
+            ensure!(xt.signature.is_none(), SIGNED_INHERENT_CALL_ERROR)
+        }
+
         // Forbidden internals.
         Call::Balances(_) | Call::System(_) => fail!(FOBIDDEN_CALL_ERROR),
 
@@ -90,6 +145,53 @@ fn validate_extrinsic_call(xt: &UncheckedExtrinsic) -> Result<(), InvalidTransac
     Ok(())
 }
 
+/// Forbid project registrations under a domain that does not exist.
+///
+/// The check happens before the transaction fee is charged so that authors don't pay for
+/// doomed transactions. It only applies to extrinsics included in a block. The transaction pool
+/// keeps these registrations until the domain is registered. See [project_domain_tags].
+fn ensure_project_domain_exists(call: &Call) -> Result<(), InvalidTransaction> {
+    if let Call::Registry(registry::Call::register_project(message)) = call {
+        ensure!(
+            registry::project_domain_exists(&message.project_domain),
+            INEXISTENT_PROJECT_DOMAIN_ERROR
+        );
+    }
+    Ok(())
+}
+
+/// Returns the tags that `call` requires and provides in the transaction pool to order project
+/// registrations after the registration of their domain.
+///
+/// Org and user registrations provide the tag of the domain they register. A project
+/// registration under a domain that does not exist yet requires the tag of the domain. It stays
+/// in the future queue of the pool until a registration of the domain is ready.
+///
+/// Two registrations of the same domain provide the same tag. The pool only keeps the one with
+/// the higher priority.
+fn project_domain_tags(call: &Call) -> (Vec<TransactionTag>, Vec<TransactionTag>) {
+    fn domain_tag(domain: ProjectDomain) -> TransactionTag {
+        (b"registry-domain", domain).encode()
+    }
+
+    match call {
+        Call::Registry(registry::Call::register_org(message)) => (
+            Vec::new(),
+            vec![domain_tag(ProjectDomain::Org(message.org_id.clone()))],
+        ),
+        Call::Registry(registry::Call::register_user(message)) => (
+            Vec::new(),
+            vec![domain_tag(ProjectDomain::User(message.user_id.clone()))],
+        ),
+        Call::Registry(registry::Call::register_project(message))
+            if !registry::project_domain_exists(&message.project_domain) =>
+        {
+            (vec![domain_tag(message.project_domain.clone())], Vec::new())
+        }
+        _ => (Vec::new(), Vec::new()),
+    }
+}
+
 sp_api::impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
         fn version() -> RuntimeVersion {
@@ -128,7 +230,7 @@ sp_api::impl_runtime_apis! {
             block: Block,
             data: sp_inherents::InherentData,
         ) -> sp_inherents::CheckInherentsResult {
-            data.check_extrinsics(&block)
+            check_inherents(block, data)
         }
 
         fn random_seed() -> <Block as BlockT>::Hash {
@@ -138,8 +240,7 @@ sp_api::impl_runtime_apis! {
 
     impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
         fn validate_transaction(source: TransactionSource, tx: <Block as BlockT>::Extrinsic) -> TransactionValidity {
-            validate_extrinsic_call(&tx)?;
-            Executive::validate_transaction(source, tx)
+            validate_transaction(source, tx)
         }
     }
 
@@ -171,3 +272,148 @@ sp_api::impl_runtime_apis! {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::genesis::GenesisConfig;
+
+    use core::convert::TryFrom;
+    use parity_scale_codec::Encode as _;
+    use radicle_registry_core::{message, Bytes128, Id, ProjectName};
+    use sp_core::{crypto::Pair as _, ed25519};
+    use sp_inherents::{InherentData, ProvideInherentData as _};
+    use sp_runtime::{generic::Era, traits::Header as _, BuildStorage as _};
+
+    /// Test that a block without the `set_block_author` inherent fails the inherent check
+    /// instead of panicking when the block is finalized.
+    #[test]
+    fn check_inherents_block_author_missing() {
+        let genesis_config = GenesisConfig {
+            pallet_balances: None,
+            pallet_sudo: None,
+            registry: None,
+            system: None,
+        };
+        let mut test_ext = sp_io::TestExternalities::new(genesis_config.build_storage().unwrap());
+
+        test_ext.execute_with(|| {
+            let block_author = ed25519::Pair::from_string("//Alice", None)
+                .unwrap()
+                .public();
+            let mut inherent_data = InherentData::new();
+            inherent_data
+                .put_data(sp_timestamp::INHERENT_IDENTIFIER, &1_000_000u64)
+                .unwrap();
+            registry::AuthoringInherentData { block_author }
+                .provide_inherent_data(&mut inherent_data)
+                .unwrap();
+
+            let header = Header::new(
+                1,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            );
+            let inherents = inherent_extrinsics(inherent_data.clone());
+            let block = Block {
+                header: header.clone(),
+                extrinsics: inherents.clone(),
+            };
+            assert!(check_inherents(block, inherent_data.clone()).ok());
+
+            let block_without_author = Block {
+                header,
+                extrinsics: inherents
+                    .into_iter()
+                    .filter(|xt| !matches!(xt.function, Call::Registry(_)))
+                    .collect(),
+            };
+            let result = check_inherents(block_without_author, inherent_data);
+            assert!(!result.ok());
+            assert!(result.fatal_error());
+        });
+    }
+
+    /// Test that a project registration under a domain that does not exist requires the tag that
+    /// the registration of the domain provides.
+    #[test]
+    fn project_domain_tags_order_registrations() {
+        let genesis_config = GenesisConfig {
+            pallet_balances: None,
+            pallet_sudo: None,
+            registry: None,
+            system: None,
+        };
+        let mut test_ext = sp_io::TestExternalities::new(genesis_config.build_storage().unwrap());
+
+        test_ext.execute_with(|| {
+            let id = Id::try_from("monadic").unwrap();
+            let register_project = |project_domain| {
+                Call::Registry(registry::Call::register_project(message::RegisterProject {
+                    project_name: ProjectName::try_from("radicle".to_string()).unwrap(),
+                    project_domain,
+                    metadata: Bytes128::from_vec(Vec::new()).unwrap(),
+                }))
+            };
+
+            let (_, org_provides) = project_domain_tags(&Call::Registry(
+                registry::Call::register_org(message::RegisterOrg {
+                    org_id: id.clone(),
+                    reservation_salt: None,
+                }),
+            ));
+            let (org_project_requires, _) =
+                project_domain_tags(&register_project(ProjectDomain::Org(id.clone())));
+            assert_eq!(org_project_requires.len(), 1);
+            assert_eq!(org_project_requires, org_provides);
+
+            let (user_project_requires, _) =
+                project_domain_tags(&register_project(ProjectDomain::User(id)));
+            assert_eq!(user_project_requires.len(), 1);
+            assert_ne!(user_project_requires, org_provides);
+        });
+    }
+
+    /// Test that calls reserved for unsigned extrinsics are rejected when they are signed instead
+    /// of being charged like regular calls.
+    #[test]
+    fn validate_extrinsic_call_signed_inherent() {
+        let author = ed25519::Pair::from_string("//Alice", None).unwrap();
+        let extra = (
+            frame_system::CheckTxVersion::new(),
+            frame_system::CheckGenesis::new(),
+            frame_system::CheckEra::from(Era::Immortal),
+            frame_system::CheckNonce::from(0),
+            frame_system::CheckWeight::new(),
+            crate::fees::PayTxFee { fee: 1 },
+            crate::longevity::TxLongevity { longevity: None },
+        );
+        let calls = vec![
+            Call::Registry(registry::Call::set_block_author(author.public())),
+            Call::Registry(registry::Call::faucet(
+                radicle_registry_core::message::Faucet {
+                    recipient: author.public(),
+                    amount: 1000,
+                },
+            )),
+        ];
+
+        for call in calls {
+            let signature = author.sign(&call.encode());
+            let xt = UncheckedExtrinsic::new_signed(
+                call.clone(),
+                author.public(),
+                signature,
+                extra.clone(),
+            );
+            assert_eq!(
+                validate_extrinsic_call(&xt),
+                Err(SIGNED_INHERENT_CALL_ERROR),
+                "Signed extrinsic with {:?} was not rejected",
+                call
+            );
+        }
+    }
+}
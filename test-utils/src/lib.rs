@@ -16,7 +16,10 @@
 //! Miscellaneous helpers used throughout Registry tests.
 
 use std::convert::TryFrom;
+use std::future::Future;
 
+use futures::lock::Mutex;
+use lazy_static::lazy_static;
 use rand::distributions::Alphanumeric;
 use rand::Rng;
 
@@ -51,6 +54,45 @@ pub async fn submit_ok<Message_: Message>(
     submit_ok_with_fee(&client, &author, message, random_balance()).await
 }
 
+/// Assert that the runtime message of the transaction was applied successfully.
+///
+/// Panics with the transaction error otherwise.
+pub fn assert_result_ok(tx_included: &TransactionIncluded) {
+    if let Err(error) = &tx_included.result {
+        panic!(
+            "Transaction {:?} in block {} failed: {:?}",
+            tx_included.tx_hash, tx_included.block, error
+        );
+    }
+}
+
+/// Assert that applying the transaction emitted `event`.
+///
+/// The events of the transaction are searched so the assertion does not depend on the order in
+/// which events are emitted. Panics with the list of emitted events if `event` is missing.
+pub async fn assert_event_present(
+    client: &Client,
+    tx_included: &TransactionIncluded,
+    event: Event,
+) {
+    let extrinsics = client.block_extrinsics(tx_included.block).await.unwrap();
+    let extrinsic = extrinsics
+        .into_iter()
+        .find(|extrinsic| extrinsic.tx_hash == tx_included.tx_hash)
+        .unwrap_or_else(|| {
+            panic!(
+                "Transaction {:?} not found in block {}",
+                tx_included.tx_hash, tx_included.block
+            )
+        });
+    if !extrinsic.events.contains(&event) {
+        panic!(
+            "Event {:?} not emitted by transaction {:?}. Emitted events: {:#?}",
+            event, tx_included.tx_hash, extrinsic.events
+        );
+    }
+}
+
 pub async fn create_project(
     client: &Client,
     author: &ed25519::Pair,
@@ -59,7 +101,9 @@ pub async fn create_project(
     let register_project_message = random_register_project_message(domain);
     submit_ok(&client, &author, register_project_message.clone()).await;
     let project = client
-        .get_project(
+        .registry()
+        .projects()
+        .get(
             register_project_message.project_name.clone(),
             domain.clone(),
         )
@@ -83,6 +127,7 @@ pub fn random_project_name() -> ProjectName {
 pub fn random_register_org_message() -> message::RegisterOrg {
     message::RegisterOrg {
         org_id: random_id(),
+        reservation_salt: None,
     }
 }
 
@@ -99,6 +144,7 @@ pub fn random_register_project_message(domain: &ProjectDomain) -> message::Regis
 pub fn random_register_user_message() -> message::RegisterUser {
     message::RegisterUser {
         user_id: random_id(),
+        reservation_salt: None,
     }
 }
 
@@ -115,6 +161,62 @@ pub async fn key_pair_with_funds(client: &Client) -> ed25519::Pair {
     key_pair
 }
 
+/// Create an emulator client and a random key pair whose account is equipped with some funds.
+pub async fn emulator_with_funded_author() -> (Client, ed25519::Pair) {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    (client, author)
+}
+
+/// Connect to the node running on `host` and create a random key pair whose account is equipped
+/// with some funds.
+///
+/// The funds are transferred from [root_key_pair] which must have funds on the chain of the node.
+pub async fn remote_with_funded_author(host: url::Host) -> (Client, ed25519::Pair) {
+    let client = Client::create_with_executor(host).await.unwrap();
+    let author = key_pair_with_funds(&client).await;
+    (client, author)
+}
+
+/// Environment variable with the host of a running dev node. If it is set, [for_each_backend]
+/// also runs tests against that node.
+pub const TEST_NODE_HOST_ENV: &str = "RADICLE_REGISTRY_TEST_NODE_HOST";
+
+lazy_static! {
+    /// Serializes the tests that run against the test node. Tests running in parallel would
+    /// otherwise fund their accounts from [root_key_pair] with the same nonce.
+    static ref TEST_NODE_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// Run `test` with an emulator client and, if [TEST_NODE_HOST_ENV] is set, with a client
+/// connected to the node on that host.
+///
+/// The chain state of the node is shared between tests so tests must only rely on state they
+/// create themselves. Use random IDs and funded random key pairs.
+///
+/// ```ignore
+/// for_each_backend(|client| async move {
+///     let author = key_pair_with_funds(&client).await;
+///     // ...
+/// })
+/// .await;
+/// ```
+pub async fn for_each_backend<Test, TestFuture>(test: Test)
+where
+    Test: Fn(Client) -> TestFuture,
+    TestFuture: Future<Output = ()>,
+{
+    let (client, _) = Client::new_emulator();
+    test(client).await;
+
+    if let Ok(host) = std::env::var(TEST_NODE_HOST_ENV) {
+        let host = url::Host::parse(&host).expect("Invalid test node host");
+        let _guard = TEST_NODE_LOCK.lock().await;
+        let client = Client::create_with_executor(host).await.unwrap();
+        test(client).await;
+    }
+}
+
 /// Create a random key pair derived and register a user associated with it.
 /// Ensures that the account for the key pair is equipped with enough RAD to run transactions.
 pub async fn key_pair_with_associated_user(client: &Client) -> (ed25519::Pair, Id) {
@@ -129,6 +231,7 @@ pub async fn associate_key_pair_with_random_user(client: &Client, key_pair: &ed2
     let user_id = random_id();
     let register_user_message = message::RegisterUser {
         user_id: user_id.clone(),
+        reservation_salt: None,
     };
     let tx_applied = submit_ok(&client, &key_pair, register_user_message).await;
     assert_eq!(tx_applied.result, Ok(()));
@@ -146,7 +249,9 @@ pub fn random_alnum_string(size: usize) -> String {
 /// Check if the user with the given id exists in the chain state.
 pub async fn user_exists(client: &Client, user_id: Id) -> bool {
     client
-        .list_users()
+        .registry()
+        .users()
+        .list()
         .await
         .unwrap()
         .iter()
@@ -199,8 +304,108 @@ pub async fn register_random_org(
     let org_id = register_org.org_id.clone();
     submit_ok(&client, author, register_org).await;
 
-    let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+    let org = client
+        .registry()
+        .orgs()
+        .get(org_id.clone())
+        .await
+        .unwrap()
+        .unwrap();
     transfer(&client, &author, org.account_id(), 1000).await;
 
     (org_id, org)
 }
+
+/// Number of entities [populate_chain] creates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PopulateSpec {
+    /// Number of orgs to register. Every org gets the `author` as its only member.
+    pub orgs: usize,
+    /// Number of users to register. Every user is associated with its own funded key pair.
+    pub users: usize,
+    /// Number of projects to register. Every project is registered under a domain picked at
+    /// random from the orgs and users created by [populate_chain].
+    pub projects: usize,
+}
+
+/// Entities created by [populate_chain].
+pub struct PopulatedChain {
+    /// IDs of the registered orgs.
+    pub orgs: Vec<Id>,
+    /// Registered users with the key pairs they are associated with.
+    pub users: Vec<(ed25519::Pair, Id)>,
+    /// Names and domains of the registered projects.
+    pub projects: Vec<ProjectId>,
+}
+
+/// Populate the chain with the number of orgs, users and projects given by `spec`.
+///
+/// Orgs are registered by `author` which must be associated with a user and have enough funds to
+/// pay the registration fees. Every org account is equipped with enough funds to register
+/// projects.
+///
+/// A domain is picked for each project with a probability proportional to the number of
+/// projects it already holds plus one. This results in the uneven distribution of projects
+/// among domains that we see on real chains.
+///
+/// Panics if `spec` requests projects but no orgs or users or if any transaction fails.
+pub async fn populate_chain(
+    client: &Client,
+    author: &ed25519::Pair,
+    spec: PopulateSpec,
+) -> PopulatedChain {
+    let mut orgs = Vec::with_capacity(spec.orgs);
+    for _ in 0..spec.orgs {
+        let (org_id, _) = register_random_org(client, author).await;
+        orgs.push(org_id);
+    }
+
+    let mut users = Vec::with_capacity(spec.users);
+    for _ in 0..spec.users {
+        users.push(key_pair_with_associated_user(client).await);
+    }
+
+    let domains = orgs
+        .iter()
+        .map(|org_id| (ProjectDomain::Org(org_id.clone()), author))
+        .chain(
+            users
+                .iter()
+                .map(|(key_pair, user_id)| (ProjectDomain::User(user_id.clone()), key_pair)),
+        )
+        .collect::<Vec<_>>();
+    assert!(
+        spec.projects == 0 || !domains.is_empty(),
+        "Cannot register projects without orgs or users"
+    );
+
+    let mut domain_weights = vec![1usize; domains.len()];
+    let mut projects = Vec::with_capacity(spec.projects);
+    for _ in 0..spec.projects {
+        let index = random_weighted_index(&domain_weights);
+        let (domain, domain_author) = &domains[index];
+        let (project_name, _) = create_project(client, domain_author, domain).await;
+        domain_weights[index] += 1;
+        projects.push((project_name, domain.clone()));
+    }
+
+    PopulatedChain {
+        orgs,
+        users,
+        projects,
+    }
+}
+
+/// Pick a random index into `weights` where the probability of an index is proportional to its
+/// weight.
+fn random_weighted_index(weights: &[usize]) -> usize {
+    let total: usize = weights.iter().sum();
+    let mut choice = rand::thread_rng().gen_range(0, total);
+    for (index, weight) in weights.iter().enumerate() {
+        if choice < *weight {
+            return index;
+        }
+        choice -= weight;
+    }
+    unreachable!("choice is less than the sum of all weights")
+}